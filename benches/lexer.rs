@@ -0,0 +1,42 @@
+//! Measures the time the lexer spends per token on the test corpus.
+//!
+//! Run with `cargo bench --bench lexer`.
+
+use std::{fs, hint::black_box, path::Path, time::Instant};
+
+use ecc::lexer::Lexer;
+
+const REPETITIONS: usize = 200;
+const RUNS: usize = 10;
+
+fn main() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&corpus)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "c"))
+        .collect();
+    paths.sort();
+    let src: String = paths
+        .iter()
+        .map(|path| fs::read_to_string(path).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .repeat(REPETITIONS);
+
+    let mut best = f64::INFINITY;
+    let mut count = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let (tokens, _) = Lexer::new(black_box(&src)).lex();
+        let elapsed = start.elapsed().as_secs_f64();
+        count = tokens.len();
+        best = best.min(elapsed);
+    }
+    println!(
+        "lexed {count} tokens from {} bytes in {:.2} ms: {:.1} ns per token",
+        src.len(),
+        best * 1e3,
+        best * 1e9 / count as f64
+    );
+}
@@ -1,4 +1,6 @@
-use crate::token::{At, IntegerToken, StringEncoding, TokenKind};
+use std::fmt;
+
+use crate::token::{At, FloatToken, IntegerToken, StringEncoding, TokenKind};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct List<T> {
@@ -11,6 +13,26 @@ pub enum ListKind<T> {
     Leaf(Box<T>),
     Cons(Box<List<T>>, Box<T>),
 }
+impl<T> List<T> {
+    /// Returns the elements of the list in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut items = Vec::new();
+        let mut list = self;
+        loop {
+            match &list.kind {
+                ListKind::Leaf(item) => {
+                    items.push(&**item);
+                    break;
+                }
+                ListKind::Cons(left, item) => {
+                    items.push(&**item);
+                    list = left;
+                }
+            }
+        }
+        items.into_iter().rev()
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CommaList<T> {
@@ -27,6 +49,44 @@ pub enum CommaListKind<T> {
         right: Box<T>,
     },
 }
+impl<T> CommaList<T> {
+    /// Returns the elements of the list in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut items = Vec::new();
+        let mut list = self;
+        loop {
+            match &list.kind {
+                CommaListKind::Leaf(item) => {
+                    items.push(&**item);
+                    break;
+                }
+                CommaListKind::Cons { left, right, .. } => {
+                    items.push(&**right);
+                    list = left;
+                }
+            }
+        }
+        items.into_iter().rev()
+    }
+    /// Returns the elements of the list in source order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let mut items = Vec::new();
+        let mut list = self;
+        loop {
+            match &mut list.kind {
+                CommaListKind::Leaf(item) => {
+                    items.push(&mut **item);
+                    break;
+                }
+                CommaListKind::Cons { left, right, .. } => {
+                    items.push(&mut **right);
+                    list = left;
+                }
+            }
+        }
+        items.into_iter().rev()
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StringLiteral<'a> {
@@ -34,6 +94,131 @@ pub struct StringLiteral<'a> {
     pub literal: &'a str,
     pub encoding: StringEncoding,
 }
+impl StringLiteral<'_> {
+    /// Returns the code units of the string in its encoding, without the terminating null.
+    pub fn decode(&self) -> Result<Vec<u32>, CharValueErr> {
+        decode(self.literal, self.encoding)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharLiteral<'a> {
+    pub at: At,
+    pub literal: &'a str,
+    pub encoding: StringEncoding,
+}
+impl CharLiteral<'_> {
+    /// Returns the value of the character constant.
+    ///
+    /// A plain constant with several characters, such as `'AB'`, packs the bytes
+    /// of its characters big-endian into an `int` (`'AB'` is `0x4142`), keeping only
+    /// the last four, as GCC and Clang do. Prefixed constants must hold a single
+    /// code unit. The value is not sign-extended, even where `char` is signed.
+    pub fn value(&self) -> Result<u32, CharValueErr> {
+        let units = decode(self.literal, self.encoding)?;
+        match (self.encoding, units.as_slice()) {
+            (_, []) => Err(CharValueErr::Empty),
+            (StringEncoding::None, _) => Ok(units.iter().fold(0, |packed, &b| packed << 8 | b)),
+            (_, [unit]) => Ok(*unit),
+            (_, _) => Err(CharValueErr::MultiCharacter),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CharValueErr {
+    Empty,
+    /// A prefixed character constant contains more than one code unit.
+    MultiCharacter,
+    InvalidEscape,
+    /// An octal or hexadecimal escape does not fit in a code unit of the encoding.
+    OutOfRange,
+}
+
+/// Decodes the body of a string literal or character constant into code units.
+///
+/// Octal and hexadecimal escapes denote a single code unit, whereas source characters
+/// and universal character names are encoded as UTF-8, UTF-16, or UTF-32.
+/// Plain and wide literals use UTF-8 and UTF-32 respectively.
+fn decode(literal: &str, encoding: StringEncoding) -> Result<Vec<u32>, CharValueErr> {
+    let unit_width = match encoding {
+        StringEncoding::None | StringEncoding::UTF8 => 8,
+        StringEncoding::UTF16 => 16,
+        StringEncoding::UTF32 | StringEncoding::Wide => 32,
+    };
+
+    let mut units = Vec::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(mut c) = chars.next() {
+        if c == '\\' {
+            let is_universal = matches!(chars.peek(), Some('u' | 'U'));
+            let value = decode_escape(&mut chars)?;
+            if is_universal {
+                c = char::from_u32(value).ok_or(CharValueErr::InvalidEscape)?;
+            } else if unit_width < 32 && value >= 1 << unit_width {
+                return Err(CharValueErr::OutOfRange);
+            } else {
+                units.push(value);
+                continue;
+            }
+        }
+
+        match unit_width {
+            8 => units.extend(c.encode_utf8(&mut [0; 4]).bytes().map(u32::from)),
+            16 => units.extend(c.encode_utf16(&mut [0; 2]).iter().map(|&u| u as u32)),
+            _ => units.push(c as u32),
+        }
+    }
+    Ok(units)
+}
+
+fn decode_escape(
+    chars: &mut std::iter::Peekable<impl Iterator<Item = char>>,
+) -> Result<u32, CharValueErr> {
+    let c = chars.next().ok_or(CharValueErr::InvalidEscape)?;
+    let value = match c {
+        '\'' | '"' | '?' | '\\' => c as u32,
+        'a' => 0x07,
+        'b' => 0x08,
+        'f' => 0x0C,
+        'n' => 0x0A,
+        'r' => 0x0D,
+        't' => 0x09,
+        'v' => 0x0B,
+        '0'..='7' => {
+            let mut value = c.to_digit(8).unwrap();
+            for _ in 0..2 {
+                let Some(digit) = chars.peek().and_then(|c| c.to_digit(8)) else {
+                    break;
+                };
+                chars.next();
+                value = value * 8 + digit;
+            }
+            value
+        }
+        'x' => {
+            // Hexadecimal escapes take every following hexadecimal digit.
+            let mut value: Option<u32> = None;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(16)) {
+                chars.next();
+                let shifted = value.unwrap_or(0).checked_mul(16);
+                value = Some(shifted.ok_or(CharValueErr::OutOfRange)? + digit);
+            }
+            value.ok_or(CharValueErr::InvalidEscape)?
+        }
+        'u' | 'U' => {
+            let length = if c == 'u' { 4 } else { 8 };
+            let mut value = 0;
+            for _ in 0..length {
+                let digit = chars.next().and_then(|c| c.to_digit(16));
+                value = value * 16 + digit.ok_or(CharValueErr::InvalidEscape)?;
+            }
+            value
+        }
+        _ => return Err(CharValueErr::InvalidEscape),
+    };
+    Ok(value)
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Expression<'a> {
@@ -45,13 +230,24 @@ pub struct Expression<'a> {
 pub enum ExpressionKind<'a> {
     Identifier(&'a str),
     Integer(IntegerToken<'a>),
+    Float(FloatToken<'a>),
     String(StringLiteral<'a>),
+    Character(CharLiteral<'a>),
+    /// The predefined constant `true` or `false`.
+    Bool(bool),
+    Nullptr,
     Parenthesized {
         open_parenthesis: At,
         inner: Box<Expression<'a>>,
         close_parenthesis: At,
     },
     GenericSelection(GenericSelection<'a>),
+    /// A GNU `({ ... })` whose value is that of its last expression statement.
+    StatementExpression {
+        open_parenthesis: At,
+        body: CompoundStatement<'a>,
+        close_parenthesis: At,
+    },
     Index {
         left: Box<Expression<'a>>,
         open_bracket: At,
@@ -62,6 +258,7 @@ pub enum ExpressionKind<'a> {
         left: Box<Expression<'a>>,
         open_parenthesis: At,
         arguments: Option<ArgumentExpressionList<'a>>,
+        trailing_comma: Option<At>,
         close_parenthesis: At,
     },
     Member {
@@ -92,6 +289,8 @@ pub enum ExpressionKind<'a> {
         right: Box<Expression<'a>>,
     },
     Unary(UnaryOperator, Box<Expression<'a>>),
+    /// A GNU `&&label` taking the address of a label.
+    LabelAddress(&'a str),
     Sizeof {
         sizeof_keyword: At,
         kind: SizeofKind<'a>,
@@ -130,7 +329,178 @@ pub enum ExpressionKind<'a> {
         comma: At,
         right: Box<Expression<'a>>,
     },
+    /// GNU `__builtin_offsetof(type-name, member-designator)`.
+    Offsetof {
+        builtin: At,
+        open_parenthesis: At,
+        type_name: Box<TypeName<'a>>,
+        comma: At,
+        member: MemberDesignator<'a>,
+        close_parenthesis: At,
+    },
+    /// GNU `__builtin_types_compatible_p(type-name, type-name)`.
+    TypesCompatible {
+        builtin: At,
+        open_parenthesis: At,
+        left: Box<TypeName<'a>>,
+        comma: At,
+        right: Box<TypeName<'a>>,
+        close_parenthesis: At,
+    },
 }
+impl<'a> Expression<'a> {
+    /// Returns the expression inside any number of enclosing parentheses,
+    /// for analyses to which the parentheses make no difference.
+    pub fn without_parens(&self) -> &Expression<'a> {
+        let mut expression = self;
+        while let ExpressionKind::Parenthesized { inner, .. } = &expression.kind {
+            expression = inner;
+        }
+        expression
+    }
+    /// Removes the parentheses that the precedence of the surrounding operators
+    /// does not require, so that `(a) + (b * c)` becomes `a + b * c`,
+    /// but `(a + b) * c` is kept. Operands nested in type names, initializers
+    /// and statement expressions are left as they are.
+    pub fn strip_parens(&mut self) {
+        self.strip_parens_below(COMMA);
+    }
+    /// Strips the parentheses in an operand that must bind at least as tightly as `precedence`.
+    fn strip_parens_below(&mut self, precedence: u8) {
+        use ExpressionKind::*;
+        match &mut self.kind {
+            Parenthesized { inner, .. } => {
+                inner.strip_parens_below(COMMA);
+                if inner.precedence() >= precedence {
+                    let empty = Expression {
+                        at: self.at,
+                        kind: Identifier(""),
+                    };
+                    *self = std::mem::replace(inner, empty);
+                }
+            }
+            GenericSelection(selection) => {
+                selection
+                    .controlling_expression
+                    .strip_parens_below(ASSIGNMENT);
+                for association in selection.generic_assocs.iter_mut() {
+                    association.value.strip_parens_below(ASSIGNMENT);
+                }
+            }
+            Index { left, index, .. } => {
+                left.strip_parens_below(POSTFIX);
+                index.strip_parens_below(COMMA);
+            }
+            Call {
+                left, arguments, ..
+            } => {
+                left.strip_parens_below(POSTFIX);
+                for argument in arguments.iter_mut().flat_map(|a| a.iter_mut()) {
+                    argument.strip_parens_below(ASSIGNMENT);
+                }
+            }
+            Member { left, .. }
+            | MemberIndirect { left, .. }
+            | PostIncrement { left, .. }
+            | PostDecrement { left, .. } => left.strip_parens_below(POSTFIX),
+            PreIncrement { right, .. } | PreDecrement { right, .. } => {
+                right.strip_parens_below(UNARY)
+            }
+            Unary(_, right) | Cast { right, .. } => right.strip_parens_below(CAST),
+            Sizeof {
+                kind: SizeofKind::Expression(right),
+                ..
+            } => right.strip_parens_below(UNARY),
+            Binary {
+                left,
+                operator: (_, operator),
+                right,
+            } => {
+                let precedence = operator.precedence();
+                left.strip_parens_below(precedence);
+                right.strip_parens_below(precedence + 1);
+            }
+            Conditional {
+                condition,
+                then_value,
+                else_value,
+                ..
+            } => {
+                condition.strip_parens_below(BinaryOperator::LogicalOr.precedence());
+                then_value.strip_parens_below(COMMA);
+                else_value.strip_parens_below(CONDITIONAL);
+            }
+            Assign { left, right, .. } => {
+                left.strip_parens_below(UNARY);
+                right.strip_parens_below(ASSIGNMENT);
+            }
+            Comma { left, right, .. } => {
+                left.strip_parens_below(COMMA);
+                right.strip_parens_below(ASSIGNMENT);
+            }
+            Identifier(_)
+            | Integer(_)
+            | Float(_)
+            | String(_)
+            | Character(_)
+            | Bool(_)
+            | Nullptr
+            | StatementExpression { .. }
+            | CompoundLiteral(_)
+            | LabelAddress(_)
+            | Sizeof { .. }
+            | Alignof { .. }
+            | Offsetof { .. }
+            | TypesCompatible { .. } => (),
+        }
+    }
+    /// Returns how tightly the expression binds; higher values bind tighter.
+    fn precedence(&self) -> u8 {
+        use ExpressionKind::*;
+        match &self.kind {
+            Identifier(_)
+            | Integer(_)
+            | Float(_)
+            | String(_)
+            | Character(_)
+            | Bool(_)
+            | Nullptr
+            | Parenthesized { .. }
+            | GenericSelection(_)
+            | StatementExpression { .. }
+            | Offsetof { .. }
+            | TypesCompatible { .. } => PRIMARY,
+            Index { .. }
+            | Call { .. }
+            | Member { .. }
+            | MemberIndirect { .. }
+            | PostIncrement { .. }
+            | PostDecrement { .. }
+            | CompoundLiteral(_) => POSTFIX,
+            PreIncrement { .. }
+            | PreDecrement { .. }
+            | Unary(..)
+            | LabelAddress(_)
+            | Sizeof { .. }
+            | Alignof { .. } => UNARY,
+            Cast { .. } => CAST,
+            Binary {
+                operator: (_, operator),
+                ..
+            } => operator.precedence(),
+            Conditional { .. } => CONDITIONAL,
+            Assign { .. } => ASSIGNMENT,
+            Comma { .. } => COMMA,
+        }
+    }
+}
+const PRIMARY: u8 = 16;
+const POSTFIX: u8 = 15;
+const UNARY: u8 = 14;
+const CAST: u8 = 13;
+const CONDITIONAL: u8 = 2;
+const ASSIGNMENT: u8 = 1;
+const COMMA: u8 = 0;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GenericSelection<'a> {
@@ -182,6 +552,18 @@ pub enum UnaryOperator {
     BitNot,
     LogicalNot,
 }
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UnaryOperator::AddressOf => "&",
+            UnaryOperator::Dereference => "*",
+            UnaryOperator::Positive => "+",
+            UnaryOperator::Negative => "-",
+            UnaryOperator::BitNot => "~",
+            UnaryOperator::LogicalNot => "!",
+        })
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SizeofKind<'a> {
@@ -214,6 +596,50 @@ pub enum BinaryOperator {
     LogicalAnd,
     LogicalOr,
 }
+impl BinaryOperator {
+    /// Returns how tightly the operator binds; higher values bind tighter.
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 12,
+            BinaryOperator::Add | BinaryOperator::Subtract => 11,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 10,
+            BinaryOperator::Less
+            | BinaryOperator::Greater
+            | BinaryOperator::LessEqual
+            | BinaryOperator::GreaterEqual => 9,
+            BinaryOperator::Equal | BinaryOperator::NotEqual => 8,
+            BinaryOperator::BitAnd => 7,
+            BinaryOperator::BitXor => 6,
+            BinaryOperator::BitOr => 5,
+            BinaryOperator::LogicalAnd => 4,
+            BinaryOperator::LogicalOr => 3,
+        }
+    }
+}
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+            BinaryOperator::Less => "<",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::LessEqual => "<=",
+            BinaryOperator::GreaterEqual => ">=",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::BitXor => "^",
+            BinaryOperator::LogicalAnd => "&&",
+            BinaryOperator::LogicalOr => "||",
+        })
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum AssignmentOperator {
@@ -229,6 +655,23 @@ pub enum AssignmentOperator {
     Xor,
     Or,
 }
+impl fmt::Display for AssignmentOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AssignmentOperator::Assign => "=",
+            AssignmentOperator::Multiply => "*=",
+            AssignmentOperator::Divide => "/=",
+            AssignmentOperator::Modulo => "%=",
+            AssignmentOperator::Add => "+=",
+            AssignmentOperator::Subtract => "-=",
+            AssignmentOperator::ShiftLeft => "<<=",
+            AssignmentOperator::ShiftRight => ">>=",
+            AssignmentOperator::And => "&=",
+            AssignmentOperator::Xor => "^=",
+            AssignmentOperator::Or => "|=",
+        })
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Declaration<'a> {
@@ -245,6 +688,7 @@ pub enum DeclarationKind<'a> {
     },
     Assert(StaticAssertDeclaration<'a>),
     Attribute(AttributeDeclaration<'a>),
+    Pragma(PragmaOperator<'a>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -259,6 +703,67 @@ pub enum DeclarationSpecifiersKind<'a> {
     Leaf(Option<AttributeSpecifierSequence<'a>>),
     Cons(Box<DeclarationSpecifiers<'a>>),
 }
+impl<'a> DeclarationSpecifiers<'a> {
+    /// Returns the specifiers in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &DeclarationSpecifier<'a>> {
+        let mut items = Vec::new();
+        let mut list = self;
+        loop {
+            items.push(&list.specifier);
+            match &list.kind {
+                DeclarationSpecifiersKind::Leaf(_) => break,
+                DeclarationSpecifiersKind::Cons(right) => list = right,
+            }
+        }
+        items.into_iter()
+    }
+    /// Returns the attributes trailing the last specifier, if any.
+    pub fn attributes(&self) -> Option<&AttributeSpecifierSequence<'a>> {
+        match &self.kind {
+            DeclarationSpecifiersKind::Leaf(attributes) => attributes.as_ref(),
+            DeclarationSpecifiersKind::Cons(right) => right.attributes(),
+        }
+    }
+    /// Classifies a function declared with these specifiers by its `inline`,
+    /// `static` and `extern` specifiers.
+    pub fn inline_linkage(&self) -> InlineLinkage {
+        let mut is_inline = false;
+        let mut is_static = false;
+        let mut is_extern = false;
+        for specifier in self.iter() {
+            match &specifier.kind {
+                DeclarationSpecifierKind::Function(FunctionSpecifier {
+                    kind: FunctionSpecifierKind::Inline,
+                    ..
+                }) => is_inline = true,
+                DeclarationSpecifierKind::StorageClass(StorageClassSpecifier {
+                    kind: StorageClassSpecifierKind::Static,
+                    ..
+                }) => is_static = true,
+                DeclarationSpecifierKind::StorageClass(StorageClassSpecifier {
+                    kind: StorageClassSpecifierKind::Extern,
+                    ..
+                }) => is_extern = true,
+                _ => (),
+            }
+        }
+
+        match (is_inline, is_static, is_extern) {
+            (true, true, _) => InlineLinkage::StaticInline,
+            (true, false, true) => InlineLinkage::ExternInline,
+            (true, false, false) => InlineLinkage::Inline,
+            (false, true, _) => InlineLinkage::Static,
+            (false, false, _) => InlineLinkage::External,
+        }
+    }
+    /// Returns the type specifiers and qualifiers in source order.
+    pub fn type_specifier_qualifiers(&self) -> impl Iterator<Item = &TypeSpecifierQualifier<'a>> {
+        self.iter().filter_map(|s| match &s.kind {
+            DeclarationSpecifierKind::Type(t) => Some(t),
+            _ => None,
+        })
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DeclarationSpecifier<'a> {
@@ -327,6 +832,19 @@ pub enum StorageClassSpecifierKind {
     ThreadLocal,
     Typedef,
 }
+impl fmt::Display for StorageClassSpecifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StorageClassSpecifierKind::Auto => "auto",
+            StorageClassSpecifierKind::Constexpr => "constexpr",
+            StorageClassSpecifierKind::Extern => "extern",
+            StorageClassSpecifierKind::Register => "register",
+            StorageClassSpecifierKind::Static => "static",
+            StorageClassSpecifierKind::ThreadLocal => "thread_local",
+            StorageClassSpecifierKind::Typedef => "typedef",
+        })
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypeSpecifier<'a> {
@@ -356,6 +874,15 @@ pub enum TypeSpecifierKind<'a> {
     Decimal32,
     Decimal64,
     Decimal128,
+    Float16,
+    Float32,
+    Float64,
+    Float128,
+    Float32x,
+    Float64x,
+    Float128x,
+    /// The GNU `__int128`.
+    Int128,
     Atomic(AtomicTypeSpecifier<'a>),
     StructOrUnion(StructOrUnionSpecifier<'a>),
     Enum(EnumSpecifier<'a>),
@@ -397,6 +924,14 @@ pub enum StructOrUnion {
     Struct,
     Union,
 }
+impl fmt::Display for StructOrUnion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StructOrUnion::Struct => "struct",
+            StructOrUnion::Union => "union",
+        })
+    }
+}
 
 pub type MemberDeclarationList<'a> = List<MemberDeclaration<'a>>;
 
@@ -427,6 +962,28 @@ pub enum SpecifierQualifierListKind<'a> {
     Leaf(Option<AttributeSpecifierSequence<'a>>),
     Cons(Box<SpecifierQualifierList<'a>>),
 }
+impl<'a> SpecifierQualifierList<'a> {
+    /// Returns the specifiers and qualifiers in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &TypeSpecifierQualifier<'a>> {
+        let mut items = Vec::new();
+        let mut list = self;
+        loop {
+            items.push(&*list.specifier_qualifier);
+            match &list.kind {
+                SpecifierQualifierListKind::Leaf(_) => break,
+                SpecifierQualifierListKind::Cons(right) => list = right,
+            }
+        }
+        items.into_iter()
+    }
+    /// Returns the attributes trailing the last specifier, if any.
+    pub fn attributes(&self) -> Option<&AttributeSpecifierSequence<'a>> {
+        match &self.kind {
+            SpecifierQualifierListKind::Leaf(attributes) => attributes.as_ref(),
+            SpecifierQualifierListKind::Cons(right) => right.attributes(),
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypeSpecifierQualifier<'a> {
@@ -542,6 +1099,16 @@ pub enum TypeQualifierKind {
     Volatile,
     Atomic,
 }
+impl fmt::Display for TypeQualifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TypeQualifierKind::Const => "const",
+            TypeQualifierKind::Restrict => "restrict",
+            TypeQualifierKind::Volatile => "volatile",
+            TypeQualifierKind::Atomic => "_Atomic",
+        })
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct FunctionSpecifier {
@@ -554,6 +1121,14 @@ pub enum FunctionSpecifierKind {
     Inline,
     NoReturn,
 }
+impl fmt::Display for FunctionSpecifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FunctionSpecifierKind::Inline => "inline",
+            FunctionSpecifierKind::NoReturn => "_Noreturn",
+        })
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AlignmentSpecifier<'a> {
@@ -574,6 +1149,28 @@ pub struct Declarator<'a> {
     pub at: At,
     pub pointer: Option<Pointer<'a>>,
     pub direct: DirectDeclarator<'a>,
+    /// A GNU `asm("name")` clause naming the register or assembler symbol.
+    pub asm_label: Option<AsmLabel<'a>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsmLabel<'a> {
+    pub at: At,
+    pub asm_keyword: At,
+    pub open_parenthesis: At,
+    /// The adjacent string literals that are concatenated into the name.
+    pub name: List<StringLiteral<'a>>,
+    pub close_parenthesis: At,
+}
+impl AsmLabel<'_> {
+    /// Returns the code units of the concatenated name.
+    pub fn decode(&self) -> Result<Vec<u32>, CharValueErr> {
+        let mut units = Vec::new();
+        for literal in self.name.iter() {
+            units.extend(literal.decode()?);
+        }
+        Ok(units)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -595,6 +1192,71 @@ pub enum DirectDeclaratorKind<'a> {
         Option<AttributeSpecifierSequence<'a>>,
     ),
 }
+impl<'a> Declarator<'a> {
+    /// Returns the declared identifier and its position.
+    pub fn name(&self) -> (&'a str, At) {
+        self.direct.name()
+    }
+    /// Returns the type derivations applied to the declared identifier,
+    /// starting with the one closest to the identifier.
+    ///
+    /// For `int *f(void)` this is `[Function, Pointer]`,
+    /// for `int (*f)(void)` it is `[Pointer, Function]`.
+    pub fn derivations(&self) -> Vec<Derivation<'_, 'a>> {
+        let mut derivations = self.direct.derivations();
+        push_pointer_derivations(&mut derivations, self.pointer.as_ref());
+        derivations
+    }
+    /// Returns the function declarator if this declarator declares a function.
+    pub fn function(&self) -> Option<&FunctionDeclarator<'a>> {
+        match self.derivations().first() {
+            Some(Derivation::Function(function)) => Some(function),
+            _ => None,
+        }
+    }
+}
+impl<'a> DirectDeclarator<'a> {
+    /// Returns the declared identifier and its position.
+    pub fn name(&self) -> (&'a str, At) {
+        match &self.kind {
+            DirectDeclaratorKind::Name(name, _) => (name, self.at),
+            DirectDeclaratorKind::Parenthesized { inner, .. } => inner.name(),
+            DirectDeclaratorKind::Array(array, _) => array.left.name(),
+            DirectDeclaratorKind::Function(function, _) => function.left.name(),
+        }
+    }
+    fn derivations(&self) -> Vec<Derivation<'_, 'a>> {
+        match &self.kind {
+            DirectDeclaratorKind::Name(..) => Vec::new(),
+            DirectDeclaratorKind::Parenthesized { inner, .. } => inner.derivations(),
+            DirectDeclaratorKind::Array(array, _) => {
+                let mut derivations = array.left.derivations();
+                derivations.push(Derivation::Array(array));
+                derivations
+            }
+            DirectDeclaratorKind::Function(function, _) => {
+                let mut derivations = function.left.derivations();
+                derivations.push(Derivation::Function(function));
+                derivations
+            }
+        }
+    }
+}
+fn push_pointer_derivations<'p, 'a>(
+    derivations: &mut Vec<Derivation<'p, 'a>>,
+    pointer: Option<&'p Pointer<'a>>,
+) {
+    let levels = pointer.map(Pointer::levels).unwrap_or_default();
+    derivations.extend(levels.into_iter().rev().map(Derivation::Pointer));
+}
+
+/// A single pointer, array or function derivation of a declarator.
+#[derive(Copy, Clone, Debug)]
+pub enum Derivation<'d, 'a> {
+    Pointer(&'d Pointer<'a>),
+    Array(&'d ArrayDeclarator<'a>),
+    Function(&'d FunctionDeclarator<'a>),
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ArrayDeclarator<'a> {
@@ -634,6 +1296,22 @@ pub struct Pointer<'a> {
     pub right: Option<Box<Pointer<'a>>>,
 }
 
+impl<'a> Pointer<'a> {
+    /// Returns this pointer and the ones to its right in source order.
+    ///
+    /// In `int * const * restrict p` these are `* const` and `* restrict`; the last
+    /// applies to `p` itself and the first to the pointer `p` points to.
+    pub fn levels(&self) -> Vec<&Pointer<'a>> {
+        let mut levels = Vec::new();
+        let mut pointer = Some(self);
+        while let Some(p) = pointer {
+            levels.push(p);
+            pointer = p.right.as_deref();
+        }
+        levels
+    }
+}
+
 pub type TypeQualifierList = List<TypeQualifier>;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -774,6 +1452,24 @@ pub enum DesignatorKind<'a> {
     },
 }
 
+/// The member operand of `__builtin_offsetof`, such as `a.b[2].c`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberDesignator<'a> {
+    pub at: At,
+    pub name: &'a str,
+    pub designators: Option<DesignatorList<'a>>,
+}
+
+/// A `_Pragma("...")` operator left in the token stream by the preprocessor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PragmaOperator<'a> {
+    pub at: At,
+    pub pragma_keyword: At,
+    pub open_parenthesis: At,
+    pub directive: StringLiteral<'a>,
+    pub close_parenthesis: At,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StaticAssertDeclaration<'a> {
     pub at: At,
@@ -792,6 +1488,19 @@ pub struct AttributeSpecifierSequence<'a> {
     pub specifier: AttributeSpecifier<'a>,
 }
 
+impl<'a> AttributeSpecifierSequence<'a> {
+    /// Returns the attribute specifiers in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &AttributeSpecifier<'a>> {
+        let mut items = Vec::new();
+        let mut sequence = Some(self);
+        while let Some(s) = sequence {
+            items.push(&s.specifier);
+            sequence = s.left.as_deref();
+        }
+        items.into_iter().rev()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AttributeSpecifier<'a> {
     pub at: At,
@@ -913,6 +1622,13 @@ pub enum LabelKind<'a> {
         case_keyword: At,
         value: Expression<'a>,
     },
+    /// A GNU `case low ... high:` covering every value in the inclusive range.
+    CaseRange {
+        case_keyword: At,
+        low: Expression<'a>,
+        ellipses: At,
+        high: Expression<'a>,
+    },
     Default {
         default_keyword: At,
     },
@@ -1030,6 +1746,12 @@ pub enum JumpStatementKind<'a> {
         goto_keyword: At,
         target: &'a str,
     },
+    /// A GNU `goto *expression` jumping to a label address.
+    ComputedGoto {
+        goto_keyword: At,
+        asterisk: At,
+        target: Expression<'a>,
+    },
     Continue {
         continue_keyword: At,
     },
@@ -1070,3 +1792,29 @@ pub struct FunctionDefinition<'a> {
     pub declarator: Declarator<'a>,
     pub body: CompoundStatement<'a>,
 }
+impl FunctionDefinition<'_> {
+    /// Classifies the definition by its `inline`, `static` and `extern` specifiers.
+    pub fn inline_linkage(&self) -> InlineLinkage {
+        self.specifiers.inline_linkage()
+    }
+}
+
+/// How a function is linked, as determined by the specifiers of one of its
+/// declarations or its definition alone.
+///
+/// An earlier declaration of the same function can still turn an `Inline`
+/// definition into an external one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InlineLinkage {
+    /// An external definition without `inline`.
+    External,
+    /// A definition with internal linkage, without `inline`.
+    Static,
+    /// `inline` without `extern`: an inline definition, which does not
+    /// provide an external definition of the function.
+    Inline,
+    /// `static inline`: an inline definition with internal linkage.
+    StaticInline,
+    /// `extern inline`: an external definition that may also be inlined.
+    ExternInline,
+}
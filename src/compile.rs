@@ -0,0 +1,32 @@
+use std::fmt;
+
+use crate::{lexer::LexError, parser::ParseErr};
+
+/// Any failure on the way from a source file to a syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompileError<'a> {
+    /// The preprocessor could not be run or rejected the source, with its message.
+    Preprocess(String),
+    Lex(LexError),
+    Parse(ParseErr<'a>),
+}
+impl From<LexError> for CompileError<'_> {
+    fn from(err: LexError) -> Self {
+        Self::Lex(err)
+    }
+}
+impl<'a> From<ParseErr<'a>> for CompileError<'a> {
+    fn from(err: ParseErr<'a>) -> Self {
+        Self::Parse(err)
+    }
+}
+impl fmt::Display for CompileError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::Preprocess(message) => write!(f, "preprocessing failed: {message}"),
+            CompileError::Lex(err) => err.fmt(f),
+            CompileError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+impl std::error::Error for CompileError<'_> {}
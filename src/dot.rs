@@ -0,0 +1,204 @@
+use std::fmt::Write;
+
+use crate::{
+    ast::*,
+    visit::{Node, Visitor},
+};
+
+/// Renders a translation unit as a Graphviz DOT graph.
+///
+/// Every node is labelled with its kind and the line and column it starts at.
+pub fn to_dot(unit: &TranslationUnit) -> String {
+    let mut exporter = DotExporter {
+        out: String::from("digraph ast {\n    node [shape=box];\n"),
+        stack: Vec::new(),
+        next_id: 0,
+    };
+    exporter.visit_translation_unit(unit);
+    exporter.out.push_str("}\n");
+    exporter.out
+}
+
+struct DotExporter {
+    out: String,
+    stack: Vec<usize>,
+    next_id: usize,
+}
+impl<'a> Visitor<'a> for DotExporter {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let at = node.at();
+        let label = escape(&format!("{}\n{}:{}", label(node), at.line, at.column));
+        writeln!(self.out, "    n{id} [label=\"{label}\"];").unwrap();
+        if let Some(parent) = self.stack.last() {
+            writeln!(self.out, "    n{parent} -> n{id};").unwrap();
+        }
+        self.stack.push(id);
+    }
+    fn leave(&mut self, _node: Node<'_, 'a>) {
+        self.stack.pop();
+    }
+}
+
+fn label(node: Node) -> String {
+    match node {
+        Node::TranslationUnit(_) => "TranslationUnit".to_string(),
+        Node::ExternalDeclaration(_) => "ExternalDeclaration".to_string(),
+        Node::FunctionDefinition(_) => "FunctionDefinition".to_string(),
+        Node::Declaration(d) => match d.kind {
+            DeclarationKind::Normal { .. } => "Declaration".to_string(),
+            DeclarationKind::Assert(_) => "Declaration Assert".to_string(),
+            DeclarationKind::Attribute(_) => "Declaration Attribute".to_string(),
+            DeclarationKind::Pragma(_) => "Declaration Pragma".to_string(),
+        },
+        Node::DeclarationSpecifier(_) => "DeclarationSpecifier".to_string(),
+        Node::InitDeclarator(_) => "InitDeclarator".to_string(),
+        Node::AttributeDeclaration(_) => "AttributeDeclaration".to_string(),
+        Node::StorageClassSpecifier(s) => format!("StorageClassSpecifier {:?}", s.kind),
+        Node::TypeSpecifierQualifier(_) => "TypeSpecifierQualifier".to_string(),
+        Node::TypeSpecifier(s) => match &s.kind {
+            TypeSpecifierKind::BitInt { .. } => "TypeSpecifier BitInt".to_string(),
+            TypeSpecifierKind::Atomic(_) => "TypeSpecifier Atomic".to_string(),
+            TypeSpecifierKind::StructOrUnion(_) => "TypeSpecifier StructOrUnion".to_string(),
+            TypeSpecifierKind::Enum(_) => "TypeSpecifier Enum".to_string(),
+            TypeSpecifierKind::Typeof(_) => "TypeSpecifier Typeof".to_string(),
+            TypeSpecifierKind::TypedefName(name) => format!("TypeSpecifier TypedefName {name}"),
+            kind => format!("TypeSpecifier {kind:?}"),
+        },
+        Node::TypeQualifier(q) => format!("TypeQualifier {:?}", q.kind),
+        Node::FunctionSpecifier(f) => format!("FunctionSpecifier {:?}", f.kind),
+        Node::AlignmentSpecifier(_) => "AlignmentSpecifier".to_string(),
+        Node::StructOrUnionSpecifier(s) => match s.tag {
+            Some(tag) => format!("{:?} {tag}", s.struct_or_union.1),
+            None => format!("{:?}", s.struct_or_union.1),
+        },
+        Node::MemberDeclaration(_) => "MemberDeclaration".to_string(),
+        Node::MemberDeclarator(_) => "MemberDeclarator".to_string(),
+        Node::EnumSpecifier(e) => match e.tag {
+            Some(tag) => format!("Enum {tag}"),
+            None => "Enum".to_string(),
+        },
+        Node::Enumerator(e) => format!("Enumerator {}", e.name),
+        Node::AtomicTypeSpecifier(_) => "AtomicTypeSpecifier".to_string(),
+        Node::TypeofSpecifier(t) if t.unqual => "TypeofSpecifier unqual".to_string(),
+        Node::TypeofSpecifier(_) => "TypeofSpecifier".to_string(),
+        Node::Declarator(_) => "Declarator".to_string(),
+        Node::DirectDeclarator(d) => match &d.kind {
+            DirectDeclaratorKind::Name(name, _) => format!("DirectDeclarator Name {name}"),
+            DirectDeclaratorKind::Parenthesized { .. } => {
+                "DirectDeclarator Parenthesized".to_string()
+            }
+            DirectDeclaratorKind::Array(..) => "DirectDeclarator Array".to_string(),
+            DirectDeclaratorKind::Function(..) => "DirectDeclarator Function".to_string(),
+        },
+        Node::Pointer(_) => "Pointer".to_string(),
+        Node::ParameterTypeList(p) if p.ellipses.is_some() => "ParameterTypeList ...".to_string(),
+        Node::ParameterTypeList(_) => "ParameterTypeList".to_string(),
+        Node::ParameterDeclaration(_) => "ParameterDeclaration".to_string(),
+        Node::TypeName(_) => "TypeName".to_string(),
+        Node::AbstractDeclarator(_) => "AbstractDeclarator".to_string(),
+        Node::DirectAbstractDeclarator(d) => match &d.kind {
+            DirectAbstractDeclaratorKind::Parenthesized { .. } => {
+                "DirectAbstractDeclarator Parenthesized".to_string()
+            }
+            DirectAbstractDeclaratorKind::Array(..) => "DirectAbstractDeclarator Array".to_string(),
+            DirectAbstractDeclaratorKind::Function(..) => {
+                "DirectAbstractDeclarator Function".to_string()
+            }
+        },
+        Node::Initializer(_) => "Initializer".to_string(),
+        Node::BracedInitializer(_) => "BracedInitializer".to_string(),
+        Node::Designator(d) => match &d.kind {
+            DesignatorKind::InBrackets { .. } => "Designator []".to_string(),
+            DesignatorKind::AfterPeriod { name, .. } => format!("Designator .{name}"),
+        },
+        Node::StaticAssertDeclaration(_) => "StaticAssertDeclaration".to_string(),
+        Node::PragmaOperator(p) => format!("Pragma \"{}\"", p.directive.literal),
+        Node::AttributeSpecifier(_) => "AttributeSpecifier".to_string(),
+        Node::Attribute(a) => match a.token.prefix {
+            Some((prefix, _)) => format!("Attribute {prefix}::{}", a.token.token),
+            None => format!("Attribute {}", a.token.token),
+        },
+        Node::Statement(_) => "Statement".to_string(),
+        Node::Label(l) => match &l.kind {
+            LabelKind::Name(name) => format!("Label {name}"),
+            LabelKind::Case { .. } => "Label Case".to_string(),
+            LabelKind::CaseRange { .. } => "Label CaseRange".to_string(),
+            LabelKind::Default { .. } => "Label Default".to_string(),
+        },
+        Node::UnlabeledStatement(_) => "UnlabeledStatement".to_string(),
+        Node::CompoundStatement(_) => "CompoundStatement".to_string(),
+        Node::BlockItem(_) => "BlockItem".to_string(),
+        Node::ExpressionStatement(_) => "ExpressionStatement".to_string(),
+        Node::SelectionStatement(s) => match s.kind {
+            SelectionStatementKind::If { .. } => "If".to_string(),
+            SelectionStatementKind::Switch { .. } => "Switch".to_string(),
+        },
+        Node::IterationStatement(s) => match s.kind {
+            IterationStatementKind::While { .. } => "While".to_string(),
+            IterationStatementKind::DoWhile { .. } => "DoWhile".to_string(),
+            IterationStatementKind::For { .. } => "For".to_string(),
+        },
+        Node::JumpStatement(j) => match &j.kind {
+            JumpStatementKind::Goto { target, .. } => format!("Goto {target}"),
+            JumpStatementKind::ComputedGoto { .. } => "ComputedGoto".to_string(),
+            JumpStatementKind::Continue { .. } => "Continue".to_string(),
+            JumpStatementKind::Break { .. } => "Break".to_string(),
+            JumpStatementKind::Return { .. } => "Return".to_string(),
+        },
+        Node::Expression(e) => expression_label(e),
+        Node::GenericAssociation(a) => match a.kind {
+            GenericAssociationKind::Default { .. } => "GenericAssociation default".to_string(),
+            GenericAssociationKind::ForType(_) => "GenericAssociation".to_string(),
+        },
+    }
+}
+fn expression_label(expression: &Expression) -> String {
+    match &expression.kind {
+        ExpressionKind::Identifier(name) => format!("Identifier {name}"),
+        ExpressionKind::Integer(int) => format!("Integer {}", int.source),
+        ExpressionKind::Float(float) => format!("Float {float}"),
+        ExpressionKind::String(string) => format!("String \"{}\"", string.literal),
+        ExpressionKind::Character(character) => format!("Character '{}'", character.literal),
+        ExpressionKind::Bool(value) => format!("Bool {value}"),
+        ExpressionKind::Nullptr => "Nullptr".to_string(),
+        ExpressionKind::Parenthesized { .. } => "Parenthesized".to_string(),
+        ExpressionKind::GenericSelection(_) => "GenericSelection".to_string(),
+        ExpressionKind::StatementExpression { .. } => "StatementExpression".to_string(),
+        ExpressionKind::Index { .. } => "Index".to_string(),
+        ExpressionKind::Call { .. } => "Call".to_string(),
+        ExpressionKind::Member { name, .. } => format!("Member .{name}"),
+        ExpressionKind::MemberIndirect { name, .. } => format!("MemberIndirect ->{name}"),
+        ExpressionKind::PostIncrement { .. } => "PostIncrement".to_string(),
+        ExpressionKind::PostDecrement { .. } => "PostDecrement".to_string(),
+        ExpressionKind::CompoundLiteral(_) => "CompoundLiteral".to_string(),
+        ExpressionKind::PreIncrement { .. } => "PreIncrement".to_string(),
+        ExpressionKind::PreDecrement { .. } => "PreDecrement".to_string(),
+        ExpressionKind::Unary(operator, _) => format!("Unary {operator:?}"),
+        ExpressionKind::LabelAddress(label) => format!("LabelAddress &&{label}"),
+        ExpressionKind::Sizeof { .. } => "Sizeof".to_string(),
+        ExpressionKind::Alignof { .. } => "Alignof".to_string(),
+        ExpressionKind::Offsetof { member, .. } => format!("Offsetof {}", member.name),
+        ExpressionKind::TypesCompatible { .. } => "TypesCompatible".to_string(),
+        ExpressionKind::Cast { .. } => "Cast".to_string(),
+        ExpressionKind::Binary { operator, .. } => format!("Binary {:?}", operator.1),
+        ExpressionKind::Conditional { .. } => "Conditional".to_string(),
+        ExpressionKind::Assign { operator, .. } => format!("Assign {:?}", operator.1),
+        ExpressionKind::Comma { .. } => "Comma".to_string(),
+    }
+}
+
+fn escape(label: &str) -> String {
+    let mut out = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,624 @@
+use crate::{
+    ast::*,
+    symbols::SymbolTable,
+    token::{At, IntegerFormat, IntegerSuffix, IntegerToken, StringEncoding},
+    types::{BaseType, Type, TypeKind, canonicalize},
+};
+
+/// Describes the target and how [`Evaluator`] treats signed overflow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvalConfig {
+    pub overflow: Overflow,
+    pub data_model: DataModel,
+    pub char_is_signed: bool,
+}
+impl Default for EvalConfig {
+    fn default() -> Self {
+        Self {
+            overflow: Overflow::Error,
+            data_model: DataModel::LP64,
+            char_is_signed: true,
+        }
+    }
+}
+
+/// What happens when a signed result, or a shift count, is out of range for its type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Report the constant expression as invalid.
+    #[default]
+    Error,
+    /// Wrap around modulo two to the power of the type's width.
+    Wrap,
+    /// Clamp to the smallest or largest value of the type.
+    Saturate,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DataModel {
+    ILP32,
+    #[default]
+    LP64,
+    LLP64,
+}
+impl DataModel {
+    /// Returns the width of `ty` in bits.
+    pub fn width(self, ty: IntegerType) -> u32 {
+        match ty {
+            IntegerType::Bool
+            | IntegerType::Char
+            | IntegerType::SignedChar
+            | IntegerType::UnsignedChar => 8,
+            IntegerType::Short | IntegerType::UnsignedShort => 16,
+            IntegerType::Int | IntegerType::UnsignedInt => 32,
+            IntegerType::Long | IntegerType::UnsignedLong => match self {
+                DataModel::LP64 => 64,
+                DataModel::ILP32 | DataModel::LLP64 => 32,
+            },
+            IntegerType::LongLong | IntegerType::UnsignedLongLong => 64,
+        }
+    }
+    /// Returns the width of a pointer in bits.
+    pub fn pointer_width(self) -> u32 {
+        match self {
+            DataModel::ILP32 => 32,
+            DataModel::LP64 | DataModel::LLP64 => 64,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IntegerType {
+    Bool,
+    Char,
+    SignedChar,
+    UnsignedChar,
+    Short,
+    UnsignedShort,
+    Int,
+    UnsignedInt,
+    Long,
+    UnsignedLong,
+    LongLong,
+    UnsignedLongLong,
+}
+impl IntegerType {
+    fn rank(self) -> u8 {
+        match self {
+            IntegerType::Bool => 0,
+            IntegerType::Char | IntegerType::SignedChar | IntegerType::UnsignedChar => 1,
+            IntegerType::Short | IntegerType::UnsignedShort => 2,
+            IntegerType::Int | IntegerType::UnsignedInt => 3,
+            IntegerType::Long | IntegerType::UnsignedLong => 4,
+            IntegerType::LongLong | IntegerType::UnsignedLongLong => 5,
+        }
+    }
+    fn to_unsigned(self) -> Self {
+        match self {
+            IntegerType::Char | IntegerType::SignedChar => IntegerType::UnsignedChar,
+            IntegerType::Short => IntegerType::UnsignedShort,
+            IntegerType::Int => IntegerType::UnsignedInt,
+            IntegerType::Long => IntegerType::UnsignedLong,
+            IntegerType::LongLong => IntegerType::UnsignedLongLong,
+            unsigned => unsigned,
+        }
+    }
+}
+
+/// The value of an integer constant expression together with its type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Value {
+    pub value: i128,
+    pub ty: IntegerType,
+}
+impl Value {
+    fn int(value: bool) -> Self {
+        Self {
+            value: value as i128,
+            ty: IntegerType::Int,
+        }
+    }
+    fn bool(value: bool) -> Self {
+        Self {
+            value: value as i128,
+            ty: IntegerType::Bool,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalErr {
+    pub at: At,
+    pub kind: EvalErrKind,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EvalErrKind {
+    NotConstant,
+    Overflow,
+    DivisionByZero,
+    InvalidShift,
+    /// An integer literal is too large for every type it may have.
+    LiteralTooLarge,
+    InvalidCharacter(CharValueErr),
+    /// The expression is constant, but its type is not an integer type, as with `nullptr`.
+    NotInteger,
+    /// The expression is constant, but evaluating it is not supported yet.
+    Unsupported,
+    /// A member designator names no member of the type it is applied to,
+    /// names a bit-field, or subscripts something other than an array.
+    InvalidMemberDesignator,
+}
+
+type Res = Result<Value, EvalErr>;
+
+/// Evaluates integer constant expressions.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Evaluator {
+    config: EvalConfig,
+}
+impl Evaluator {
+    pub fn new(config: EvalConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn evaluate(&self, expression: &Expression) -> Res {
+        let at = expression.at;
+        match &expression.kind {
+            ExpressionKind::Integer(int) => self.integer(at, int),
+            ExpressionKind::Character(character) => self.character(character),
+            ExpressionKind::Bool(value) => Ok(Value::bool(*value)),
+            ExpressionKind::Nullptr => Err(EvalErr {
+                at,
+                kind: EvalErrKind::NotInteger,
+            }),
+            ExpressionKind::Parenthesized { inner, .. } => self.evaluate(inner),
+            ExpressionKind::Unary(operator, right) => self.unary(at, *operator, right),
+            ExpressionKind::Binary {
+                left,
+                operator: (operator_at, operator),
+                right,
+            } => self.binary(*operator_at, left, *operator, right),
+            ExpressionKind::Conditional {
+                condition,
+                then_value,
+                else_value,
+                ..
+            } => {
+                let (chosen, other) = if self.truth(condition)? {
+                    (then_value, else_value)
+                } else {
+                    (else_value, then_value)
+                };
+                let chosen = self.evaluate(chosen)?;
+                // The unevaluated operand still takes part in determining the result type.
+                let ty = match self.evaluate(other) {
+                    Ok(other) => self.common_type(chosen.ty, other.ty),
+                    Err(_) => self.promote(chosen.ty),
+                };
+                Ok(self.convert(chosen, ty))
+            }
+            ExpressionKind::Cast {
+                type_name, right, ..
+            } => {
+                let ty = integer_type(type_name).ok_or(EvalErr {
+                    at: type_name.at,
+                    kind: EvalErrKind::NotConstant,
+                })?;
+                if ty == IntegerType::Bool {
+                    return Ok(Value::bool(self.truth(right)?));
+                }
+                let right = self.evaluate(right)?;
+                Ok(self.convert(right, ty))
+            }
+            ExpressionKind::Sizeof { .. }
+            | ExpressionKind::Alignof { .. }
+            | ExpressionKind::Offsetof { .. }
+            | ExpressionKind::TypesCompatible { .. } => Err(EvalErr {
+                at,
+                kind: EvalErrKind::Unsupported,
+            }),
+            _ => Err(EvalErr {
+                at,
+                kind: EvalErrKind::NotConstant,
+            }),
+        }
+    }
+
+    /// Returns the byte offset `__builtin_offsetof` gives for `member` within
+    /// an object of type `object`, such as that of `a.b[2].c`.
+    pub fn offsetof<'a>(
+        &self,
+        object: &Type<'a>,
+        member: &MemberDesignator<'a>,
+        symbols: &SymbolTable<'a>,
+    ) -> Result<u64, EvalErr> {
+        let data_model = self.config.data_model;
+        let err = |at, kind| EvalErr { at, kind };
+
+        let mut ty = object.clone();
+        let mut offset: i128 = 0;
+        let first = (member.at, Some(member.name), None);
+        let rest = member
+            .designators
+            .iter()
+            .flat_map(|d| d.iter())
+            .map(|d| match &d.kind {
+                DesignatorKind::AfterPeriod { name, .. } => (d.at, Some(*name), None),
+                DesignatorKind::InBrackets { value, .. } => (d.at, None, Some(value)),
+            });
+        for (at, name, index) in std::iter::once(first).chain(rest) {
+            if let Some(name) = name {
+                let (member_offset, member) = symbols
+                    .member_offset(&ty, name, data_model)
+                    .filter(|(_, member)| member.width.is_none())
+                    .ok_or(err(at, EvalErrKind::InvalidMemberDesignator))?;
+                offset += i128::from(member_offset);
+                ty = member.ty.clone().ok_or(err(at, EvalErrKind::NotConstant))?;
+            } else if let Some(index) = index {
+                let TypeKind::Array { element, .. } = ty.kind else {
+                    return Err(err(at, EvalErrKind::InvalidMemberDesignator));
+                };
+                let size = symbols
+                    .layout(&element, data_model)
+                    .ok_or(err(at, EvalErrKind::NotConstant))?
+                    .size;
+                offset = (self.evaluate(index)?.value)
+                    .checked_mul(i128::from(size))
+                    .and_then(|scaled| offset.checked_add(scaled))
+                    .ok_or(err(at, EvalErrKind::Overflow))?;
+                ty = *element;
+            }
+        }
+        u64::try_from(offset).map_err(|_| err(member.at, EvalErrKind::Overflow))
+    }
+
+    /// Evaluates a scalar constant in a context that only compares it against zero,
+    /// in which `nullptr` is a null pointer and therefore false.
+    fn truth(&self, expression: &Expression) -> Result<bool, EvalErr> {
+        match &expression.kind {
+            ExpressionKind::Nullptr => Ok(false),
+            ExpressionKind::Parenthesized { inner, .. } => self.truth(inner),
+            _ => Ok(self.evaluate(expression)?.value != 0),
+        }
+    }
+    fn integer(&self, at: At, int: &IntegerToken) -> Res {
+        if let Some(IntegerSuffix::BitPrecise | IntegerSuffix::BitPreciseUnsigned) = int.suffix {
+            return Err(EvalErr {
+                at,
+                kind: EvalErrKind::Unsupported,
+            });
+        }
+
+        let ty = literal_type(int, self.config.data_model).ok_or(EvalErr {
+            at,
+            kind: EvalErrKind::LiteralTooLarge,
+        })?;
+        Ok(Value {
+            value: int.value().unwrap() as i128,
+            ty,
+        })
+    }
+    fn character(&self, character: &CharLiteral) -> Res {
+        let value = character.value().map_err(|err| EvalErr {
+            at: character.at,
+            kind: EvalErrKind::InvalidCharacter(err),
+        })?;
+
+        let value = match character.encoding {
+            // A single plain character has the value of a `char` converted to `int`.
+            StringEncoding::None if value <= 0xFF => Value {
+                value: value as i128,
+                ty: IntegerType::Char,
+            },
+            StringEncoding::None => Value {
+                value: value as i128,
+                ty: IntegerType::UnsignedInt,
+            },
+            StringEncoding::UTF8 => Value {
+                value: value as i128,
+                ty: IntegerType::UnsignedChar,
+            },
+            StringEncoding::UTF16 => Value {
+                value: value as i128,
+                ty: IntegerType::UnsignedShort,
+            },
+            StringEncoding::UTF32 => Value {
+                value: value as i128,
+                ty: IntegerType::UnsignedInt,
+            },
+            StringEncoding::Wide => Value {
+                value: value as i128,
+                ty: match self.config.data_model {
+                    DataModel::LLP64 => IntegerType::UnsignedShort,
+                    DataModel::ILP32 | DataModel::LP64 => IntegerType::Int,
+                },
+            },
+        };
+
+        let value = self.wrap(value.value, value.ty);
+        Ok(match character.encoding {
+            StringEncoding::None => self.convert(value, IntegerType::Int),
+            _ => value,
+        })
+    }
+
+    fn unary(&self, at: At, operator: UnaryOperator, right: &Expression) -> Res {
+        if operator == UnaryOperator::LogicalNot {
+            return Ok(Value::int(!self.truth(right)?));
+        }
+        let right = self.evaluate(right)?;
+        let ty = self.promote(right.ty);
+        match operator {
+            UnaryOperator::Positive => Ok(Value {
+                value: right.value,
+                ty,
+            }),
+            UnaryOperator::Negative => self.fit(at, -right.value, ty),
+            UnaryOperator::BitNot => Ok(self.wrap(!right.value, ty)),
+            UnaryOperator::LogicalNot => unreachable!(),
+            UnaryOperator::AddressOf | UnaryOperator::Dereference => Err(EvalErr {
+                at,
+                kind: EvalErrKind::NotConstant,
+            }),
+        }
+    }
+    fn binary(
+        &self,
+        at: At,
+        left: &Expression,
+        operator: BinaryOperator,
+        right: &Expression,
+    ) -> Res {
+        match operator {
+            BinaryOperator::LogicalAnd => {
+                return Ok(Value::int(self.truth(left)? && self.truth(right)?));
+            }
+            BinaryOperator::LogicalOr => {
+                return Ok(Value::int(self.truth(left)? || self.truth(right)?));
+            }
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                return self.shift(at, left, operator, right);
+            }
+            _ => (),
+        }
+
+        let left = self.evaluate(left)?;
+        let right = self.evaluate(right)?;
+        let ty = self.common_type(left.ty, right.ty);
+        let (l, r) = (self.convert(left, ty).value, self.convert(right, ty).value);
+
+        let division_by_zero = EvalErr {
+            at,
+            kind: EvalErrKind::DivisionByZero,
+        };
+        let value = match operator {
+            BinaryOperator::Add => l + r,
+            BinaryOperator::Subtract => l - r,
+            BinaryOperator::Multiply => match l.checked_mul(r) {
+                Some(value) => value,
+                None if !self.is_signed(ty) || self.config.overflow == Overflow::Wrap => {
+                    return Ok(self.wrap(l.wrapping_mul(r), ty));
+                }
+                None => return self.overflow(at, ty, (l < 0) != (r < 0)),
+            },
+            BinaryOperator::Divide if r == 0 => return Err(division_by_zero),
+            BinaryOperator::Divide => l / r,
+            BinaryOperator::Modulo if r == 0 => return Err(division_by_zero),
+            BinaryOperator::Modulo => l % r,
+            BinaryOperator::BitAnd => l & r,
+            BinaryOperator::BitOr => l | r,
+            BinaryOperator::BitXor => l ^ r,
+            BinaryOperator::Less => return Ok(Value::int(l < r)),
+            BinaryOperator::Greater => return Ok(Value::int(l > r)),
+            BinaryOperator::LessEqual => return Ok(Value::int(l <= r)),
+            BinaryOperator::GreaterEqual => return Ok(Value::int(l >= r)),
+            BinaryOperator::Equal => return Ok(Value::int(l == r)),
+            BinaryOperator::NotEqual => return Ok(Value::int(l != r)),
+            BinaryOperator::LogicalAnd
+            | BinaryOperator::LogicalOr
+            | BinaryOperator::ShiftLeft
+            | BinaryOperator::ShiftRight => unreachable!(),
+        };
+        self.fit(at, value, ty)
+    }
+    fn shift(&self, at: At, left: Value, operator: BinaryOperator, right: Value) -> Res {
+        let ty = self.promote(left.ty);
+        let width = self.config.data_model.width(ty);
+        let count = right.value;
+        if count < 0 || (count >= width as i128 && self.config.overflow == Overflow::Error) {
+            return Err(EvalErr {
+                at,
+                kind: EvalErrKind::InvalidShift,
+            });
+        }
+
+        if operator == BinaryOperator::ShiftRight {
+            return Ok(Value {
+                value: left.value >> count.min(127),
+                ty,
+            });
+        }
+
+        let shifted = u32::try_from(count)
+            .ok()
+            .and_then(|count| 1i128.checked_shl(count).filter(|&f| f > 0))
+            .and_then(|factor| left.value.checked_mul(factor));
+        match shifted {
+            Some(value) => self.fit(at, value, ty),
+            None if left.value == 0 => Ok(Value { value: 0, ty }),
+            None if !self.is_signed(ty) || self.config.overflow == Overflow::Wrap => {
+                // Every bit of the operand has been shifted out.
+                Ok(Value { value: 0, ty })
+            }
+            None => self.overflow(at, ty, left.value < 0),
+        }
+    }
+
+    /// Returns `value` as a `ty`, applying the overflow policy if it is out of range.
+    fn fit(&self, at: At, value: i128, ty: IntegerType) -> Res {
+        if (self.min(ty)..=self.max(ty)).contains(&value) {
+            Ok(Value { value, ty })
+        } else if !self.is_signed(ty) || self.config.overflow == Overflow::Wrap {
+            Ok(self.wrap(value, ty))
+        } else {
+            self.overflow(at, ty, value < 0)
+        }
+    }
+    fn overflow(&self, at: At, ty: IntegerType, negative: bool) -> Res {
+        match self.config.overflow {
+            Overflow::Error => Err(EvalErr {
+                at,
+                kind: EvalErrKind::Overflow,
+            }),
+            Overflow::Wrap => unreachable!("wrapping results never overflow"),
+            Overflow::Saturate => Ok(Value {
+                value: if negative { self.min(ty) } else { self.max(ty) },
+                ty,
+            }),
+        }
+    }
+    /// Reduces `value` modulo two to the power of the width of `ty`.
+    fn wrap(&self, value: i128, ty: IntegerType) -> Value {
+        if ty == IntegerType::Bool {
+            return Value {
+                value: (value != 0) as i128,
+                ty,
+            };
+        }
+
+        let width = self.config.data_model.width(ty);
+        let modulus = 1i128 << width;
+        let mut value = value.rem_euclid(modulus);
+        if self.is_signed(ty) && value > self.max(ty) {
+            value -= modulus;
+        }
+        Value { value, ty }
+    }
+    fn convert(&self, value: Value, ty: IntegerType) -> Value {
+        self.wrap(value.value, ty)
+    }
+
+    fn promote(&self, ty: IntegerType) -> IntegerType {
+        if ty.rank() < IntegerType::Int.rank() {
+            IntegerType::Int
+        } else {
+            ty
+        }
+    }
+    /// Applies the usual arithmetic conversions to two integer types.
+    fn common_type(&self, left: IntegerType, right: IntegerType) -> IntegerType {
+        let (left, right) = (self.promote(left), self.promote(right));
+        if left == right {
+            return left;
+        }
+
+        let (signed, unsigned) = match (self.is_signed(left), self.is_signed(right)) {
+            (true, true) | (false, false) => {
+                return if left.rank() >= right.rank() {
+                    left
+                } else {
+                    right
+                };
+            }
+            (true, false) => (left, right),
+            (false, true) => (right, left),
+        };
+        if unsigned.rank() >= signed.rank() {
+            unsigned
+        } else if self.config.data_model.width(signed) > self.config.data_model.width(unsigned) {
+            signed
+        } else {
+            signed.to_unsigned()
+        }
+    }
+
+    fn is_signed(&self, ty: IntegerType) -> bool {
+        match ty {
+            IntegerType::Char => self.config.char_is_signed,
+            IntegerType::SignedChar
+            | IntegerType::Short
+            | IntegerType::Int
+            | IntegerType::Long
+            | IntegerType::LongLong => true,
+            _ => false,
+        }
+    }
+    fn min(&self, ty: IntegerType) -> i128 {
+        if self.is_signed(ty) {
+            -(1i128 << (self.config.data_model.width(ty) - 1))
+        } else {
+            0
+        }
+    }
+    fn max(&self, ty: IntegerType) -> i128 {
+        let width = self.config.data_model.width(ty);
+        match ty {
+            IntegerType::Bool => 1,
+            _ if self.is_signed(ty) => (1i128 << (width - 1)) - 1,
+            _ => (1i128 << width) - 1,
+        }
+    }
+}
+
+/// Returns the type of the integer literal `int`: the first type permitted by its suffix
+/// and base that can represent its value.
+///
+/// Returns `None` if no permitted type can, or if the suffix is bit-precise.
+pub fn literal_type(int: &IntegerToken, data_model: DataModel) -> Option<IntegerType> {
+    use IntegerType::*;
+
+    let decimal = int.format == IntegerFormat::Decimal;
+    let candidates: &[IntegerType] = match (int.suffix, decimal) {
+        (None, true) => &[Int, Long, LongLong],
+        (None, false) => &[
+            Int,
+            UnsignedInt,
+            Long,
+            UnsignedLong,
+            LongLong,
+            UnsignedLongLong,
+        ],
+        (Some(IntegerSuffix::Unsigned), _) => &[UnsignedInt, UnsignedLong, UnsignedLongLong],
+        (Some(IntegerSuffix::Long), true) => &[Long, LongLong],
+        (Some(IntegerSuffix::Long), false) => &[Long, UnsignedLong, LongLong, UnsignedLongLong],
+        (Some(IntegerSuffix::LongUnsigned), _) => &[UnsignedLong, UnsignedLongLong],
+        (Some(IntegerSuffix::LongLong), true) => &[LongLong],
+        (Some(IntegerSuffix::LongLong), false) => &[LongLong, UnsignedLongLong],
+        (Some(IntegerSuffix::LongLongUnsigned), _) => &[UnsignedLongLong],
+        (Some(IntegerSuffix::BitPrecise | IntegerSuffix::BitPreciseUnsigned), _) => &[],
+    };
+
+    let value = int.value()?;
+    candidates.iter().copied().find(|&ty| {
+        let width = data_model.width(ty);
+        let unsigned = matches!(ty, UnsignedInt | UnsignedLong | UnsignedLongLong);
+        value < 1 << (width - !unsigned as u32)
+    })
+}
+
+/// Returns the integer type named by `type_name`, if it names one.
+fn integer_type(type_name: &TypeName) -> Option<IntegerType> {
+    if type_name.declarator.is_some() {
+        return None;
+    }
+
+    let ty = canonicalize(type_name.specifier_qualifiers.iter()).ok()?;
+    Some(match ty.base {
+        BaseType::Bool => IntegerType::Bool,
+        BaseType::Char => IntegerType::Char,
+        BaseType::SignedChar => IntegerType::SignedChar,
+        BaseType::UnsignedChar => IntegerType::UnsignedChar,
+        BaseType::Short => IntegerType::Short,
+        BaseType::UnsignedShort => IntegerType::UnsignedShort,
+        BaseType::Int => IntegerType::Int,
+        BaseType::UnsignedInt => IntegerType::UnsignedInt,
+        BaseType::Long => IntegerType::Long,
+        BaseType::UnsignedLong => IntegerType::UnsignedLong,
+        BaseType::LongLong => IntegerType::LongLong,
+        BaseType::UnsignedLongLong => IntegerType::UnsignedLongLong,
+        _ => return None,
+    })
+}
@@ -1,28 +1,81 @@
+use std::{collections::HashMap, fmt, ops::Range, sync::LazyLock};
+
 use crate::token::{
-    At, Files, IntegerFormat, IntegerSuffix, IntegerToken, StringEncoding, Token, TokenKind,
+    At, Files, FloatFormat, FloatSuffix, FloatToken, IntegerFormat, IntegerSuffix, IntegerToken,
+    StringEncoding, Token, TokenKind,
 };
 
+const DUMMY_FILE: &str = "<dummy file; this should never appear; lexer bug>";
+
+/// The revision of the C standard whose lexical grammar is followed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Standard {
+    C11,
+    C17,
+    #[default]
+    C23,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexerOptions {
+    /// The distance between tab stops; a tab advances the column to the next stop.
+    pub tab_width: u32,
+    /// Whether comments are emitted as `LineComment` and `BlockComment` tokens
+    /// instead of being skipped like whitespace.
+    pub keep_comments: bool,
+    /// Before C23, `u8'a'` is the identifier `u8` followed by a character constant.
+    pub standard: Standard,
+}
+impl Default for LexerOptions {
+    fn default() -> Self {
+        Self {
+            tab_width: 1,
+            keep_comments: false,
+            standard: Standard::default(),
+        }
+    }
+}
+
+/// Splits source text, usually the output of the preprocessor, into tokens.
+///
+/// A line marker `# line "file"` sets the line and file of the line following it,
+/// wherever the marker appears. Columns always count characters of the lexed text,
+/// so on a line produced by a macro expansion they need not match the original
+/// source, which markers say nothing about.
 pub struct Lexer<'a> {
     src: &'a str,
     index: usize,
     at: At,
     files: Files,
+    options: LexerOptions,
+    /// The position of each line start, including those following line markers.
+    line_starts: Vec<At>,
 }
 impl<'a> Lexer<'a> {
     pub fn new(src: &'a str) -> Self {
-        let mut files = Files::new();
-        let dummy_file = files.get_file_id("<dummy file; this should never appear; lexer bug>");
-
+        Self::with_options(src, LexerOptions::default())
+    }
+    pub fn with_options(src: &'a str, options: LexerOptions) -> Self {
         Self {
             src,
             index: 0,
-            at: At::new(dummy_file, 1, 1),
-            files,
+            at: At::new(0, 1, 1, 0),
+            files: Files::new(),
+            options,
+            line_starts: Vec::new(),
         }
     }
 
-    pub fn lex(mut self) -> (Vec<Token<'a>>, Files) {
+    pub fn lex(self) -> (Vec<Token<'a>>, Files) {
         let mut tokens = Vec::new();
+        let mut files = Files::new();
+        self.lex_into(&mut tokens, &mut files);
+        (tokens, files)
+    }
+    /// Appends the tokens of the source to `tokens`, interning file names into `files`.
+    pub fn lex_into(mut self, tokens: &mut Vec<Token<'a>>, files: &mut Files) {
+        self.files = std::mem::take(files);
+        self.start();
 
         while !self.is_eof() {
             let Some(token) = self.lex_next() else {
@@ -30,93 +83,256 @@ impl<'a> Lexer<'a> {
             };
             tokens.push(token);
         }
+        self.push_eof(tokens);
+
+        *files = self.files;
+    }
+    /// Lexes the source after `edit` was applied to `old_src`, reusing the `old_tokens`
+    /// lexed from `old_src` wherever the edit cannot have affected them.
+    ///
+    /// The result is the same as that of [`Lexer::lex_into`] with the same `files`.
+    pub fn relex(
+        mut self,
+        old_src: &str,
+        old_tokens: &[Token],
+        edit: &Edit,
+        files: &mut Files,
+    ) -> Vec<Token<'a>> {
+        debug_assert_eq!(
+            self.src.len() + edit.range.len(),
+            old_src.len() + edit.replacement.len()
+        );
+        self.files = std::mem::take(files);
+        let delta = edit.replacement.len() as isize - edit.range.len() as isize;
 
+        // No token looks further ahead than the line after the one it starts on,
+        // so tokens starting before the line preceding the edit are unaffected.
+        // The last of them is lexed again, as its position is known to be correct.
+        let line_start = |offset: usize| old_src[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let unaffected = line_start(line_start(edit.range.start).saturating_sub(1));
+        let kept = old_tokens
+            .iter()
+            .take_while(|t| t.at.offset < unaffected && t.kind != TokenKind::Eof)
+            .count()
+            .saturating_sub(1);
+        let mut tokens: Vec<_> = old_tokens[..kept]
+            .iter()
+            .map(|&t| rebase_token(t, old_src, self.src, 0))
+            .collect();
+        match old_tokens.get(kept) {
+            Some(token) if token.at.offset < unaffected => {
+                self.at = token.at;
+                self.index = self.at.offset;
+            }
+            _ => self.start(),
+        }
+
+        // Once a token starts after the edit in the same position as an old token,
+        // lexing proceeds exactly as before and the remaining old tokens are reused.
+        let edit_end = edit.range.start + edit.replacement.len();
+        let mut old = kept;
+        while !self.is_eof() {
+            let Some(token) = self.lex_next() else {
+                continue;
+            };
+            if token.at.offset >= edit_end {
+                let old_offset = token.at.offset.checked_add_signed(-delta).unwrap();
+                while old_tokens
+                    .get(old)
+                    .is_some_and(|t| t.at.offset < old_offset)
+                {
+                    old += 1;
+                }
+                let moved = At {
+                    offset: old_offset,
+                    ..token.at
+                };
+                if old_tokens.get(old).is_some_and(|t| t.at == moved) {
+                    tokens.extend(
+                        old_tokens[old..]
+                            .iter()
+                            .map(|&t| rebase_token(t, old_src, self.src, delta)),
+                    );
+                    *files = self.files;
+                    return tokens;
+                }
+            }
+            tokens.push(token);
+        }
+        self.push_eof(&mut tokens);
+
+        *files = self.files;
+        tokens
+    }
+    /// Moves to the start of the source, skipping a byte order mark.
+    fn start(&mut self) {
+        let dummy_file = self.files.get_file_id(DUMMY_FILE);
+        self.index = if self.src.starts_with('\u{FEFF}') {
+            '\u{FEFF}'.len_utf8()
+        } else {
+            0
+        };
+        self.at = At::new(dummy_file, 1, 1, self.index);
+        self.line_starts.push(self.at);
+    }
+    fn push_eof(&mut self, tokens: &mut Vec<Token<'a>>) {
         let eof_file = self.files.get_file_id("<EOF>");
         tokens.push(Token {
-            at: At::new(eof_file, 1, 1),
+            at: At::new(eof_file, 1, 1, self.src.len()),
             kind: TokenKind::Eof,
+            end: self.src.len(),
         });
-
-        (tokens, self.files)
     }
     fn lex_next(&mut self) -> Option<Token<'a>> {
         if self.cur() == '\n' {
-            self.next();
-            self.at.next_line();
+            self.next_line();
             None
         } else if self.cur().is_whitespace() {
             self.next();
             None
         } else if self.cur() == '#' {
-            self.process_line_directive();
-            None
+            self.process_line_directive()
+        } else if self.matches("//") || self.matches("/*") {
+            let token = self.lex_comment();
+            let is_comment = matches!(
+                token.kind,
+                TokenKind::LineComment(_) | TokenKind::BlockComment(_)
+            );
+            (self.options.keep_comments || !is_comment).then_some(token)
         } else {
             Some(self.lex_token())
         }
     }
-    fn process_line_directive(&mut self) {
-        self.take('#');
-        self.take(' ');
-        let line = self.src[self.index..].split_whitespace().next().unwrap();
-        self.index += line.len();
-        self.take(' ');
-        self.take('"');
-        let file = self.src[self.index..].split('"').next().unwrap();
-        self.index += file.len();
-        self.take('"');
-        let rest_line = self.src[self.index..].split('\n').next().unwrap();
-        self.index += rest_line.len();
-        self.take('\n');
-
-        let line: u32 = line.parse().unwrap();
-        let file = self.files.get_file_id(file);
-        self.at = At::new(file, line, 1);
+    /// Lexes a comment, or returns an error token if a block comment is not terminated.
+    fn lex_comment(&mut self) -> Token<'a> {
+        let at = self.at;
+        let is_line = self.matches("//");
+        self.advance(2);
+        let start = self.index;
+
+        let kind = if is_line {
+            while !self.is_eof() && self.cur() != '\n' {
+                self.next();
+            }
+            TokenKind::LineComment(&self.src[start..self.index])
+        } else {
+            while !self.is_eof() && !self.matches("*/") {
+                if self.cur() == '\n' {
+                    self.next_line();
+                } else {
+                    self.next();
+                }
+            }
+            if self.is_eof() {
+                TokenKind::Error
+            } else {
+                let text = &self.src[start..self.index];
+                self.advance(2);
+                TokenKind::BlockComment(text)
+            }
+        };
+        Token {
+            at,
+            kind,
+            end: self.index,
+        }
+    }
+    /// Applies a `# line "file"` marker, or returns an error token spanning
+    /// the rest of the line if it is malformed.
+    fn process_line_directive(&mut self) -> Option<Token<'a>> {
+        let at = self.at;
+        let line_end = self.src[self.index..]
+            .find('\n')
+            .map_or(self.src.len(), |i| self.index + i);
+        let directive = &self.src[self.index..line_end];
+
+        let Some((line, file, system)) = parse_line_marker(directive) else {
+            self.advance(directive.chars().count());
+            return Some(Token {
+                at,
+                kind: TokenKind::Error,
+                end: self.index,
+            });
+        };
+        self.index = (line_end + 1).min(self.src.len());
+        let file = file.map_or(at.file, |file| self.files.get_file_id(file));
+        if system {
+            self.files.mark_system_header(file);
+        }
+        self.at = At::new(file, line, 1, self.index);
+        self.line_starts.push(self.at);
+        None
     }
     fn lex_token(&mut self) -> Token<'a> {
         let at = self.at;
+        let kind = self.lex_token_kind();
+        Token {
+            at,
+            kind,
+            end: self.index,
+        }
+    }
+    fn lex_token_kind(&mut self) -> TokenKind<'a> {
+        // Checked first, as a floating constant may begin with `.`.
+        if self.is_float_literal() {
+            return self.lex_float_literal();
+        }
 
-        for &(pattern, kind) in TOKEN_MAP {
+        let first = self.src.as_bytes().get(self.index).copied().unwrap_or(0);
+        let candidates = PUNCTUATORS_BY_FIRST_BYTE.get(usize::from(first));
+        for &(pattern, kind) in candidates.into_iter().flatten() {
             if self.matches(pattern) {
                 let length = pattern.chars().count();
                 self.advance(length);
-                return Token { at, kind };
+                return kind;
             }
         }
 
         if self.is_string_literal() {
             self.lex_string_literal()
-        } else if self.matches("0x") || self.matches("0X") && self.peek(2).is_ascii_hexdigit() {
+        } else if self.is_character_literal() {
+            self.lex_character_literal()
+        } else if (self.matches("0x") || self.matches("0X")) && self.peek(2).is_ascii_hexdigit() {
             self.lex_hexadecimal_literal()
-        } else if self.matches("0b")
-            || self.matches("0B") && self.peek(2) == '0'
-            || self.peek(2) == '1'
-        {
+        } else if (self.matches("0b") || self.matches("0B")) && is_binary_digit(self.peek(2)) {
             self.lex_binary_literal()
         } else if self.matches("0") {
             self.lex_octal_literal()
         } else if self.cur().is_ascii_digit() {
             self.lex_decimal_literal()
-        } else if self.cur().is_ascii_alphabetic() {
+        } else if self.cur().is_ascii_alphabetic() || self.cur() == '_' {
             self.lex_identifier()
         } else {
             self.next();
-            Token {
-                at,
-                kind: TokenKind::Error,
-            }
+            TokenKind::Error
         }
     }
-    fn lex_string_literal(&mut self) -> Token<'a> {
+    fn lex_string_literal(&mut self) -> TokenKind<'a> {
         let encoding = self.lex_string_encoding();
-
-        let at = self.at;
-        self.next();
+        match self.lex_quoted('"') {
+            Some(src) => TokenKind::String(src, encoding),
+            None => TokenKind::Error,
+        }
+    }
+    fn lex_character_literal(&mut self) -> TokenKind<'a> {
+        let encoding = self.lex_string_encoding();
+        match self.lex_quoted('\'') {
+            Some(src) => TokenKind::Character(src, encoding),
+            None => TokenKind::Error,
+        }
+    }
+    /// Lexes the body of a literal delimited by `quote`, leaving escapes undecoded.
+    /// Returns `None` if the literal is not terminated on the same line.
+    fn lex_quoted(&mut self, quote: char) -> Option<&'a str> {
+        self.take(quote);
         let start = self.index;
         loop {
-            if self.matches("\\\"") {
-                self.advance(2);
-            } else if self.matches("\"") {
+            if self.is_eof() || self.cur() == '\n' {
+                return None;
+            } else if self.cur() == quote {
                 break;
+            } else if self.cur() == '\\' && self.index + 1 < self.src.len() {
+                self.advance(2);
             } else {
                 self.next();
             }
@@ -124,15 +340,103 @@ impl<'a> Lexer<'a> {
         let end = self.index;
         self.next();
 
-        let src = &self.src[start..end];
+        Some(&self.src[start..end])
+    }
+    /// Returns whether a floating constant, rather than an integer constant, starts here.
+    fn is_float_literal(&self) -> bool {
+        let rest = &self.src[self.index..];
+        if !rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return false;
+        }
+        let (rest, radix, exponent) = match rest.strip_prefix("0x").or(rest.strip_prefix("0X")) {
+            Some(rest) => (rest, 16, ['p', 'P']),
+            None => (rest, 10, ['e', 'E']),
+        };
 
-        Token {
-            at,
-            kind: TokenKind::String(src, encoding),
+        let fraction = rest.trim_start_matches(|c: char| c.is_digit(radix) || c == '\'');
+        let has_digits = fraction.len() < rest.len();
+        match fraction.chars().next() {
+            Some('.') => has_digits || fraction[1..].starts_with(|c: char| c.is_digit(radix)),
+            Some(c) => has_digits && exponent.contains(&c),
+            None => false,
         }
     }
-    fn lex_hexadecimal_literal(&mut self) -> Token<'a> {
-        let at = self.at;
+    fn lex_float_literal(&mut self) -> TokenKind<'a> {
+        let (format, radix, exponent) = if self.matches("0x") || self.matches("0X") {
+            self.advance(2);
+            (FloatFormat::Hexadecimal, 16, ['p', 'P'])
+        } else {
+            (FloatFormat::Decimal, 10, ['e', 'E'])
+        };
+
+        let start = self.index;
+        self.skip_digits(radix);
+        if self.cur() == '.' {
+            self.next();
+            self.skip_digits(radix);
+        }
+        // The exponent is mandatory for hexadecimal constants.
+        if exponent.contains(&self.cur()) {
+            self.next();
+            if matches!(self.cur(), '+' | '-') {
+                self.next();
+            }
+            if !self.cur().is_ascii_digit() {
+                return self.lex_invalid_number();
+            }
+            self.skip_digits(10);
+        } else if format == FloatFormat::Hexadecimal {
+            return self.lex_invalid_number();
+        }
+        let end = self.index;
+
+        let decimal = [
+            ("df", FloatSuffix::Decimal32),
+            ("dd", FloatSuffix::Decimal64),
+            ("dl", FloatSuffix::Decimal128),
+        ];
+        let decimal = decimal
+            .into_iter()
+            .find(|(suffix, _)| self.matches(suffix) || self.matches(&suffix.to_ascii_uppercase()));
+        let suffix = if let Some((_, suffix)) = decimal {
+            // Decimal floating constants have no hexadecimal form.
+            if format == FloatFormat::Hexadecimal {
+                return self.lex_invalid_number();
+            }
+            self.advance(2);
+            Some(suffix)
+        } else if self.matches("f") || self.matches("F") {
+            self.next();
+            Some(FloatSuffix::Float)
+        } else if self.matches("l") || self.matches("L") {
+            self.next();
+            Some(FloatSuffix::LongDouble)
+        } else {
+            None
+        };
+        if is_identifier_char(self.cur()) || self.cur() == '.' {
+            return self.lex_invalid_number();
+        }
+
+        TokenKind::Floating(FloatToken {
+            source: &self.src[start..end],
+            format,
+            suffix,
+        })
+    }
+    /// Skips the rest of a malformed numeric constant, so it forms a single error token.
+    fn lex_invalid_number(&mut self) -> TokenKind<'a> {
+        while is_identifier_char(self.cur()) || self.cur() == '.' {
+            self.next();
+        }
+        TokenKind::Error
+    }
+    fn skip_digits(&mut self, radix: u32) {
+        while self.cur().is_digit(radix) || self.cur() == '\'' {
+            self.next();
+        }
+    }
+    fn lex_hexadecimal_literal(&mut self) -> TokenKind<'a> {
         self.advance(2);
         let start = self.index;
         while self.cur().is_ascii_hexdigit() || self.cur() == '\'' {
@@ -144,17 +448,13 @@ impl<'a> Lexer<'a> {
 
         let suffix = self.lex_integer_suffix();
 
-        Token {
-            at,
-            kind: TokenKind::Integer(IntegerToken {
-                source: src,
-                format: IntegerFormat::Hexadecimal,
-                suffix,
-            }),
-        }
+        TokenKind::Integer(IntegerToken {
+            source: src,
+            format: IntegerFormat::Hexadecimal,
+            suffix,
+        })
     }
-    fn lex_binary_literal(&mut self) -> Token<'a> {
-        let at = self.at;
+    fn lex_binary_literal(&mut self) -> TokenKind<'a> {
         self.advance(2);
         let start = self.index;
         while is_binary_digit(self.cur()) || self.cur() == '\'' {
@@ -166,17 +466,13 @@ impl<'a> Lexer<'a> {
 
         let suffix = self.lex_integer_suffix();
 
-        Token {
-            at,
-            kind: TokenKind::Integer(IntegerToken {
-                source: src,
-                format: IntegerFormat::Binary,
-                suffix,
-            }),
-        }
+        TokenKind::Integer(IntegerToken {
+            source: src,
+            format: IntegerFormat::Binary,
+            suffix,
+        })
     }
-    fn lex_octal_literal(&mut self) -> Token<'a> {
-        let at = self.at;
+    fn lex_octal_literal(&mut self) -> TokenKind<'a> {
         let start = self.index;
         while is_octal_digit(self.cur()) || self.cur() == '\'' {
             self.next();
@@ -187,17 +483,13 @@ impl<'a> Lexer<'a> {
 
         let suffix = self.lex_integer_suffix();
 
-        Token {
-            at,
-            kind: TokenKind::Integer(IntegerToken {
-                source: src,
-                format: IntegerFormat::Octal,
-                suffix,
-            }),
-        }
+        TokenKind::Integer(IntegerToken {
+            source: src,
+            format: IntegerFormat::Octal,
+            suffix,
+        })
     }
-    fn lex_decimal_literal(&mut self) -> Token<'a> {
-        let at = self.at;
+    fn lex_decimal_literal(&mut self) -> TokenKind<'a> {
         let start = self.index;
         while self.cur().is_ascii_digit() || self.cur() == '\'' {
             self.next();
@@ -208,17 +500,13 @@ impl<'a> Lexer<'a> {
 
         let suffix = self.lex_integer_suffix();
 
-        Token {
-            at,
-            kind: TokenKind::Integer(IntegerToken {
-                source: src,
-                format: IntegerFormat::Decimal,
-                suffix,
-            }),
-        }
+        TokenKind::Integer(IntegerToken {
+            source: src,
+            format: IntegerFormat::Decimal,
+            suffix,
+        })
     }
-    fn lex_identifier(&mut self) -> Token<'a> {
-        let at = self.at;
+    fn lex_identifier(&mut self) -> TokenKind<'a> {
         let start = self.index;
         while self.cur().is_ascii_alphanumeric() || self.cur() == '_' {
             self.next();
@@ -227,27 +515,22 @@ impl<'a> Lexer<'a> {
         let end = self.index;
         let src = &self.src[start..end];
 
-        Token {
-            at,
-            kind: TokenKind::Identifier(src),
+        match KEYWORDS.get(src) {
+            Some(&kind) => kind,
+            None => TokenKind::Identifier(src),
         }
     }
 
     fn lex_integer_suffix(&mut self) -> Option<IntegerSuffix> {
-        if self.matches("u") || self.matches("U") {
-            self.next();
-            Some(IntegerSuffix::Unsigned)
-        } else if self.matches("ul")
-            || self.matches("UL")
-            || self.matches("uL")
-            || self.matches("Ul")
-        {
-            self.advance(2);
-            Some(IntegerSuffix::LongUnsigned)
-        } else if self.matches("ull")
+        // Longer suffixes are checked first, so that `ull` is not lexed as `u`.
+        if self.matches("ull")
             || self.matches("ULL")
             || self.matches("uLL")
             || self.matches("Ull")
+            || self.matches("llu")
+            || self.matches("LLU")
+            || self.matches("llU")
+            || self.matches("LLu")
         {
             self.advance(3);
             Some(IntegerSuffix::LongLongUnsigned)
@@ -255,13 +538,18 @@ impl<'a> Lexer<'a> {
             || self.matches("UWB")
             || self.matches("uWB")
             || self.matches("Uwb")
+            || self.matches("wbu")
+            || self.matches("WBU")
+            || self.matches("wbU")
+            || self.matches("WBu")
         {
             self.advance(3);
             Some(IntegerSuffix::BitPreciseUnsigned)
-        } else if self.matches("l") || self.matches("L") {
-            self.next();
-            Some(IntegerSuffix::Long)
-        } else if self.matches("lu")
+        } else if self.matches("ul")
+            || self.matches("UL")
+            || self.matches("uL")
+            || self.matches("Ul")
+            || self.matches("lu")
             || self.matches("LU")
             || self.matches("lU")
             || self.matches("Lu")
@@ -271,23 +559,15 @@ impl<'a> Lexer<'a> {
         } else if self.matches("ll") || self.matches("LL") {
             self.advance(2);
             Some(IntegerSuffix::LongLong)
-        } else if self.matches("llu")
-            || self.matches("LLU")
-            || self.matches("llU")
-            || self.matches("LLu")
-        {
-            self.advance(3);
-            Some(IntegerSuffix::LongLongUnsigned)
         } else if self.matches("wb") || self.matches("WB") {
             self.advance(2);
             Some(IntegerSuffix::BitPrecise)
-        } else if self.matches("wbu")
-            || self.matches("WBU")
-            || self.matches("wbU")
-            || self.matches("WBu")
-        {
-            self.advance(3);
-            Some(IntegerSuffix::BitPreciseUnsigned)
+        } else if self.matches("u") || self.matches("U") {
+            self.next();
+            Some(IntegerSuffix::Unsigned)
+        } else if self.matches("l") || self.matches("L") {
+            self.next();
+            Some(IntegerSuffix::Long)
         } else {
             None
         }
@@ -299,14 +579,25 @@ impl<'a> Lexer<'a> {
             || self.matches("U\"")
             || self.matches("L\"")
     }
+    fn is_character_literal(&self) -> bool {
+        self.matches("'")
+            || (self.options.standard >= Standard::C23 && self.matches("u8'"))
+            || self.matches("u'")
+            || self.matches("U'")
+            || self.matches("L'")
+    }
     fn lex_string_encoding(&mut self) -> StringEncoding {
         if self.matches("u8") {
+            self.advance(2);
             StringEncoding::UTF8
         } else if self.matches("u") {
+            self.next();
             StringEncoding::UTF16
         } else if self.matches("U") {
+            self.next();
             StringEncoding::UTF32
         } else if self.matches("L") {
+            self.next();
             StringEncoding::Wide
         } else {
             StringEncoding::None
@@ -323,26 +614,159 @@ impl<'a> Lexer<'a> {
     fn next(&mut self) {
         self.advance(1);
     }
+    fn next_line(&mut self) {
+        self.next();
+        self.at.next_line();
+        self.line_starts.push(self.at);
+    }
     fn advance(&mut self, by: usize) {
-        let bytes: usize = self.src[self.index..]
-            .chars()
-            .take(by)
-            .map(|c| c.len_utf8())
-            .sum();
-        self.index += bytes;
-        self.at.next_column(by as u32);
+        for c in self.src[self.index..].chars().take(by) {
+            self.index += c.len_utf8();
+            advance_column(&mut self.at, c, self.options.tab_width);
+        }
+        self.at.offset = self.index;
     }
     fn cur(&self) -> char {
         self.peek(0)
     }
+    /// Returns the character `offset` ahead, or `'\0'` past the end of the source.
     fn peek(&self, offset: usize) -> char {
-        self.src[self.index..].chars().skip(offset).next().unwrap()
+        self.src[self.index..].chars().nth(offset).unwrap_or('\0')
     }
     fn is_eof(&self) -> bool {
         self.index >= self.src.len()
     }
 }
 
+fn advance_column(at: &mut At, c: char, tab_width: u32) {
+    let tab_width = tab_width.max(1);
+    if c == '\t' {
+        let column = at.column - 1;
+        at.next_column(tab_width - column % tab_width);
+    } else {
+        at.next_column(1);
+    }
+}
+
+/// Translates byte offsets in a source buffer to the positions the lexer reports for them.
+#[derive(Clone, Debug)]
+pub struct SourceMap<'a> {
+    src: &'a str,
+    tab_width: u32,
+    line_starts: Vec<At>,
+}
+impl<'a> SourceMap<'a> {
+    /// Lexes `src` to find its lines and line markers, interning file names into `files`
+    /// as [`Lexer::lex_into`] does.
+    pub fn new(src: &'a str, options: LexerOptions, files: &mut Files) -> Self {
+        let tab_width = options.tab_width;
+        let mut lexer = Lexer::with_options(src, options);
+        lexer.files = std::mem::take(files);
+        lexer.start();
+        while !lexer.is_eof() {
+            lexer.lex_next();
+        }
+        *files = lexer.files;
+
+        Self {
+            src,
+            tab_width,
+            line_starts: lexer.line_starts,
+        }
+    }
+
+    /// Returns the position of the byte at `offset`, with the line and file
+    /// remapped by any line marker before it.
+    pub fn offset_to_at(&self, offset: usize) -> At {
+        let mut offset = offset.clamp(self.line_starts[0].offset, self.src.len());
+        while !self.src.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self
+            .line_starts
+            .partition_point(|at| at.offset <= offset)
+            .saturating_sub(1);
+        let mut at = self.line_starts[line];
+        for c in self.src[at.offset..offset].chars() {
+            advance_column(&mut at, c, self.tab_width);
+        }
+        at.offset = offset;
+        at
+    }
+}
+
+/// Parses a line marker `# line "file" flags...`, in which the file name is optional.
+/// Also returns whether the flags include 3, which marks a system header.
+fn parse_line_marker(directive: &str) -> Option<(u32, Option<&str>, bool)> {
+    let rest = directive.strip_prefix('#')?.trim_start_matches([' ', '\t']);
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let line = rest[..digits].parse().ok()?;
+
+    let rest = rest[digits..].trim_start_matches([' ', '\t']);
+    if rest.is_empty() {
+        return Some((line, None, false));
+    }
+    let rest = rest.strip_prefix('"')?;
+    let end = rest.find('"')?;
+    let system = rest[end + 1..].split_whitespace().any(|flag| flag == "3");
+    Some((line, Some(&rest[..end]), system))
+}
+
+/// A token the lexer could not make sense of, such as a stray `@`, an unterminated
+/// literal or comment, or a malformed number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LexError {
+    pub at: At,
+    /// The byte offset just past the end of the invalid text.
+    pub end: usize,
+}
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: invalid token", self.at.line, self.at.column)
+    }
+}
+
+/// Returns the invalid tokens among `tokens`, in order.
+pub fn lex_errors<'t>(tokens: &'t [Token]) -> impl Iterator<Item = LexError> + 't {
+    tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Error)
+        .map(|t| LexError {
+            at: t.at,
+            end: t.end,
+        })
+}
+
+/// A change to a source buffer, replacing the bytes in `range` with `replacement`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit<'e> {
+    pub range: Range<usize>,
+    pub replacement: &'e str,
+}
+
+/// Moves `token` from `old_src` to the same text in `new_src`, `delta` bytes later.
+fn rebase_token<'a>(token: Token, old_src: &str, new_src: &'a str, delta: isize) -> Token<'a> {
+    let rebase = |text: &str| {
+        let offset = text.as_ptr() as usize - old_src.as_ptr() as usize;
+        let offset = offset.checked_add_signed(delta).unwrap();
+        &new_src[offset..offset + text.len()]
+    };
+    let kind = token.kind.map_source(rebase);
+    Token {
+        at: At {
+            offset: token.at.offset.checked_add_signed(delta).unwrap(),
+            ..token.at
+        },
+        kind,
+        end: token.end.checked_add_signed(delta).unwrap(),
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
 fn is_octal_digit(c: char) -> bool {
     matches!(c, '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7')
 }
@@ -350,7 +774,22 @@ fn is_binary_digit(c: char) -> bool {
     matches!(c, '0' | '1')
 }
 
-static TOKEN_MAP: &[(&'static str, TokenKind)] = &[
+/// The entries of [`PUNCTUATORS`] grouped by the first byte of their pattern,
+/// in their order there, so that lexing a token only tries those that can match.
+static PUNCTUATORS_BY_FIRST_BYTE: LazyLock<[Vec<(&str, TokenKind)>; 128]> = LazyLock::new(|| {
+    let mut table: [Vec<_>; 128] = std::array::from_fn(|_| Vec::new());
+    for &(pattern, kind) in PUNCTUATORS {
+        table[usize::from(pattern.as_bytes()[0])].push((pattern, kind));
+    }
+    table
+});
+
+/// Identifiers that are lexed as keywords instead.
+static KEYWORDS: LazyLock<HashMap<&str, TokenKind>> =
+    LazyLock::new(|| KEYWORD_SPELLINGS.iter().copied().collect());
+
+/// Longer punctuators come before their prefixes, which are tried in order.
+static PUNCTUATORS: &[(&str, TokenKind)] = &[
     ("...", TokenKind::Ellipses),
     ("<<=", TokenKind::DoubleLessEqual),
     (">>=", TokenKind::DoubleGreaterEqual),
@@ -371,7 +810,7 @@ static TOKEN_MAP: &[(&'static str, TokenKind)] = &[
     ("%=", TokenKind::PercentEqual),
     ("+=", TokenKind::PlusEqual),
     ("-=", TokenKind::MinusEqual),
-    ("&=", TokenKind::Ampersand),
+    ("&=", TokenKind::AmpersandEqual),
     ("^=", TokenKind::CaretEqual),
     ("|=", TokenKind::BarEqual),
     ("[", TokenKind::OpenBracket),
@@ -398,6 +837,9 @@ static TOKEN_MAP: &[(&'static str, TokenKind)] = &[
     (";", TokenKind::Semicolon),
     ("=", TokenKind::Equal),
     (",", TokenKind::Comma),
+];
+
+static KEYWORD_SPELLINGS: &[(&str, TokenKind)] = &[
     ("alignas", TokenKind::Alignas),
     ("alignof", TokenKind::Alignof),
     ("auto", TokenKind::Auto),
@@ -443,13 +885,25 @@ static TOKEN_MAP: &[(&'static str, TokenKind)] = &[
     ("void", TokenKind::Void),
     ("volatile", TokenKind::Volatile),
     ("while", TokenKind::While),
+    ("_Alignof", TokenKind::Alignof),
     ("_Atomic", TokenKind::Atomic),
+    ("_Bool", TokenKind::Bool),
     ("_BitInt", TokenKind::BitInt),
     ("_Complex", TokenKind::Complex),
     ("_Decimal128", TokenKind::Decimal128),
     ("_Decimal32", TokenKind::Decimal32),
     ("_Decimal64", TokenKind::Decimal64),
+    ("_Float16", TokenKind::Float16),
+    ("_Float32", TokenKind::Float32),
+    ("_Float64", TokenKind::Float64),
+    ("_Float128", TokenKind::Float128),
+    ("_Float32x", TokenKind::Float32x),
+    ("_Float64x", TokenKind::Float64x),
+    ("_Float128x", TokenKind::Float128x),
+    ("__int128", TokenKind::Int128),
     ("_Generic", TokenKind::Generic),
     ("_Imaginary", TokenKind::Imaginary),
     ("_Noreturn", TokenKind::Noreturn),
+    ("_Pragma", TokenKind::Pragma),
+    ("_Static_assert", TokenKind::StaticAssert),
 ];
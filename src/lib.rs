@@ -1,4 +1,17 @@
+#![allow(clippy::large_enum_variant)]
+
 pub mod ast;
+pub mod compile;
+pub mod dot;
+pub mod eval;
 pub mod lexer;
+pub mod lint;
+pub mod owned;
 pub mod parser;
+pub mod symbols;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod token;
+pub mod types;
+pub mod validate;
+pub mod visit;
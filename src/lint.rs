@@ -0,0 +1,566 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::*,
+    eval::{EvalConfig, Evaluator},
+    symbols::{SymbolKind, SymbolTable},
+    token::At,
+    types::{BaseType, Type, TypeKind, accessed_member, decay, expression_type},
+    visit::{self, Visitor},
+};
+
+/// Selects which of the opt-in lints [`lint`] runs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintOptions {
+    /// Warn when control can reach the end of a non-void function.
+    pub missing_return: bool,
+    /// Warn when a `switch` without `default` over an enumerated object misses enumerators.
+    pub enum_switch: bool,
+    /// Warn when a declaration uses a name reserved for the implementation.
+    ///
+    /// Preprocessed input includes the declarations of the headers it uses, which
+    /// are reported too; callers can drop those in files that
+    /// [`Files::is_system_header`](crate::token::Files::is_system_header) marks.
+    pub reserved_identifiers: bool,
+    /// Warn when the operands of a binary operator have types it does not accept,
+    /// such as a structure in `+` or a floating operand of `%`.
+    pub operand_types: bool,
+    /// Warn when a constant assigned to a bit-field does not fit its width.
+    pub bit_field_overflow: bool,
+    /// Warn when the address of a `register` object is taken, with `&` or by
+    /// an array decaying to a pointer to its first element.
+    pub register_address: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint<'a> {
+    pub at: At,
+    pub kind: LintKind<'a>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintKind<'a> {
+    MissingReturn { function: &'a str },
+    MissingEnumCases { missing: Vec<&'a str> },
+    ReservedIdentifier { name: &'a str },
+    InvalidOperands { operator: BinaryOperator },
+    BitFieldOverflow { member: &'a str, width: u64 },
+    AddressOfRegister { name: &'a str },
+    RegisterArrayDecay { name: &'a str },
+}
+
+pub fn lint<'a>(unit: &TranslationUnit<'a>, options: LintOptions) -> Vec<Lint<'a>> {
+    let symbols = SymbolTable::collect(unit);
+    let mut linter = Linter {
+        options,
+        symbols: &symbols,
+        lints: Vec::new(),
+        no_decay: HashSet::new(),
+    };
+    linter.visit_translation_unit(unit);
+
+    if options.reserved_identifiers {
+        for symbol in symbols.symbols() {
+            if is_reserved(symbol.name, symbol.depth == 0) {
+                linter.lints.push(Lint {
+                    at: symbol.at,
+                    kind: LintKind::ReservedIdentifier { name: symbol.name },
+                });
+            }
+        }
+    }
+
+    linter.lints
+}
+
+struct Linter<'s, 'a> {
+    options: LintOptions,
+    symbols: &'s SymbolTable<'a>,
+    lints: Vec<Lint<'a>>,
+    /// The identifiers that are operands of `&`, `sizeof` or `typeof`, where
+    /// arrays do not decay.
+    no_decay: HashSet<At>,
+}
+impl<'a> Linter<'_, 'a> {
+    fn check_missing_return(&mut self, function: &FunctionDefinition<'a>) {
+        let (name, _) = function.declarator.name();
+        if name == "main" || returns_void(function) || !compound_completes(&function.body) {
+            return;
+        }
+
+        self.lints.push(Lint {
+            at: function.body.close_brace,
+            kind: LintKind::MissingReturn { function: name },
+        });
+    }
+    fn check_enum_switch(
+        &mut self,
+        switch_keyword: At,
+        controlling: &Expression,
+        body: &Statement,
+    ) {
+        let ExpressionKind::Identifier(_) = controlling.without_parens().kind else {
+            return;
+        };
+        let Some(symbol) = self.symbols.resolve(controlling.without_parens().at) else {
+            return;
+        };
+        if !matches!(symbol.kind, SymbolKind::Object | SymbolKind::Parameter) {
+            return;
+        }
+        let Some(enumeration) = symbol.enumeration else {
+            return;
+        };
+
+        let mut labels = SwitchLabels::default();
+        labels.visit_statement(body);
+        // The enumerators a case range covers are not known without their values.
+        if labels.has_default || labels.has_range {
+            return;
+        }
+
+        let missing: Vec<_> = self
+            .symbols
+            .enumeration(enumeration)
+            .enumerators
+            .iter()
+            .map(|&(name, _)| name)
+            .filter(|name| !labels.cases.contains(name))
+            .collect();
+        if !missing.is_empty() {
+            self.lints.push(Lint {
+                at: switch_keyword,
+                kind: LintKind::MissingEnumCases { missing },
+            });
+        }
+    }
+    fn check_operand_types(
+        &mut self,
+        left: &Expression<'a>,
+        (at, operator): (At, BinaryOperator),
+        right: &Expression<'a>,
+    ) {
+        use BinaryOperator as B;
+
+        let operand = |expression| {
+            let ty = expression_type(expression, self.symbols)?;
+            Operand::classify(&decay(&ty), expression)
+        };
+        let (Some(left), Some(right)) = (operand(left), operand(right)) else {
+            return;
+        };
+        let is_valid = match operator {
+            B::Multiply | B::Divide => left.is_arithmetic() && right.is_arithmetic(),
+            B::Modulo | B::ShiftLeft | B::ShiftRight | B::BitAnd | B::BitXor | B::BitOr => {
+                left.is_integer() && right.is_integer()
+            }
+            B::Add => match (left, right) {
+                (Operand::Pointer, other) | (other, Operand::Pointer) => other.is_integer(),
+                _ => left.is_arithmetic() && right.is_arithmetic(),
+            },
+            B::Subtract => match (left, right) {
+                (Operand::Pointer, right) => right == Operand::Pointer || right.is_integer(),
+                _ => left.is_arithmetic() && right.is_arithmetic(),
+            },
+            B::Less | B::Greater | B::LessEqual | B::GreaterEqual => match (left, right) {
+                (Operand::Pointer, Operand::Pointer) => true,
+                _ => left.is_real() && right.is_real(),
+            },
+            B::Equal | B::NotEqual => match (left, right) {
+                (Operand::Pointer, other) | (other, Operand::Pointer) => {
+                    matches!(other, Operand::Pointer | Operand::NullPointerConstant)
+                }
+                _ => left.is_arithmetic() && right.is_arithmetic(),
+            },
+            B::LogicalAnd | B::LogicalOr => left.is_scalar() && right.is_scalar(),
+        };
+
+        if !is_valid {
+            self.lints.push(Lint {
+                at,
+                kind: LintKind::InvalidOperands { operator },
+            });
+        }
+    }
+    fn check_bit_field_assignment(&mut self, left: &Expression<'a>, right: &Expression<'a>) {
+        let Some(member) = accessed_member(left.without_parens(), self.symbols) else {
+            return;
+        };
+        let (Some(name), Some(width), Some(ty)) = (member.name, member.width, &member.ty) else {
+            return;
+        };
+        // No type is wider than 64 bits, and zero-width bit-fields are unnamed.
+        if !(1..=64).contains(&width) {
+            return;
+        }
+        // Any value converts to a `bool` without loss of meaning, and the
+        // signedness of an enumeration depends on its enumerators.
+        let TypeKind::Base(base) = &ty.kind else {
+            return;
+        };
+        if !base.is_integer() || matches!(base, BaseType::Bool | BaseType::Enum(_)) {
+            return;
+        }
+        let Ok(value) = Evaluator::new(EvalConfig::default()).evaluate(right) else {
+            return;
+        };
+
+        let range = if base.is_unsigned() {
+            0..=(1i128 << width) - 1
+        } else {
+            -(1i128 << (width - 1))..=(1i128 << (width - 1)) - 1
+        };
+        if !range.contains(&value.value) {
+            self.lints.push(Lint {
+                at: right.at,
+                kind: LintKind::BitFieldOverflow {
+                    member: name,
+                    width,
+                },
+            });
+        }
+    }
+    fn check_address_of(&mut self, operand: &Expression<'a>) {
+        // The address of a member is within that of the object.
+        let mut object = operand.without_parens();
+        while let ExpressionKind::Member { left, .. } = &object.kind {
+            object = left.without_parens();
+        }
+        self.no_decay.insert(object.at);
+        if let Some(name) = self.register_object(object) {
+            self.lints.push(Lint {
+                at: operand.at,
+                kind: LintKind::AddressOfRegister { name },
+            });
+        }
+    }
+    fn check_decay(&mut self, identifier: &Expression<'a>) {
+        if self.no_decay.contains(&identifier.at) {
+            return;
+        }
+        if let Some(name) = self.register_object(identifier)
+            && let Some(symbol) = self.symbols.resolve(identifier.at)
+            && let Some(Type {
+                kind: TypeKind::Array { .. },
+                ..
+            }) = &symbol.ty
+        {
+            self.lints.push(Lint {
+                at: identifier.at,
+                kind: LintKind::RegisterArrayDecay { name },
+            });
+        }
+    }
+    /// Returns the name of the `register` object `expression` designates, if it is one.
+    fn register_object(&self, expression: &Expression<'a>) -> Option<&'a str> {
+        let ExpressionKind::Identifier(name) = expression.kind else {
+            return None;
+        };
+        let symbol = self.symbols.resolve(expression.at)?;
+        (matches!(symbol.kind, SymbolKind::Object | SymbolKind::Parameter)
+            && symbol.storage_class == Some(StorageClassSpecifierKind::Register))
+        .then_some(name)
+    }
+}
+impl<'a> Visitor<'a> for Linter<'_, 'a> {
+    fn visit_expression(&mut self, expression: &Expression<'a>) {
+        if self.options.register_address {
+            match &expression.kind {
+                ExpressionKind::Unary(UnaryOperator::AddressOf, operand) => {
+                    self.check_address_of(operand)
+                }
+                ExpressionKind::Sizeof {
+                    kind: SizeofKind::Expression(operand),
+                    ..
+                } => {
+                    self.no_decay.insert(operand.without_parens().at);
+                }
+                ExpressionKind::Identifier(_) => self.check_decay(expression),
+                _ => (),
+            }
+        }
+        if let ExpressionKind::Binary {
+            left,
+            operator,
+            right,
+        } = &expression.kind
+            && self.options.operand_types
+        {
+            self.check_operand_types(left, *operator, right);
+        }
+        if let ExpressionKind::Assign {
+            left,
+            operator: (_, AssignmentOperator::Assign),
+            right,
+        } = &expression.kind
+            && self.options.bit_field_overflow
+        {
+            self.check_bit_field_assignment(left, right);
+        }
+        visit::walk_expression(self, expression);
+    }
+    fn visit_typeof_specifier(&mut self, specifier: &TypeofSpecifier<'a>) {
+        if let TypeofSpecifierArgumentKind::Expression(operand) = &specifier.argument.kind {
+            self.no_decay.insert(operand.without_parens().at);
+        }
+        visit::walk_typeof_specifier(self, specifier);
+    }
+    fn visit_function_definition(&mut self, function: &FunctionDefinition<'a>) {
+        if self.options.missing_return {
+            self.check_missing_return(function);
+        }
+        visit::walk_function_definition(self, function);
+    }
+    fn visit_selection_statement(&mut self, statement: &SelectionStatement<'a>) {
+        if let SelectionStatementKind::Switch {
+            switch_keyword,
+            controlling_expression,
+            body,
+            ..
+        } = &statement.kind
+            && self.options.enum_switch
+        {
+            self.check_enum_switch(*switch_keyword, controlling_expression, &body.statement);
+        }
+        visit::walk_selection_statement(self, statement);
+    }
+}
+
+/// The category of an operand's type that decides which operators accept it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Operand {
+    Integer,
+    /// An integer constant expression with value zero, which may be compared with pointers.
+    NullPointerConstant,
+    RealFloating,
+    ComplexFloating,
+    Pointer,
+    /// A structure, union or `void`.
+    Other,
+}
+impl Operand {
+    /// Classifies an operand of type `ty`, or returns `None` if the type is not known.
+    fn classify(ty: &Type, expression: &Expression) -> Option<Self> {
+        Some(match &ty.kind {
+            TypeKind::Pointer(_) => Operand::Pointer,
+            TypeKind::Base(BaseType::Typedef(_) | BaseType::Typeof(_) | BaseType::Atomic(_)) => {
+                return None;
+            }
+            TypeKind::Base(base) if base.is_integer() => match &expression.without_parens().kind {
+                ExpressionKind::Integer(int) if int.source.chars().all(|c| "0'".contains(c)) => {
+                    Operand::NullPointerConstant
+                }
+                _ => Operand::Integer,
+            },
+            TypeKind::Base(base) if base.is_complex() => Operand::ComplexFloating,
+            TypeKind::Base(base) if base.is_floating() => Operand::RealFloating,
+            TypeKind::Base(_) => Operand::Other,
+            TypeKind::Array { .. } | TypeKind::Function { .. } => return None,
+        })
+    }
+    fn is_integer(self) -> bool {
+        matches!(self, Operand::Integer | Operand::NullPointerConstant)
+    }
+    fn is_real(self) -> bool {
+        self.is_integer() || self == Operand::RealFloating
+    }
+    fn is_arithmetic(self) -> bool {
+        self.is_real() || self == Operand::ComplexFloating
+    }
+    fn is_scalar(self) -> bool {
+        self.is_arithmetic() || self == Operand::Pointer
+    }
+}
+
+/// Collects the `case` and `default` labels belonging to a single switch body.
+#[derive(Default)]
+struct SwitchLabels<'a> {
+    cases: Vec<&'a str>,
+    has_default: bool,
+    has_range: bool,
+}
+impl<'a> Visitor<'a> for SwitchLabels<'a> {
+    fn visit_selection_statement(&mut self, statement: &SelectionStatement<'a>) {
+        if let SelectionStatementKind::If { .. } = statement.kind {
+            visit::walk_selection_statement(self, statement);
+        }
+    }
+    fn visit_label(&mut self, label: &Label<'a>) {
+        match &label.kind {
+            LabelKind::Case { value, .. } => {
+                if let ExpressionKind::Identifier(name) = value.without_parens().kind {
+                    self.cases.push(name);
+                }
+            }
+            LabelKind::CaseRange { .. } => self.has_range = true,
+            LabelKind::Default { .. } => self.has_default = true,
+            LabelKind::Name(_) => (),
+        }
+    }
+}
+
+/// Finds `break` or `continue` statements that target the enclosing loop or switch.
+struct FindJump {
+    breaks: bool,
+    found: bool,
+}
+impl<'a> Visitor<'a> for FindJump {
+    fn visit_selection_statement(&mut self, statement: &SelectionStatement<'a>) {
+        // A nested switch captures `break`, but not `continue`.
+        if !self.breaks || matches!(statement.kind, SelectionStatementKind::If { .. }) {
+            visit::walk_selection_statement(self, statement);
+        }
+    }
+    fn visit_iteration_statement(&mut self, _statement: &IterationStatement<'a>) {}
+    fn visit_jump_statement(&mut self, statement: &JumpStatement<'a>) {
+        match statement.kind {
+            JumpStatementKind::Break { .. } if self.breaks => self.found = true,
+            JumpStatementKind::Continue { .. } if !self.breaks => self.found = true,
+            _ => (),
+        }
+    }
+}
+fn contains_jump(body: &Statement, breaks: bool) -> bool {
+    let mut finder = FindJump {
+        breaks,
+        found: false,
+    };
+    finder.visit_statement(body);
+    finder.found
+}
+
+fn returns_void(function: &FunctionDefinition) -> bool {
+    let derivations = function.declarator.derivations();
+    if derivations.len() != 1 {
+        return false;
+    }
+
+    function.specifiers.iter().any(|s| {
+        matches!(
+            &s.kind,
+            DeclarationSpecifierKind::Type(TypeSpecifierQualifier {
+                kind: TypeSpecifierQualifierKind::TypeSpecifier(TypeSpecifier {
+                    kind: TypeSpecifierKind::Void,
+                    ..
+                }),
+                ..
+            })
+        )
+    })
+}
+
+/// Returns whether control can flow past the end of `statement`.
+fn statement_completes(statement: &Statement) -> bool {
+    match &statement.kind {
+        StatementKind::Labeled(labeled) => statement_completes(&labeled.statement),
+        StatementKind::Unlabeled(unlabeled) => unlabeled_completes(unlabeled),
+    }
+}
+fn unlabeled_completes(statement: &UnlabeledStatement) -> bool {
+    match &statement.kind {
+        UnlabeledStatementKind::Expression(expression) => {
+            !expression.expression.as_ref().is_some_and(is_noreturn_call)
+        }
+        UnlabeledStatementKind::Jump(..) => false,
+        UnlabeledStatementKind::Primary(_, block) => match &block.kind {
+            PrimaryBlockKind::Compound(compound) => compound_completes(compound),
+            PrimaryBlockKind::Selection(selection) => match &selection.kind {
+                SelectionStatementKind::If {
+                    then_body,
+                    else_body,
+                    ..
+                } => match else_body {
+                    None => true,
+                    Some((_, else_body)) => {
+                        statement_completes(&then_body.statement)
+                            || statement_completes(&else_body.statement)
+                    }
+                },
+                SelectionStatementKind::Switch { body, .. } => {
+                    let mut labels = SwitchLabels::default();
+                    labels.visit_statement(&body.statement);
+                    !labels.has_default
+                        || statement_completes(&body.statement)
+                        || contains_jump(&body.statement, true)
+                }
+            },
+            PrimaryBlockKind::Iteration(iteration) => match &iteration.kind {
+                IterationStatementKind::While {
+                    condition, body, ..
+                } => !is_constant_true(condition) || contains_jump(&body.statement, true),
+                IterationStatementKind::DoWhile {
+                    body, condition, ..
+                } => {
+                    let reaches_condition = statement_completes(&body.statement)
+                        || contains_jump(&body.statement, false);
+                    (reaches_condition && !is_constant_true(condition))
+                        || contains_jump(&body.statement, true)
+                }
+                IterationStatementKind::For {
+                    condition, body, ..
+                } => {
+                    condition.as_ref().is_some_and(|c| !is_constant_true(c))
+                        || contains_jump(&body.statement, true)
+                }
+            },
+        },
+    }
+}
+fn compound_completes(compound: &CompoundStatement) -> bool {
+    let mut reachable = true;
+    for item in compound.items.iter().flat_map(|i| i.iter()) {
+        match &item.kind {
+            BlockItemKind::Label(_) => reachable = true,
+            BlockItemKind::Declaration(_) => (),
+            BlockItemKind::Unlabeled(statement) => {
+                reachable = reachable && unlabeled_completes(statement)
+            }
+        }
+    }
+    reachable
+}
+
+/// Returns whether `name` is reserved for the implementation,
+/// either always or only when declared at file scope.
+fn is_reserved(name: &str, file_scope: bool) -> bool {
+    const LIBRARY: &[&str] = &[
+        "abort", "abs", "atexit", "atof", "atoi", "atol", "bsearch", "calloc", "errno", "exit",
+        "fclose", "fflush", "fgets", "fopen", "fprintf", "fputs", "fread", "free", "fscanf",
+        "fwrite", "getchar", "getenv", "malloc", "memcmp", "memcpy", "memmove", "memset", "printf",
+        "putchar", "puts", "qsort", "rand", "realloc", "scanf", "snprintf", "sprintf", "srand",
+        "sscanf", "strcat", "strchr", "strcmp", "strcpy", "strlen", "strncmp", "strncpy",
+        "strrchr", "strstr", "strtol", "system", "time",
+    ];
+
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some('_'), Some('_')) => true,
+        (Some('_'), Some(c)) if c.is_ascii_uppercase() => true,
+        (Some('_'), _) => file_scope,
+        _ => file_scope && LIBRARY.contains(&name),
+    }
+}
+
+fn is_constant_true(expression: &Expression) -> bool {
+    let value = Evaluator::new(EvalConfig::default()).evaluate(expression);
+    value.is_ok_and(|value| value.value != 0)
+}
+fn is_noreturn_call(expression: &Expression) -> bool {
+    const NORETURN: &[&str] = &[
+        "abort",
+        "exit",
+        "_Exit",
+        "quick_exit",
+        "thrd_exit",
+        "longjmp",
+        "__builtin_unreachable",
+    ];
+
+    let ExpressionKind::Call { left, .. } = &expression.kind else {
+        return false;
+    };
+    let ExpressionKind::Identifier(name) = left.without_parens().kind else {
+        return false;
+    };
+    NORETURN.contains(&name)
+}
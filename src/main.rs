@@ -1,14 +1,25 @@
-use ecc::{lexer::Lexer, parser::Parser};
+use ecc::{compile::CompileError, lexer::Lexer, parser::Parser};
 use std::process::Command;
 
 fn main() {
     const SRC_FILE: &str = "main.c";
-    let src = invoke_preprocessor(SRC_FILE).unwrap();
+    let src = match invoke_preprocessor(SRC_FILE) {
+        Ok(src) => src,
+        Err(err) => {
+            eprintln!("{err}");
+            return;
+        }
+    };
     println!("--------------------------------------------------");
     print!("{src}");
     println!("--------------------------------------------------\n\n");
 
     let (tokens, files) = Lexer::new(&src).lex();
+    if std::env::args().any(|arg| arg == "--dump-scopes") {
+        dump_scopes(&tokens);
+        return;
+    }
+
     for &token in &tokens {
         let file = &files[token.at.file];
         println!(
@@ -17,12 +28,16 @@ fn main() {
         );
     }
 
-    let (ast, parse_errs) = Parser::new(&tokens).parse();
+    let (ast, parse_errs) = Parser::new(&tokens).parse().into_parts();
     if !parse_errs.is_empty() {
         eprintln!("Encountered {} parsing errors:", parse_errs.len());
     }
     for parse_err in &parse_errs {
-        eprintln!("    {parse_err:?}");
+        let at = parse_err.at.at;
+        eprintln!(
+            "    {}:{}:{}: {}, found `{}`",
+            &files[at.file], at.line, at.column, parse_err.expected, parse_err.at.kind
+        );
     }
     let Ok(ast) = ast else {
         eprintln!("Cannot continue compilation process");
@@ -32,7 +47,24 @@ fn main() {
     println!("{ast:#?}");
 }
 
-fn invoke_preprocessor(file: &str) -> Result<String, ()> {
+/// Prints the open scopes after each external declaration.
+#[cfg(feature = "debug-scopes")]
+fn dump_scopes(tokens: &[ecc::token::Token]) {
+    let mut parser = Parser::new(tokens);
+    while let Ok(declaration) = parser.parse_next_declaration() {
+        let at = declaration.at;
+        println!("after declaration at {}:{}:", at.line, at.column);
+        for (depth, scope) in parser.scopes().iter().enumerate() {
+            println!("    {depth}: {scope:?}");
+        }
+    }
+}
+#[cfg(not(feature = "debug-scopes"))]
+fn dump_scopes(_: &[ecc::token::Token]) {
+    eprintln!("--dump-scopes requires the debug-scopes feature");
+}
+
+fn invoke_preprocessor(file: &str) -> Result<String, CompileError<'static>> {
     let out = Command::new("gcc")
         .arg("-E")
         .arg("-xc")
@@ -42,19 +74,12 @@ fn invoke_preprocessor(file: &str) -> Result<String, ()> {
         .arg(file)
         .arg("-")
         .output()
-        .unwrap();
+        .map_err(|err| CompileError::Preprocess(err.to_string()))?;
     if !out.status.success() {
         let stderr = String::from_utf8_lossy(&out.stderr);
-        eprintln!("Preprocessor failed:");
-        eprintln!("{stderr}");
-        return Err(());
+        return Err(CompileError::Preprocess(stderr.into_owned()));
     }
 
-    match String::from_utf8(out.stdout) {
-        Ok(out) => Ok(out),
-        Err(err) => {
-            eprintln!("Preprocessor output is not UTF-8: {err}");
-            Err(())
-        }
-    }
+    String::from_utf8(out.stdout)
+        .map_err(|err| CompileError::Preprocess(format!("output is not UTF-8: {err}")))
 }
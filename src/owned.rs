@@ -0,0 +1,3254 @@
+//! An owned mirror of the syntax tree in [`crate::ast`], whose text is copied
+//! out of the source so the tree can outlive it.
+//!
+//! Types that borrow nothing from the source are shared with [`crate::ast`] and
+//! re-exported here. A borrowed tree is converted with [`ToOwnedAst::to_owned_ast`].
+
+use crate::{
+    ast,
+    token::{self, At, FloatFormat, FloatSuffix, IntegerFormat, IntegerSuffix, StringEncoding},
+};
+
+pub use crate::ast::{
+    AssignmentOperator, BinaryOperator, CommaList, CommaListKind, FunctionSpecifier,
+    FunctionSpecifierKind, InlineLinkage, List, ListKind, StorageClassSpecifier,
+    StorageClassSpecifierKind, StorageClassSpecifiers, StructOrUnion, TypeQualifier,
+    TypeQualifierKind, TypeQualifierList, UnaryOperator,
+};
+
+/// Converts a node of a borrowed syntax tree into its owned counterpart.
+///
+/// Not named `to_owned`, which every `Clone` type already has.
+pub trait ToOwnedAst {
+    type Owned;
+    fn to_owned_ast(&self) -> Self::Owned;
+}
+
+impl ToOwnedAst for &str {
+    type Owned = Box<str>;
+    fn to_owned_ast(&self) -> Box<str> {
+        (*self).into()
+    }
+}
+impl<T: ToOwnedAst> ToOwnedAst for Box<T> {
+    type Owned = Box<T::Owned>;
+    fn to_owned_ast(&self) -> Box<T::Owned> {
+        Box::new((**self).to_owned_ast())
+    }
+}
+impl<T: ToOwnedAst> ToOwnedAst for Option<T> {
+    type Owned = Option<T::Owned>;
+    fn to_owned_ast(&self) -> Option<T::Owned> {
+        self.as_ref().map(T::to_owned_ast)
+    }
+}
+impl<A: ToOwnedAst, B: ToOwnedAst> ToOwnedAst for (A, B) {
+    type Owned = (A::Owned, B::Owned);
+    fn to_owned_ast(&self) -> Self::Owned {
+        (self.0.to_owned_ast(), self.1.to_owned_ast())
+    }
+}
+impl<A: ToOwnedAst, B: ToOwnedAst, C: ToOwnedAst> ToOwnedAst for (A, B, C) {
+    type Owned = (A::Owned, B::Owned, C::Owned);
+    fn to_owned_ast(&self) -> Self::Owned {
+        (
+            self.0.to_owned_ast(),
+            self.1.to_owned_ast(),
+            self.2.to_owned_ast(),
+        )
+    }
+}
+impl<A: ToOwnedAst, B: ToOwnedAst, C: ToOwnedAst, D: ToOwnedAst> ToOwnedAst for (A, B, C, D) {
+    type Owned = (A::Owned, B::Owned, C::Owned, D::Owned);
+    fn to_owned_ast(&self) -> Self::Owned {
+        (
+            self.0.to_owned_ast(),
+            self.1.to_owned_ast(),
+            self.2.to_owned_ast(),
+            self.3.to_owned_ast(),
+        )
+    }
+}
+impl<T: ToOwnedAst> ToOwnedAst for List<T> {
+    type Owned = List<T::Owned>;
+    fn to_owned_ast(&self) -> List<T::Owned> {
+        List {
+            at: self.at,
+            kind: match &self.kind {
+                ListKind::Leaf(item) => ListKind::Leaf(item.to_owned_ast()),
+                ListKind::Cons(left, item) => {
+                    ListKind::Cons(left.to_owned_ast(), item.to_owned_ast())
+                }
+            },
+        }
+    }
+}
+impl<T: ToOwnedAst> ToOwnedAst for CommaList<T> {
+    type Owned = CommaList<T::Owned>;
+    fn to_owned_ast(&self) -> CommaList<T::Owned> {
+        CommaList {
+            at: self.at,
+            kind: match &self.kind {
+                CommaListKind::Leaf(item) => CommaListKind::Leaf(item.to_owned_ast()),
+                CommaListKind::Cons { left, comma, right } => CommaListKind::Cons {
+                    left: left.to_owned_ast(),
+                    comma: *comma,
+                    right: right.to_owned_ast(),
+                },
+            },
+        }
+    }
+}
+
+/// Implements [`ToOwnedAst`] for types that borrow nothing, as a copy.
+macro_rules! shared {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ToOwnedAst for $ty {
+                type Owned = $ty;
+                fn to_owned_ast(&self) -> $ty {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+shared!(
+    bool,
+    At,
+    StringEncoding,
+    IntegerFormat,
+    IntegerSuffix,
+    FloatFormat,
+    FloatSuffix,
+    AssignmentOperator,
+    BinaryOperator,
+    FunctionSpecifier,
+    FunctionSpecifierKind,
+    InlineLinkage,
+    StorageClassSpecifier,
+    StorageClassSpecifierKind,
+    StructOrUnion,
+    TypeQualifier,
+    TypeQualifierKind,
+    UnaryOperator,
+);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringLiteral {
+    pub at: At,
+    pub literal: Box<str>,
+    pub encoding: StringEncoding,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharLiteral {
+    pub at: At,
+    pub literal: Box<str>,
+    pub encoding: StringEncoding,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expression {
+    pub at: At,
+    pub kind: ExpressionKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionKind {
+    Identifier(Box<str>),
+    Integer(IntegerToken),
+    Float(FloatToken),
+    String(StringLiteral),
+    Character(CharLiteral),
+    Bool(bool),
+    Nullptr,
+    Parenthesized {
+        open_parenthesis: At,
+        inner: Box<Expression>,
+        close_parenthesis: At,
+    },
+    GenericSelection(GenericSelection),
+    StatementExpression {
+        open_parenthesis: At,
+        body: CompoundStatement,
+        close_parenthesis: At,
+    },
+    Index {
+        left: Box<Expression>,
+        open_bracket: At,
+        index: Box<Expression>,
+        close_bracket: At,
+    },
+    Call {
+        left: Box<Expression>,
+        open_parenthesis: At,
+        arguments: Option<ArgumentExpressionList>,
+        trailing_comma: Option<At>,
+        close_parenthesis: At,
+    },
+    Member {
+        left: Box<Expression>,
+        period: At,
+        name: Box<str>,
+    },
+    MemberIndirect {
+        left: Box<Expression>,
+        arrow: At,
+        name: Box<str>,
+    },
+    PostIncrement {
+        left: Box<Expression>,
+        double_plus: At,
+    },
+    PostDecrement {
+        left: Box<Expression>,
+        double_minus: At,
+    },
+    CompoundLiteral(CompoundLiteral),
+    PreIncrement {
+        double_plus: At,
+        right: Box<Expression>,
+    },
+    PreDecrement {
+        double_minus: At,
+        right: Box<Expression>,
+    },
+    Unary(UnaryOperator, Box<Expression>),
+    LabelAddress(Box<str>),
+    Sizeof {
+        sizeof_keyword: At,
+        kind: SizeofKind,
+    },
+    Alignof {
+        alignof_keyword: At,
+        open_parenthesis: At,
+        type_name: TypeName,
+        close_parenthesis: At,
+    },
+    Cast {
+        open_parenthesis: At,
+        type_name: TypeName,
+        close_parenthesis: At,
+        right: Box<Expression>,
+    },
+    Binary {
+        left: Box<Expression>,
+        operator: (At, BinaryOperator),
+        right: Box<Expression>,
+    },
+    Conditional {
+        condition: Box<Expression>,
+        question: At,
+        then_value: Box<Expression>,
+        colon: At,
+        else_value: Box<Expression>,
+    },
+    Assign {
+        left: Box<Expression>,
+        operator: (At, AssignmentOperator),
+        right: Box<Expression>,
+    },
+    Comma {
+        left: Box<Expression>,
+        comma: At,
+        right: Box<Expression>,
+    },
+    Offsetof {
+        builtin: At,
+        open_parenthesis: At,
+        type_name: Box<TypeName>,
+        comma: At,
+        member: MemberDesignator,
+        close_parenthesis: At,
+    },
+    TypesCompatible {
+        builtin: At,
+        open_parenthesis: At,
+        left: Box<TypeName>,
+        comma: At,
+        right: Box<TypeName>,
+        close_parenthesis: At,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericSelection {
+    pub at: At,
+    pub generic_keyword: At,
+    pub open_parenthesis: At,
+    pub controlling_expression: Box<Expression>,
+    pub comma: At,
+    pub generic_assocs: GenericAssocList,
+    pub close_parenthesis: At,
+}
+
+pub type GenericAssocList = CommaList<GenericAssociation>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericAssociation {
+    pub at: At,
+    pub colon: At,
+    pub kind: GenericAssociationKind,
+    pub value: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GenericAssociationKind {
+    Default { default_keyword: At },
+    ForType(TypeName),
+}
+
+pub type ArgumentExpressionList = CommaList<Expression>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompoundLiteral {
+    pub at: At,
+    pub open_parenthesis: At,
+    pub storage_class: Option<StorageClassSpecifiers>,
+    pub type_name: TypeName,
+    pub close_parenthesis: At,
+    pub initializer: BracedInitializer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SizeofKind {
+    Expression(Box<Expression>),
+    Type {
+        open_parenthesis: At,
+        type_name: TypeName,
+        close_parenthesis: At,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Declaration {
+    pub at: At,
+    pub kind: DeclarationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Normal {
+        attributes: Option<AttributeSpecifierSequence>,
+        specifiers: DeclarationSpecifiers,
+        init_declarators: Option<InitDeclaratorList>,
+        semicolon: At,
+    },
+    Assert(StaticAssertDeclaration),
+    Attribute(AttributeDeclaration),
+    Pragma(PragmaOperator),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarationSpecifiers {
+    pub at: At,
+    pub specifier: DeclarationSpecifier,
+    pub kind: DeclarationSpecifiersKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeclarationSpecifiersKind {
+    Leaf(Option<AttributeSpecifierSequence>),
+    Cons(Box<DeclarationSpecifiers>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarationSpecifier {
+    pub at: At,
+    pub kind: DeclarationSpecifierKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeclarationSpecifierKind {
+    StorageClass(StorageClassSpecifier),
+    Type(TypeSpecifierQualifier),
+    Function(FunctionSpecifier),
+}
+
+pub type InitDeclaratorList = CommaList<InitDeclarator>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InitDeclarator {
+    pub at: At,
+    pub declarator: Declarator,
+    pub initializer: Option<(At, Initializer)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeDeclaration {
+    pub at: At,
+    pub attributes: AttributeSpecifierSequence,
+    pub semicolon: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeSpecifier {
+    pub at: At,
+    pub kind: TypeSpecifierKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeSpecifierKind {
+    Void,
+    Char,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    Signed,
+    Unsigned,
+    BitInt {
+        bitint_keyword: At,
+        open_parenthesis: At,
+        width: Expression,
+        close_parenthesis: At,
+    },
+    Bool,
+    Complex,
+    Decimal32,
+    Decimal64,
+    Decimal128,
+    Float16,
+    Float32,
+    Float64,
+    Float128,
+    Float32x,
+    Float64x,
+    Float128x,
+    Int128,
+    Atomic(AtomicTypeSpecifier),
+    StructOrUnion(StructOrUnionSpecifier),
+    Enum(EnumSpecifier),
+    TypedefName(Box<str>),
+    Typeof(TypeofSpecifier),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructOrUnionSpecifier {
+    pub at: At,
+    pub struct_or_union: (At, StructOrUnion),
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub tag: Option<Box<str>>,
+    pub members: Option<(At, MemberDeclarationList, At)>,
+}
+
+pub type MemberDeclarationList = List<MemberDeclaration>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberDeclaration {
+    pub at: At,
+    pub kind: MemberDeclarationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemberDeclarationKind {
+    Member {
+        attributes: Option<AttributeSpecifierSequence>,
+        specifier_qualifiers: SpecifierQualifierList,
+        member_declarators: Option<MemberDeclaratorList>,
+        semicolon: At,
+    },
+    Assert(StaticAssertDeclaration),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpecifierQualifierList {
+    pub at: At,
+    pub specifier_qualifier: Box<TypeSpecifierQualifier>,
+    pub kind: SpecifierQualifierListKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpecifierQualifierListKind {
+    Leaf(Option<AttributeSpecifierSequence>),
+    Cons(Box<SpecifierQualifierList>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeSpecifierQualifier {
+    pub at: At,
+    pub kind: TypeSpecifierQualifierKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeSpecifierQualifierKind {
+    TypeSpecifier(TypeSpecifier),
+    TypeQualifier(TypeQualifier),
+    Alignment(AlignmentSpecifier),
+}
+
+pub type MemberDeclaratorList = CommaList<MemberDeclarator>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberDeclarator {
+    pub at: At,
+    pub declarator: Option<Declarator>,
+    pub width: Option<(At, Expression)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumSpecifier {
+    pub at: At,
+    pub enum_keyword: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub tag: Option<Box<str>>,
+    pub enum_type: Option<EnumTypeSpecifier>,
+    pub enumerators: Option<(At, EnumeratorList, Option<At>, At)>,
+}
+
+pub type EnumeratorList = CommaList<Enumerator>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Enumerator {
+    pub at: At,
+    pub name: Box<str>,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub value: Option<(At, Expression)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumTypeSpecifier {
+    pub at: At,
+    pub colon: At,
+    pub specifier_qualifiers: SpecifierQualifierList,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicTypeSpecifier {
+    pub at: At,
+    pub atomic_keyword: At,
+    pub open_parenthesis: At,
+    pub type_name: TypeName,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeofSpecifier {
+    pub at: At,
+    pub typeof_keyword: At,
+    pub unqual: bool,
+    pub open_parenthesis: At,
+    pub argument: TypeofSpecifierArgument,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeofSpecifierArgument {
+    pub at: At,
+    pub kind: TypeofSpecifierArgumentKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeofSpecifierArgumentKind {
+    Expression(Expression),
+    Type(TypeName),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlignmentSpecifier {
+    pub at: At,
+    pub alignas_keyword: At,
+    pub open_parenthesis: At,
+    pub kind: AlignmentSpecifierKind,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlignmentSpecifierKind {
+    Type(TypeName),
+    Expression(Expression),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Declarator {
+    pub at: At,
+    pub pointer: Option<Pointer>,
+    pub direct: DirectDeclarator,
+    pub asm_label: Option<AsmLabel>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsmLabel {
+    pub at: At,
+    pub asm_keyword: At,
+    pub open_parenthesis: At,
+    pub name: List<StringLiteral>,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectDeclarator {
+    pub at: At,
+    pub kind: DirectDeclaratorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirectDeclaratorKind {
+    Name(Box<str>, Option<AttributeSpecifierSequence>),
+    Parenthesized {
+        open_parenthesis: At,
+        inner: Box<Declarator>,
+        close_parenthesis: At,
+    },
+    Array(ArrayDeclarator, Option<AttributeSpecifierSequence>),
+    Function(FunctionDeclarator, Option<AttributeSpecifierSequence>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrayDeclarator {
+    pub at: At,
+    pub left: Box<DirectDeclarator>,
+    pub open_bracket: At,
+    pub qualifiers: Option<TypeQualifierList>,
+    pub kind: ArrayDeclaratorKind,
+    pub close_bracket: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrayDeclaratorKind {
+    Normal {
+        static_keyword: Option<At>,
+        size: Option<Expression>,
+    },
+    Var {
+        asterisk: At,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionDeclarator {
+    pub at: At,
+    pub left: Box<DirectDeclarator>,
+    pub open_parenthesis: At,
+    pub parameters: Option<ParameterTypeList>,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pointer {
+    pub at: At,
+    pub asterisk: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub qualifiers: Option<TypeQualifierList>,
+    pub right: Option<Box<Pointer>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterTypeList {
+    pub at: At,
+    pub parameters: Option<(ParameterList, Option<At>)>,
+    pub ellipses: Option<At>,
+}
+
+pub type ParameterList = CommaList<ParameterDeclaration>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterDeclaration {
+    pub at: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub specifiers: DeclarationSpecifiers,
+    pub kind: ParameterDeclarationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParameterDeclarationKind {
+    Concrete(Declarator),
+    Abstract(Option<AbstractDeclarator>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeName {
+    pub at: At,
+    pub specifier_qualifiers: SpecifierQualifierList,
+    pub declarator: Option<AbstractDeclarator>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbstractDeclarator {
+    pub at: At,
+    pub pointer: Option<Pointer>,
+    pub direct: Option<DirectAbstractDeclarator>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectAbstractDeclarator {
+    pub at: At,
+    pub kind: DirectAbstractDeclaratorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirectAbstractDeclaratorKind {
+    Parenthesized {
+        open_parenthesis: At,
+        inner: Box<AbstractDeclarator>,
+        close_parenthesis: At,
+    },
+    Array(ArrayAbstractDeclarator, Option<AttributeSpecifierSequence>),
+    Function(
+        FunctionAbstractDeclarator,
+        Option<AttributeSpecifierSequence>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrayAbstractDeclarator {
+    pub at: At,
+    pub left: Option<Box<DirectAbstractDeclarator>>,
+    pub open_bracket: At,
+    pub kind: ArrayAbstractDeclaratorKind,
+    pub close_bracket: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrayAbstractDeclaratorKind {
+    Normal {
+        qualifiers: Option<TypeQualifierList>,
+        static_keyword: Option<At>,
+        size: Option<Box<Expression>>,
+    },
+    Var {
+        asterisk: At,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionAbstractDeclarator {
+    pub at: At,
+    pub left: Option<Box<DirectAbstractDeclarator>>,
+    pub open_parenthesis: At,
+    pub parameters: Option<ParameterTypeList>,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BracedInitializer {
+    pub at: At,
+    pub open_brace: At,
+    pub initializers: Option<(InitializerList, Option<At>)>,
+    pub close_brace: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Initializer {
+    pub at: At,
+    pub kind: InitializerKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InitializerKind {
+    Expression(Expression),
+    Braced(Box<BracedInitializer>),
+}
+
+pub type InitializerList = CommaList<(Option<Designation>, Initializer)>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Designation {
+    pub at: At,
+    pub designators: DesignatorList,
+    pub equal: At,
+}
+
+pub type DesignatorList = List<Designator>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Designator {
+    pub at: At,
+    pub kind: DesignatorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DesignatorKind {
+    InBrackets {
+        open_bracket: At,
+        value: Expression,
+        close_bracket: At,
+    },
+    AfterPeriod {
+        period: At,
+        name: Box<str>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberDesignator {
+    pub at: At,
+    pub name: Box<str>,
+    pub designators: Option<DesignatorList>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PragmaOperator {
+    pub at: At,
+    pub pragma_keyword: At,
+    pub open_parenthesis: At,
+    pub directive: StringLiteral,
+    pub close_parenthesis: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaticAssertDeclaration {
+    pub at: At,
+    pub static_assert_keyword: At,
+    pub open_parenthesis: At,
+    pub condition: Expression,
+    pub message: Option<(At, StringLiteral)>,
+    pub close_parenthesis: At,
+    pub semicolon: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeSpecifierSequence {
+    pub at: At,
+    pub left: Option<Box<AttributeSpecifierSequence>>,
+    pub specifier: AttributeSpecifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeSpecifier {
+    pub at: At,
+    pub open_bracket_0: At,
+    pub open_bracket_1: At,
+    pub attributes: AttributeList,
+    pub close_bracket_0: At,
+    pub close_bracket_1: At,
+}
+
+pub type AttributeList = CommaList<Option<Attribute>>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attribute {
+    pub at: At,
+    pub token: AttributeToken,
+    pub argument_clause: Option<AttributeArgumentClause>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeToken {
+    pub at: At,
+    pub prefix: Option<(Box<str>, At)>,
+    pub token: Box<str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeArgumentClause {
+    pub at: At,
+    pub open_parenthesis: At,
+    pub tokens: Option<BalancedTokenSequence>,
+    pub close_parenthesis: At,
+}
+
+pub type BalancedTokenSequence = List<BalancedToken>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BalancedToken {
+    pub at: At,
+    pub kind: BalancedTokenKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BalancedTokenKind {
+    Parenthesized {
+        open_parenthesis: At,
+        inner: Option<BalancedTokenSequence>,
+        close_parenthesis: At,
+    },
+    Bracketed {
+        open_bracket: At,
+        inner: Option<BalancedTokenSequence>,
+        close_bracket: At,
+    },
+    Braced {
+        open_brace: At,
+        inner: Option<BalancedTokenSequence>,
+        close_brace: At,
+    },
+    Token(TokenKind),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    pub at: At,
+    pub kind: StatementKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatementKind {
+    Labeled(LabeledStatement),
+    Unlabeled(UnlabeledStatement),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnlabeledStatement {
+    pub at: At,
+    pub kind: UnlabeledStatementKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnlabeledStatementKind {
+    Expression(ExpressionStatement),
+    Primary(Option<AttributeSpecifierSequence>, PrimaryBlock),
+    Jump(Option<AttributeSpecifierSequence>, JumpStatement),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimaryBlock {
+    pub at: At,
+    pub kind: PrimaryBlockKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrimaryBlockKind {
+    Compound(CompoundStatement),
+    Selection(SelectionStatement),
+    Iteration(IterationStatement),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecondaryBlock {
+    pub at: At,
+    pub statement: Box<Statement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Label {
+    pub at: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub kind: LabelKind,
+    pub colon: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LabelKind {
+    Name(Box<str>),
+    Case {
+        case_keyword: At,
+        value: Expression,
+    },
+    CaseRange {
+        case_keyword: At,
+        low: Expression,
+        ellipses: At,
+        high: Expression,
+    },
+    Default {
+        default_keyword: At,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabeledStatement {
+    pub at: At,
+    pub label: Label,
+    pub statement: Box<Statement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompoundStatement {
+    pub at: At,
+    pub open_brace: At,
+    pub items: Option<BlockItemList>,
+    pub close_brace: At,
+}
+
+pub type BlockItemList = List<BlockItem>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockItem {
+    pub at: At,
+    pub kind: BlockItemKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockItemKind {
+    Declaration(Declaration),
+    Unlabeled(UnlabeledStatement),
+    Label(Label),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpressionStatement {
+    pub at: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub expression: Option<Expression>,
+    pub semicolon: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectionStatement {
+    pub at: At,
+    pub kind: SelectionStatementKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectionStatementKind {
+    If {
+        if_keyword: At,
+        open_parenthesis: At,
+        condition: Expression,
+        close_parenthesis: At,
+        then_body: SecondaryBlock,
+        else_body: Option<(At, SecondaryBlock)>,
+    },
+    Switch {
+        switch_keyword: At,
+        open_parenthesis: At,
+        controlling_expression: Expression,
+        close_parenthesis: At,
+        body: SecondaryBlock,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IterationStatement {
+    pub at: At,
+    pub kind: IterationStatementKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IterationStatementKind {
+    While {
+        while_keyword: At,
+        open_parenthesis: At,
+        condition: Expression,
+        close_parenthesis: At,
+        body: SecondaryBlock,
+    },
+    DoWhile {
+        do_keyword: At,
+        body: SecondaryBlock,
+        while_keyword: At,
+        open_parenthesis: At,
+        condition: Expression,
+        close_parenthesis: At,
+        semicolon: At,
+    },
+    For {
+        for_keyword: At,
+        open_parenthesis: At,
+        initializer: ForInitializer,
+        condition: Option<Expression>,
+        semicolon: At,
+        counter: Option<Expression>,
+        close_parenthesis: At,
+        body: SecondaryBlock,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JumpStatement {
+    pub at: At,
+    pub kind: JumpStatementKind,
+    pub semicolon: At,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JumpStatementKind {
+    Goto {
+        goto_keyword: At,
+        target: Box<str>,
+    },
+    ComputedGoto {
+        goto_keyword: At,
+        asterisk: At,
+        target: Expression,
+    },
+    Continue {
+        continue_keyword: At,
+    },
+    Break {
+        break_keyword: At,
+    },
+    Return {
+        return_keyword: At,
+        value: Option<Expression>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForInitializer {
+    Expression(Option<Expression>, At),
+    Declaration(Declaration),
+}
+
+pub type TranslationUnit = List<ExternalDeclaration>;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalDeclaration {
+    pub at: At,
+    pub kind: ExternalDeclarationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExternalDeclarationKind {
+    Function(FunctionDefinition),
+    Declaration(Declaration),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionDefinition {
+    pub at: At,
+    pub attributes: Option<AttributeSpecifierSequence>,
+    pub specifiers: DeclarationSpecifiers,
+    pub declarator: Declarator,
+    pub body: CompoundStatement,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Identifier(Box<str>),
+    Integer(IntegerToken),
+    Floating(FloatToken),
+    String(Box<str>, StringEncoding),
+    Character(Box<str>, StringEncoding),
+    OpenBracket,
+    CloseBracket,
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenBrace,
+    CloseBrace,
+    Period,
+    ArrowLeft,
+    DoublePlus,
+    DoubleMinus,
+    Ampersand,
+    Asterisk,
+    Plus,
+    Minus,
+    Tilde,
+    Exclamation,
+    Slash,
+    Percent,
+    DoubleLess,
+    DoubleGreater,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
+    DoubleEqual,
+    NotEqual,
+    Caret,
+    Bar,
+    DoubleAmpersand,
+    DoubleBar,
+    Question,
+    Colon,
+    DoubleColon,
+    Semicolon,
+    Ellipses,
+    Equal,
+    AsteriskEqual,
+    SlashEqual,
+    PercentEqual,
+    PlusEqual,
+    MinusEqual,
+    DoubleLessEqual,
+    DoubleGreaterEqual,
+    AmpersandEqual,
+    CaretEqual,
+    BarEqual,
+    Comma,
+    Alignas,
+    Alignof,
+    Auto,
+    Bool,
+    Break,
+    Case,
+    Char,
+    Const,
+    Constexpr,
+    Continue,
+    Default,
+    Do,
+    Double,
+    Else,
+    Enum,
+    Extern,
+    False,
+    Float,
+    For,
+    Goto,
+    If,
+    Inline,
+    Int,
+    Long,
+    Nullptr,
+    Register,
+    Restrict,
+    Return,
+    Short,
+    Signed,
+    Sizeof,
+    Static,
+    StaticAssert,
+    Struct,
+    Switch,
+    ThreadLocal,
+    True,
+    Typedef,
+    Typeof,
+    TypeofUnqual,
+    Union,
+    Unsigned,
+    Void,
+    Volatile,
+    While,
+    Atomic,
+    BitInt,
+    Complex,
+    Decimal128,
+    Decimal32,
+    Decimal64,
+    Float16,
+    Float32,
+    Float64,
+    Float128,
+    Float32x,
+    Float64x,
+    Float128x,
+    Int128,
+    Generic,
+    Imaginary,
+    Noreturn,
+    Pragma,
+    LineComment(Box<str>),
+    BlockComment(Box<str>),
+    Eof,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegerToken {
+    pub source: Box<str>,
+    pub format: IntegerFormat,
+    pub suffix: Option<IntegerSuffix>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FloatToken {
+    pub source: Box<str>,
+    pub format: FloatFormat,
+    pub suffix: Option<FloatSuffix>,
+}
+
+impl ToOwnedAst for ast::StringLiteral<'_> {
+    type Owned = StringLiteral;
+    fn to_owned_ast(&self) -> StringLiteral {
+        let ast::StringLiteral {
+            at,
+            literal,
+            encoding,
+        } = self;
+        StringLiteral {
+            at: at.to_owned_ast(),
+            literal: literal.to_owned_ast(),
+            encoding: encoding.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::CharLiteral<'_> {
+    type Owned = CharLiteral;
+    fn to_owned_ast(&self) -> CharLiteral {
+        let ast::CharLiteral {
+            at,
+            literal,
+            encoding,
+        } = self;
+        CharLiteral {
+            at: at.to_owned_ast(),
+            literal: literal.to_owned_ast(),
+            encoding: encoding.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Expression<'_> {
+    type Owned = Expression;
+    fn to_owned_ast(&self) -> Expression {
+        let ast::Expression { at, kind } = self;
+        Expression {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ExpressionKind<'_> {
+    type Owned = ExpressionKind;
+    fn to_owned_ast(&self) -> ExpressionKind {
+        match self {
+            ast::ExpressionKind::Identifier(value) => {
+                ExpressionKind::Identifier(value.to_owned_ast())
+            }
+            ast::ExpressionKind::Integer(value) => ExpressionKind::Integer(value.to_owned_ast()),
+            ast::ExpressionKind::Float(value) => ExpressionKind::Float(value.to_owned_ast()),
+            ast::ExpressionKind::String(value) => ExpressionKind::String(value.to_owned_ast()),
+            ast::ExpressionKind::Character(value) => {
+                ExpressionKind::Character(value.to_owned_ast())
+            }
+            ast::ExpressionKind::Bool(value) => ExpressionKind::Bool(value.to_owned_ast()),
+            ast::ExpressionKind::Nullptr => ExpressionKind::Nullptr,
+            ast::ExpressionKind::Parenthesized {
+                open_parenthesis,
+                inner,
+                close_parenthesis,
+            } => ExpressionKind::Parenthesized {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::ExpressionKind::GenericSelection(value) => {
+                ExpressionKind::GenericSelection(value.to_owned_ast())
+            }
+            ast::ExpressionKind::StatementExpression {
+                open_parenthesis,
+                body,
+                close_parenthesis,
+            } => ExpressionKind::StatementExpression {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                body: body.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::ExpressionKind::Index {
+                left,
+                open_bracket,
+                index,
+                close_bracket,
+            } => ExpressionKind::Index {
+                left: left.to_owned_ast(),
+                open_bracket: open_bracket.to_owned_ast(),
+                index: index.to_owned_ast(),
+                close_bracket: close_bracket.to_owned_ast(),
+            },
+            ast::ExpressionKind::Call {
+                left,
+                open_parenthesis,
+                arguments,
+                trailing_comma,
+                close_parenthesis,
+            } => ExpressionKind::Call {
+                left: left.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                arguments: arguments.to_owned_ast(),
+                trailing_comma: trailing_comma.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::ExpressionKind::Member { left, period, name } => ExpressionKind::Member {
+                left: left.to_owned_ast(),
+                period: period.to_owned_ast(),
+                name: name.to_owned_ast(),
+            },
+            ast::ExpressionKind::MemberIndirect { left, arrow, name } => {
+                ExpressionKind::MemberIndirect {
+                    left: left.to_owned_ast(),
+                    arrow: arrow.to_owned_ast(),
+                    name: name.to_owned_ast(),
+                }
+            }
+            ast::ExpressionKind::PostIncrement { left, double_plus } => {
+                ExpressionKind::PostIncrement {
+                    left: left.to_owned_ast(),
+                    double_plus: double_plus.to_owned_ast(),
+                }
+            }
+            ast::ExpressionKind::PostDecrement { left, double_minus } => {
+                ExpressionKind::PostDecrement {
+                    left: left.to_owned_ast(),
+                    double_minus: double_minus.to_owned_ast(),
+                }
+            }
+            ast::ExpressionKind::CompoundLiteral(value) => {
+                ExpressionKind::CompoundLiteral(value.to_owned_ast())
+            }
+            ast::ExpressionKind::PreIncrement { double_plus, right } => {
+                ExpressionKind::PreIncrement {
+                    double_plus: double_plus.to_owned_ast(),
+                    right: right.to_owned_ast(),
+                }
+            }
+            ast::ExpressionKind::PreDecrement {
+                double_minus,
+                right,
+            } => ExpressionKind::PreDecrement {
+                double_minus: double_minus.to_owned_ast(),
+                right: right.to_owned_ast(),
+            },
+            ast::ExpressionKind::Unary(first, second) => {
+                ExpressionKind::Unary(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::ExpressionKind::LabelAddress(value) => {
+                ExpressionKind::LabelAddress(value.to_owned_ast())
+            }
+            ast::ExpressionKind::Sizeof {
+                sizeof_keyword,
+                kind,
+            } => ExpressionKind::Sizeof {
+                sizeof_keyword: sizeof_keyword.to_owned_ast(),
+                kind: kind.to_owned_ast(),
+            },
+            ast::ExpressionKind::Alignof {
+                alignof_keyword,
+                open_parenthesis,
+                type_name,
+                close_parenthesis,
+            } => ExpressionKind::Alignof {
+                alignof_keyword: alignof_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                type_name: type_name.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::ExpressionKind::Cast {
+                open_parenthesis,
+                type_name,
+                close_parenthesis,
+                right,
+            } => ExpressionKind::Cast {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                type_name: type_name.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                right: right.to_owned_ast(),
+            },
+            ast::ExpressionKind::Binary {
+                left,
+                operator,
+                right,
+            } => ExpressionKind::Binary {
+                left: left.to_owned_ast(),
+                operator: operator.to_owned_ast(),
+                right: right.to_owned_ast(),
+            },
+            ast::ExpressionKind::Conditional {
+                condition,
+                question,
+                then_value,
+                colon,
+                else_value,
+            } => ExpressionKind::Conditional {
+                condition: condition.to_owned_ast(),
+                question: question.to_owned_ast(),
+                then_value: then_value.to_owned_ast(),
+                colon: colon.to_owned_ast(),
+                else_value: else_value.to_owned_ast(),
+            },
+            ast::ExpressionKind::Assign {
+                left,
+                operator,
+                right,
+            } => ExpressionKind::Assign {
+                left: left.to_owned_ast(),
+                operator: operator.to_owned_ast(),
+                right: right.to_owned_ast(),
+            },
+            ast::ExpressionKind::Comma { left, comma, right } => ExpressionKind::Comma {
+                left: left.to_owned_ast(),
+                comma: comma.to_owned_ast(),
+                right: right.to_owned_ast(),
+            },
+            ast::ExpressionKind::Offsetof {
+                builtin,
+                open_parenthesis,
+                type_name,
+                comma,
+                member,
+                close_parenthesis,
+            } => ExpressionKind::Offsetof {
+                builtin: builtin.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                type_name: type_name.to_owned_ast(),
+                comma: comma.to_owned_ast(),
+                member: member.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::ExpressionKind::TypesCompatible {
+                builtin,
+                open_parenthesis,
+                left,
+                comma,
+                right,
+                close_parenthesis,
+            } => ExpressionKind::TypesCompatible {
+                builtin: builtin.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                left: left.to_owned_ast(),
+                comma: comma.to_owned_ast(),
+                right: right.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::GenericSelection<'_> {
+    type Owned = GenericSelection;
+    fn to_owned_ast(&self) -> GenericSelection {
+        let ast::GenericSelection {
+            at,
+            generic_keyword,
+            open_parenthesis,
+            controlling_expression,
+            comma,
+            generic_assocs,
+            close_parenthesis,
+        } = self;
+        GenericSelection {
+            at: at.to_owned_ast(),
+            generic_keyword: generic_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            controlling_expression: controlling_expression.to_owned_ast(),
+            comma: comma.to_owned_ast(),
+            generic_assocs: generic_assocs.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::GenericAssociation<'_> {
+    type Owned = GenericAssociation;
+    fn to_owned_ast(&self) -> GenericAssociation {
+        let ast::GenericAssociation {
+            at,
+            colon,
+            kind,
+            value,
+        } = self;
+        GenericAssociation {
+            at: at.to_owned_ast(),
+            colon: colon.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            value: value.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::GenericAssociationKind<'_> {
+    type Owned = GenericAssociationKind;
+    fn to_owned_ast(&self) -> GenericAssociationKind {
+        match self {
+            ast::GenericAssociationKind::Default { default_keyword } => {
+                GenericAssociationKind::Default {
+                    default_keyword: default_keyword.to_owned_ast(),
+                }
+            }
+            ast::GenericAssociationKind::ForType(value) => {
+                GenericAssociationKind::ForType(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::CompoundLiteral<'_> {
+    type Owned = CompoundLiteral;
+    fn to_owned_ast(&self) -> CompoundLiteral {
+        let ast::CompoundLiteral {
+            at,
+            open_parenthesis,
+            storage_class,
+            type_name,
+            close_parenthesis,
+            initializer,
+        } = self;
+        CompoundLiteral {
+            at: at.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            storage_class: storage_class.to_owned_ast(),
+            type_name: type_name.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+            initializer: initializer.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SizeofKind<'_> {
+    type Owned = SizeofKind;
+    fn to_owned_ast(&self) -> SizeofKind {
+        match self {
+            ast::SizeofKind::Expression(value) => SizeofKind::Expression(value.to_owned_ast()),
+            ast::SizeofKind::Type {
+                open_parenthesis,
+                type_name,
+                close_parenthesis,
+            } => SizeofKind::Type {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                type_name: type_name.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Declaration<'_> {
+    type Owned = Declaration;
+    fn to_owned_ast(&self) -> Declaration {
+        let ast::Declaration { at, kind } = self;
+        Declaration {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DeclarationKind<'_> {
+    type Owned = DeclarationKind;
+    fn to_owned_ast(&self) -> DeclarationKind {
+        match self {
+            ast::DeclarationKind::Normal {
+                attributes,
+                specifiers,
+                init_declarators,
+                semicolon,
+            } => DeclarationKind::Normal {
+                attributes: attributes.to_owned_ast(),
+                specifiers: specifiers.to_owned_ast(),
+                init_declarators: init_declarators.to_owned_ast(),
+                semicolon: semicolon.to_owned_ast(),
+            },
+            ast::DeclarationKind::Assert(value) => DeclarationKind::Assert(value.to_owned_ast()),
+            ast::DeclarationKind::Attribute(value) => {
+                DeclarationKind::Attribute(value.to_owned_ast())
+            }
+            ast::DeclarationKind::Pragma(value) => DeclarationKind::Pragma(value.to_owned_ast()),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DeclarationSpecifiers<'_> {
+    type Owned = DeclarationSpecifiers;
+    fn to_owned_ast(&self) -> DeclarationSpecifiers {
+        let ast::DeclarationSpecifiers {
+            at,
+            specifier,
+            kind,
+        } = self;
+        DeclarationSpecifiers {
+            at: at.to_owned_ast(),
+            specifier: specifier.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DeclarationSpecifiersKind<'_> {
+    type Owned = DeclarationSpecifiersKind;
+    fn to_owned_ast(&self) -> DeclarationSpecifiersKind {
+        match self {
+            ast::DeclarationSpecifiersKind::Leaf(value) => {
+                DeclarationSpecifiersKind::Leaf(value.to_owned_ast())
+            }
+            ast::DeclarationSpecifiersKind::Cons(value) => {
+                DeclarationSpecifiersKind::Cons(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DeclarationSpecifier<'_> {
+    type Owned = DeclarationSpecifier;
+    fn to_owned_ast(&self) -> DeclarationSpecifier {
+        let ast::DeclarationSpecifier { at, kind } = self;
+        DeclarationSpecifier {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DeclarationSpecifierKind<'_> {
+    type Owned = DeclarationSpecifierKind;
+    fn to_owned_ast(&self) -> DeclarationSpecifierKind {
+        match self {
+            ast::DeclarationSpecifierKind::StorageClass(value) => {
+                DeclarationSpecifierKind::StorageClass(value.to_owned_ast())
+            }
+            ast::DeclarationSpecifierKind::Type(value) => {
+                DeclarationSpecifierKind::Type(value.to_owned_ast())
+            }
+            ast::DeclarationSpecifierKind::Function(value) => {
+                DeclarationSpecifierKind::Function(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::InitDeclarator<'_> {
+    type Owned = InitDeclarator;
+    fn to_owned_ast(&self) -> InitDeclarator {
+        let ast::InitDeclarator {
+            at,
+            declarator,
+            initializer,
+        } = self;
+        InitDeclarator {
+            at: at.to_owned_ast(),
+            declarator: declarator.to_owned_ast(),
+            initializer: initializer.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AttributeDeclaration<'_> {
+    type Owned = AttributeDeclaration;
+    fn to_owned_ast(&self) -> AttributeDeclaration {
+        let ast::AttributeDeclaration {
+            at,
+            attributes,
+            semicolon,
+        } = self;
+        AttributeDeclaration {
+            at: at.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            semicolon: semicolon.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeSpecifier<'_> {
+    type Owned = TypeSpecifier;
+    fn to_owned_ast(&self) -> TypeSpecifier {
+        let ast::TypeSpecifier { at, kind } = self;
+        TypeSpecifier {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeSpecifierKind<'_> {
+    type Owned = TypeSpecifierKind;
+    fn to_owned_ast(&self) -> TypeSpecifierKind {
+        match self {
+            ast::TypeSpecifierKind::Void => TypeSpecifierKind::Void,
+            ast::TypeSpecifierKind::Char => TypeSpecifierKind::Char,
+            ast::TypeSpecifierKind::Short => TypeSpecifierKind::Short,
+            ast::TypeSpecifierKind::Int => TypeSpecifierKind::Int,
+            ast::TypeSpecifierKind::Long => TypeSpecifierKind::Long,
+            ast::TypeSpecifierKind::Float => TypeSpecifierKind::Float,
+            ast::TypeSpecifierKind::Double => TypeSpecifierKind::Double,
+            ast::TypeSpecifierKind::Signed => TypeSpecifierKind::Signed,
+            ast::TypeSpecifierKind::Unsigned => TypeSpecifierKind::Unsigned,
+            ast::TypeSpecifierKind::BitInt {
+                bitint_keyword,
+                open_parenthesis,
+                width,
+                close_parenthesis,
+            } => TypeSpecifierKind::BitInt {
+                bitint_keyword: bitint_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                width: width.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::TypeSpecifierKind::Bool => TypeSpecifierKind::Bool,
+            ast::TypeSpecifierKind::Complex => TypeSpecifierKind::Complex,
+            ast::TypeSpecifierKind::Decimal32 => TypeSpecifierKind::Decimal32,
+            ast::TypeSpecifierKind::Decimal64 => TypeSpecifierKind::Decimal64,
+            ast::TypeSpecifierKind::Decimal128 => TypeSpecifierKind::Decimal128,
+            ast::TypeSpecifierKind::Float16 => TypeSpecifierKind::Float16,
+            ast::TypeSpecifierKind::Float32 => TypeSpecifierKind::Float32,
+            ast::TypeSpecifierKind::Float64 => TypeSpecifierKind::Float64,
+            ast::TypeSpecifierKind::Float128 => TypeSpecifierKind::Float128,
+            ast::TypeSpecifierKind::Float32x => TypeSpecifierKind::Float32x,
+            ast::TypeSpecifierKind::Float64x => TypeSpecifierKind::Float64x,
+            ast::TypeSpecifierKind::Float128x => TypeSpecifierKind::Float128x,
+            ast::TypeSpecifierKind::Int128 => TypeSpecifierKind::Int128,
+            ast::TypeSpecifierKind::Atomic(value) => {
+                TypeSpecifierKind::Atomic(value.to_owned_ast())
+            }
+            ast::TypeSpecifierKind::StructOrUnion(value) => {
+                TypeSpecifierKind::StructOrUnion(value.to_owned_ast())
+            }
+            ast::TypeSpecifierKind::Enum(value) => TypeSpecifierKind::Enum(value.to_owned_ast()),
+            ast::TypeSpecifierKind::TypedefName(value) => {
+                TypeSpecifierKind::TypedefName(value.to_owned_ast())
+            }
+            ast::TypeSpecifierKind::Typeof(value) => {
+                TypeSpecifierKind::Typeof(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::StructOrUnionSpecifier<'_> {
+    type Owned = StructOrUnionSpecifier;
+    fn to_owned_ast(&self) -> StructOrUnionSpecifier {
+        let ast::StructOrUnionSpecifier {
+            at,
+            struct_or_union,
+            attributes,
+            tag,
+            members,
+        } = self;
+        StructOrUnionSpecifier {
+            at: at.to_owned_ast(),
+            struct_or_union: struct_or_union.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            tag: tag.to_owned_ast(),
+            members: members.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::MemberDeclaration<'_> {
+    type Owned = MemberDeclaration;
+    fn to_owned_ast(&self) -> MemberDeclaration {
+        let ast::MemberDeclaration { at, kind } = self;
+        MemberDeclaration {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::MemberDeclarationKind<'_> {
+    type Owned = MemberDeclarationKind;
+    fn to_owned_ast(&self) -> MemberDeclarationKind {
+        match self {
+            ast::MemberDeclarationKind::Member {
+                attributes,
+                specifier_qualifiers,
+                member_declarators,
+                semicolon,
+            } => MemberDeclarationKind::Member {
+                attributes: attributes.to_owned_ast(),
+                specifier_qualifiers: specifier_qualifiers.to_owned_ast(),
+                member_declarators: member_declarators.to_owned_ast(),
+                semicolon: semicolon.to_owned_ast(),
+            },
+            ast::MemberDeclarationKind::Assert(value) => {
+                MemberDeclarationKind::Assert(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SpecifierQualifierList<'_> {
+    type Owned = SpecifierQualifierList;
+    fn to_owned_ast(&self) -> SpecifierQualifierList {
+        let ast::SpecifierQualifierList {
+            at,
+            specifier_qualifier,
+            kind,
+        } = self;
+        SpecifierQualifierList {
+            at: at.to_owned_ast(),
+            specifier_qualifier: specifier_qualifier.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SpecifierQualifierListKind<'_> {
+    type Owned = SpecifierQualifierListKind;
+    fn to_owned_ast(&self) -> SpecifierQualifierListKind {
+        match self {
+            ast::SpecifierQualifierListKind::Leaf(value) => {
+                SpecifierQualifierListKind::Leaf(value.to_owned_ast())
+            }
+            ast::SpecifierQualifierListKind::Cons(value) => {
+                SpecifierQualifierListKind::Cons(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeSpecifierQualifier<'_> {
+    type Owned = TypeSpecifierQualifier;
+    fn to_owned_ast(&self) -> TypeSpecifierQualifier {
+        let ast::TypeSpecifierQualifier { at, kind } = self;
+        TypeSpecifierQualifier {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeSpecifierQualifierKind<'_> {
+    type Owned = TypeSpecifierQualifierKind;
+    fn to_owned_ast(&self) -> TypeSpecifierQualifierKind {
+        match self {
+            ast::TypeSpecifierQualifierKind::TypeSpecifier(value) => {
+                TypeSpecifierQualifierKind::TypeSpecifier(value.to_owned_ast())
+            }
+            ast::TypeSpecifierQualifierKind::TypeQualifier(value) => {
+                TypeSpecifierQualifierKind::TypeQualifier(value.to_owned_ast())
+            }
+            ast::TypeSpecifierQualifierKind::Alignment(value) => {
+                TypeSpecifierQualifierKind::Alignment(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::MemberDeclarator<'_> {
+    type Owned = MemberDeclarator;
+    fn to_owned_ast(&self) -> MemberDeclarator {
+        let ast::MemberDeclarator {
+            at,
+            declarator,
+            width,
+        } = self;
+        MemberDeclarator {
+            at: at.to_owned_ast(),
+            declarator: declarator.to_owned_ast(),
+            width: width.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::EnumSpecifier<'_> {
+    type Owned = EnumSpecifier;
+    fn to_owned_ast(&self) -> EnumSpecifier {
+        let ast::EnumSpecifier {
+            at,
+            enum_keyword,
+            attributes,
+            tag,
+            enum_type,
+            enumerators,
+        } = self;
+        EnumSpecifier {
+            at: at.to_owned_ast(),
+            enum_keyword: enum_keyword.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            tag: tag.to_owned_ast(),
+            enum_type: enum_type.to_owned_ast(),
+            enumerators: enumerators.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Enumerator<'_> {
+    type Owned = Enumerator;
+    fn to_owned_ast(&self) -> Enumerator {
+        let ast::Enumerator {
+            at,
+            name,
+            attributes,
+            value,
+        } = self;
+        Enumerator {
+            at: at.to_owned_ast(),
+            name: name.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            value: value.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::EnumTypeSpecifier<'_> {
+    type Owned = EnumTypeSpecifier;
+    fn to_owned_ast(&self) -> EnumTypeSpecifier {
+        let ast::EnumTypeSpecifier {
+            at,
+            colon,
+            specifier_qualifiers,
+        } = self;
+        EnumTypeSpecifier {
+            at: at.to_owned_ast(),
+            colon: colon.to_owned_ast(),
+            specifier_qualifiers: specifier_qualifiers.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AtomicTypeSpecifier<'_> {
+    type Owned = AtomicTypeSpecifier;
+    fn to_owned_ast(&self) -> AtomicTypeSpecifier {
+        let ast::AtomicTypeSpecifier {
+            at,
+            atomic_keyword,
+            open_parenthesis,
+            type_name,
+            close_parenthesis,
+        } = self;
+        AtomicTypeSpecifier {
+            at: at.to_owned_ast(),
+            atomic_keyword: atomic_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            type_name: type_name.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeofSpecifier<'_> {
+    type Owned = TypeofSpecifier;
+    fn to_owned_ast(&self) -> TypeofSpecifier {
+        let ast::TypeofSpecifier {
+            at,
+            typeof_keyword,
+            unqual,
+            open_parenthesis,
+            argument,
+            close_parenthesis,
+        } = self;
+        TypeofSpecifier {
+            at: at.to_owned_ast(),
+            typeof_keyword: typeof_keyword.to_owned_ast(),
+            unqual: unqual.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            argument: argument.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeofSpecifierArgument<'_> {
+    type Owned = TypeofSpecifierArgument;
+    fn to_owned_ast(&self) -> TypeofSpecifierArgument {
+        let ast::TypeofSpecifierArgument { at, kind } = self;
+        TypeofSpecifierArgument {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeofSpecifierArgumentKind<'_> {
+    type Owned = TypeofSpecifierArgumentKind;
+    fn to_owned_ast(&self) -> TypeofSpecifierArgumentKind {
+        match self {
+            ast::TypeofSpecifierArgumentKind::Expression(value) => {
+                TypeofSpecifierArgumentKind::Expression(value.to_owned_ast())
+            }
+            ast::TypeofSpecifierArgumentKind::Type(value) => {
+                TypeofSpecifierArgumentKind::Type(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AlignmentSpecifier<'_> {
+    type Owned = AlignmentSpecifier;
+    fn to_owned_ast(&self) -> AlignmentSpecifier {
+        let ast::AlignmentSpecifier {
+            at,
+            alignas_keyword,
+            open_parenthesis,
+            kind,
+            close_parenthesis,
+        } = self;
+        AlignmentSpecifier {
+            at: at.to_owned_ast(),
+            alignas_keyword: alignas_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AlignmentSpecifierKind<'_> {
+    type Owned = AlignmentSpecifierKind;
+    fn to_owned_ast(&self) -> AlignmentSpecifierKind {
+        match self {
+            ast::AlignmentSpecifierKind::Type(value) => {
+                AlignmentSpecifierKind::Type(value.to_owned_ast())
+            }
+            ast::AlignmentSpecifierKind::Expression(value) => {
+                AlignmentSpecifierKind::Expression(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Declarator<'_> {
+    type Owned = Declarator;
+    fn to_owned_ast(&self) -> Declarator {
+        let ast::Declarator {
+            at,
+            pointer,
+            direct,
+            asm_label,
+        } = self;
+        Declarator {
+            at: at.to_owned_ast(),
+            pointer: pointer.to_owned_ast(),
+            direct: direct.to_owned_ast(),
+            asm_label: asm_label.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AsmLabel<'_> {
+    type Owned = AsmLabel;
+    fn to_owned_ast(&self) -> AsmLabel {
+        let ast::AsmLabel {
+            at,
+            asm_keyword,
+            open_parenthesis,
+            name,
+            close_parenthesis,
+        } = self;
+        AsmLabel {
+            at: at.to_owned_ast(),
+            asm_keyword: asm_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            name: name.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DirectDeclarator<'_> {
+    type Owned = DirectDeclarator;
+    fn to_owned_ast(&self) -> DirectDeclarator {
+        let ast::DirectDeclarator { at, kind } = self;
+        DirectDeclarator {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DirectDeclaratorKind<'_> {
+    type Owned = DirectDeclaratorKind;
+    fn to_owned_ast(&self) -> DirectDeclaratorKind {
+        match self {
+            ast::DirectDeclaratorKind::Name(first, second) => {
+                DirectDeclaratorKind::Name(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::DirectDeclaratorKind::Parenthesized {
+                open_parenthesis,
+                inner,
+                close_parenthesis,
+            } => DirectDeclaratorKind::Parenthesized {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::DirectDeclaratorKind::Array(first, second) => {
+                DirectDeclaratorKind::Array(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::DirectDeclaratorKind::Function(first, second) => {
+                DirectDeclaratorKind::Function(first.to_owned_ast(), second.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ArrayDeclarator<'_> {
+    type Owned = ArrayDeclarator;
+    fn to_owned_ast(&self) -> ArrayDeclarator {
+        let ast::ArrayDeclarator {
+            at,
+            left,
+            open_bracket,
+            qualifiers,
+            kind,
+            close_bracket,
+        } = self;
+        ArrayDeclarator {
+            at: at.to_owned_ast(),
+            left: left.to_owned_ast(),
+            open_bracket: open_bracket.to_owned_ast(),
+            qualifiers: qualifiers.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            close_bracket: close_bracket.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ArrayDeclaratorKind<'_> {
+    type Owned = ArrayDeclaratorKind;
+    fn to_owned_ast(&self) -> ArrayDeclaratorKind {
+        match self {
+            ast::ArrayDeclaratorKind::Normal {
+                static_keyword,
+                size,
+            } => ArrayDeclaratorKind::Normal {
+                static_keyword: static_keyword.to_owned_ast(),
+                size: size.to_owned_ast(),
+            },
+            ast::ArrayDeclaratorKind::Var { asterisk } => ArrayDeclaratorKind::Var {
+                asterisk: asterisk.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::FunctionDeclarator<'_> {
+    type Owned = FunctionDeclarator;
+    fn to_owned_ast(&self) -> FunctionDeclarator {
+        let ast::FunctionDeclarator {
+            at,
+            left,
+            open_parenthesis,
+            parameters,
+            close_parenthesis,
+        } = self;
+        FunctionDeclarator {
+            at: at.to_owned_ast(),
+            left: left.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            parameters: parameters.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Pointer<'_> {
+    type Owned = Pointer;
+    fn to_owned_ast(&self) -> Pointer {
+        let ast::Pointer {
+            at,
+            asterisk,
+            attributes,
+            qualifiers,
+            right,
+        } = self;
+        Pointer {
+            at: at.to_owned_ast(),
+            asterisk: asterisk.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            qualifiers: qualifiers.to_owned_ast(),
+            right: right.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ParameterTypeList<'_> {
+    type Owned = ParameterTypeList;
+    fn to_owned_ast(&self) -> ParameterTypeList {
+        let ast::ParameterTypeList {
+            at,
+            parameters,
+            ellipses,
+        } = self;
+        ParameterTypeList {
+            at: at.to_owned_ast(),
+            parameters: parameters.to_owned_ast(),
+            ellipses: ellipses.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ParameterDeclaration<'_> {
+    type Owned = ParameterDeclaration;
+    fn to_owned_ast(&self) -> ParameterDeclaration {
+        let ast::ParameterDeclaration {
+            at,
+            attributes,
+            specifiers,
+            kind,
+        } = self;
+        ParameterDeclaration {
+            at: at.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            specifiers: specifiers.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ParameterDeclarationKind<'_> {
+    type Owned = ParameterDeclarationKind;
+    fn to_owned_ast(&self) -> ParameterDeclarationKind {
+        match self {
+            ast::ParameterDeclarationKind::Concrete(value) => {
+                ParameterDeclarationKind::Concrete(value.to_owned_ast())
+            }
+            ast::ParameterDeclarationKind::Abstract(value) => {
+                ParameterDeclarationKind::Abstract(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::TypeName<'_> {
+    type Owned = TypeName;
+    fn to_owned_ast(&self) -> TypeName {
+        let ast::TypeName {
+            at,
+            specifier_qualifiers,
+            declarator,
+        } = self;
+        TypeName {
+            at: at.to_owned_ast(),
+            specifier_qualifiers: specifier_qualifiers.to_owned_ast(),
+            declarator: declarator.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AbstractDeclarator<'_> {
+    type Owned = AbstractDeclarator;
+    fn to_owned_ast(&self) -> AbstractDeclarator {
+        let ast::AbstractDeclarator {
+            at,
+            pointer,
+            direct,
+        } = self;
+        AbstractDeclarator {
+            at: at.to_owned_ast(),
+            pointer: pointer.to_owned_ast(),
+            direct: direct.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DirectAbstractDeclarator<'_> {
+    type Owned = DirectAbstractDeclarator;
+    fn to_owned_ast(&self) -> DirectAbstractDeclarator {
+        let ast::DirectAbstractDeclarator { at, kind } = self;
+        DirectAbstractDeclarator {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DirectAbstractDeclaratorKind<'_> {
+    type Owned = DirectAbstractDeclaratorKind;
+    fn to_owned_ast(&self) -> DirectAbstractDeclaratorKind {
+        match self {
+            ast::DirectAbstractDeclaratorKind::Parenthesized {
+                open_parenthesis,
+                inner,
+                close_parenthesis,
+            } => DirectAbstractDeclaratorKind::Parenthesized {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::DirectAbstractDeclaratorKind::Array(first, second) => {
+                DirectAbstractDeclaratorKind::Array(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::DirectAbstractDeclaratorKind::Function(first, second) => {
+                DirectAbstractDeclaratorKind::Function(first.to_owned_ast(), second.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ArrayAbstractDeclarator<'_> {
+    type Owned = ArrayAbstractDeclarator;
+    fn to_owned_ast(&self) -> ArrayAbstractDeclarator {
+        let ast::ArrayAbstractDeclarator {
+            at,
+            left,
+            open_bracket,
+            kind,
+            close_bracket,
+        } = self;
+        ArrayAbstractDeclarator {
+            at: at.to_owned_ast(),
+            left: left.to_owned_ast(),
+            open_bracket: open_bracket.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            close_bracket: close_bracket.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ArrayAbstractDeclaratorKind<'_> {
+    type Owned = ArrayAbstractDeclaratorKind;
+    fn to_owned_ast(&self) -> ArrayAbstractDeclaratorKind {
+        match self {
+            ast::ArrayAbstractDeclaratorKind::Normal {
+                qualifiers,
+                static_keyword,
+                size,
+            } => ArrayAbstractDeclaratorKind::Normal {
+                qualifiers: qualifiers.to_owned_ast(),
+                static_keyword: static_keyword.to_owned_ast(),
+                size: size.to_owned_ast(),
+            },
+            ast::ArrayAbstractDeclaratorKind::Var { asterisk } => {
+                ArrayAbstractDeclaratorKind::Var {
+                    asterisk: asterisk.to_owned_ast(),
+                }
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::FunctionAbstractDeclarator<'_> {
+    type Owned = FunctionAbstractDeclarator;
+    fn to_owned_ast(&self) -> FunctionAbstractDeclarator {
+        let ast::FunctionAbstractDeclarator {
+            at,
+            left,
+            open_parenthesis,
+            parameters,
+            close_parenthesis,
+        } = self;
+        FunctionAbstractDeclarator {
+            at: at.to_owned_ast(),
+            left: left.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            parameters: parameters.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::BracedInitializer<'_> {
+    type Owned = BracedInitializer;
+    fn to_owned_ast(&self) -> BracedInitializer {
+        let ast::BracedInitializer {
+            at,
+            open_brace,
+            initializers,
+            close_brace,
+        } = self;
+        BracedInitializer {
+            at: at.to_owned_ast(),
+            open_brace: open_brace.to_owned_ast(),
+            initializers: initializers.to_owned_ast(),
+            close_brace: close_brace.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Initializer<'_> {
+    type Owned = Initializer;
+    fn to_owned_ast(&self) -> Initializer {
+        let ast::Initializer { at, kind } = self;
+        Initializer {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::InitializerKind<'_> {
+    type Owned = InitializerKind;
+    fn to_owned_ast(&self) -> InitializerKind {
+        match self {
+            ast::InitializerKind::Expression(value) => {
+                InitializerKind::Expression(value.to_owned_ast())
+            }
+            ast::InitializerKind::Braced(value) => InitializerKind::Braced(value.to_owned_ast()),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Designation<'_> {
+    type Owned = Designation;
+    fn to_owned_ast(&self) -> Designation {
+        let ast::Designation {
+            at,
+            designators,
+            equal,
+        } = self;
+        Designation {
+            at: at.to_owned_ast(),
+            designators: designators.to_owned_ast(),
+            equal: equal.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Designator<'_> {
+    type Owned = Designator;
+    fn to_owned_ast(&self) -> Designator {
+        let ast::Designator { at, kind } = self;
+        Designator {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::DesignatorKind<'_> {
+    type Owned = DesignatorKind;
+    fn to_owned_ast(&self) -> DesignatorKind {
+        match self {
+            ast::DesignatorKind::InBrackets {
+                open_bracket,
+                value,
+                close_bracket,
+            } => DesignatorKind::InBrackets {
+                open_bracket: open_bracket.to_owned_ast(),
+                value: value.to_owned_ast(),
+                close_bracket: close_bracket.to_owned_ast(),
+            },
+            ast::DesignatorKind::AfterPeriod { period, name } => DesignatorKind::AfterPeriod {
+                period: period.to_owned_ast(),
+                name: name.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::MemberDesignator<'_> {
+    type Owned = MemberDesignator;
+    fn to_owned_ast(&self) -> MemberDesignator {
+        let ast::MemberDesignator {
+            at,
+            name,
+            designators,
+        } = self;
+        MemberDesignator {
+            at: at.to_owned_ast(),
+            name: name.to_owned_ast(),
+            designators: designators.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::PragmaOperator<'_> {
+    type Owned = PragmaOperator;
+    fn to_owned_ast(&self) -> PragmaOperator {
+        let ast::PragmaOperator {
+            at,
+            pragma_keyword,
+            open_parenthesis,
+            directive,
+            close_parenthesis,
+        } = self;
+        PragmaOperator {
+            at: at.to_owned_ast(),
+            pragma_keyword: pragma_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            directive: directive.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::StaticAssertDeclaration<'_> {
+    type Owned = StaticAssertDeclaration;
+    fn to_owned_ast(&self) -> StaticAssertDeclaration {
+        let ast::StaticAssertDeclaration {
+            at,
+            static_assert_keyword,
+            open_parenthesis,
+            condition,
+            message,
+            close_parenthesis,
+            semicolon,
+        } = self;
+        StaticAssertDeclaration {
+            at: at.to_owned_ast(),
+            static_assert_keyword: static_assert_keyword.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            condition: condition.to_owned_ast(),
+            message: message.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+            semicolon: semicolon.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AttributeSpecifierSequence<'_> {
+    type Owned = AttributeSpecifierSequence;
+    fn to_owned_ast(&self) -> AttributeSpecifierSequence {
+        let ast::AttributeSpecifierSequence {
+            at,
+            left,
+            specifier,
+        } = self;
+        AttributeSpecifierSequence {
+            at: at.to_owned_ast(),
+            left: left.to_owned_ast(),
+            specifier: specifier.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AttributeSpecifier<'_> {
+    type Owned = AttributeSpecifier;
+    fn to_owned_ast(&self) -> AttributeSpecifier {
+        let ast::AttributeSpecifier {
+            at,
+            open_bracket_0,
+            open_bracket_1,
+            attributes,
+            close_bracket_0,
+            close_bracket_1,
+        } = self;
+        AttributeSpecifier {
+            at: at.to_owned_ast(),
+            open_bracket_0: open_bracket_0.to_owned_ast(),
+            open_bracket_1: open_bracket_1.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            close_bracket_0: close_bracket_0.to_owned_ast(),
+            close_bracket_1: close_bracket_1.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Attribute<'_> {
+    type Owned = Attribute;
+    fn to_owned_ast(&self) -> Attribute {
+        let ast::Attribute {
+            at,
+            token,
+            argument_clause,
+        } = self;
+        Attribute {
+            at: at.to_owned_ast(),
+            token: token.to_owned_ast(),
+            argument_clause: argument_clause.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AttributeToken<'_> {
+    type Owned = AttributeToken;
+    fn to_owned_ast(&self) -> AttributeToken {
+        let ast::AttributeToken { at, prefix, token } = self;
+        AttributeToken {
+            at: at.to_owned_ast(),
+            prefix: prefix.to_owned_ast(),
+            token: token.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::AttributeArgumentClause<'_> {
+    type Owned = AttributeArgumentClause;
+    fn to_owned_ast(&self) -> AttributeArgumentClause {
+        let ast::AttributeArgumentClause {
+            at,
+            open_parenthesis,
+            tokens,
+            close_parenthesis,
+        } = self;
+        AttributeArgumentClause {
+            at: at.to_owned_ast(),
+            open_parenthesis: open_parenthesis.to_owned_ast(),
+            tokens: tokens.to_owned_ast(),
+            close_parenthesis: close_parenthesis.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::BalancedToken<'_> {
+    type Owned = BalancedToken;
+    fn to_owned_ast(&self) -> BalancedToken {
+        let ast::BalancedToken { at, kind } = self;
+        BalancedToken {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::BalancedTokenKind<'_> {
+    type Owned = BalancedTokenKind;
+    fn to_owned_ast(&self) -> BalancedTokenKind {
+        match self {
+            ast::BalancedTokenKind::Parenthesized {
+                open_parenthesis,
+                inner,
+                close_parenthesis,
+            } => BalancedTokenKind::Parenthesized {
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+            },
+            ast::BalancedTokenKind::Bracketed {
+                open_bracket,
+                inner,
+                close_bracket,
+            } => BalancedTokenKind::Bracketed {
+                open_bracket: open_bracket.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_bracket: close_bracket.to_owned_ast(),
+            },
+            ast::BalancedTokenKind::Braced {
+                open_brace,
+                inner,
+                close_brace,
+            } => BalancedTokenKind::Braced {
+                open_brace: open_brace.to_owned_ast(),
+                inner: inner.to_owned_ast(),
+                close_brace: close_brace.to_owned_ast(),
+            },
+            ast::BalancedTokenKind::Token(value) => BalancedTokenKind::Token(value.to_owned_ast()),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Statement<'_> {
+    type Owned = Statement;
+    fn to_owned_ast(&self) -> Statement {
+        let ast::Statement { at, kind } = self;
+        Statement {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::StatementKind<'_> {
+    type Owned = StatementKind;
+    fn to_owned_ast(&self) -> StatementKind {
+        match self {
+            ast::StatementKind::Labeled(value) => StatementKind::Labeled(value.to_owned_ast()),
+            ast::StatementKind::Unlabeled(value) => StatementKind::Unlabeled(value.to_owned_ast()),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::UnlabeledStatement<'_> {
+    type Owned = UnlabeledStatement;
+    fn to_owned_ast(&self) -> UnlabeledStatement {
+        let ast::UnlabeledStatement { at, kind } = self;
+        UnlabeledStatement {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::UnlabeledStatementKind<'_> {
+    type Owned = UnlabeledStatementKind;
+    fn to_owned_ast(&self) -> UnlabeledStatementKind {
+        match self {
+            ast::UnlabeledStatementKind::Expression(value) => {
+                UnlabeledStatementKind::Expression(value.to_owned_ast())
+            }
+            ast::UnlabeledStatementKind::Primary(first, second) => {
+                UnlabeledStatementKind::Primary(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::UnlabeledStatementKind::Jump(first, second) => {
+                UnlabeledStatementKind::Jump(first.to_owned_ast(), second.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::PrimaryBlock<'_> {
+    type Owned = PrimaryBlock;
+    fn to_owned_ast(&self) -> PrimaryBlock {
+        let ast::PrimaryBlock { at, kind } = self;
+        PrimaryBlock {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::PrimaryBlockKind<'_> {
+    type Owned = PrimaryBlockKind;
+    fn to_owned_ast(&self) -> PrimaryBlockKind {
+        match self {
+            ast::PrimaryBlockKind::Compound(value) => {
+                PrimaryBlockKind::Compound(value.to_owned_ast())
+            }
+            ast::PrimaryBlockKind::Selection(value) => {
+                PrimaryBlockKind::Selection(value.to_owned_ast())
+            }
+            ast::PrimaryBlockKind::Iteration(value) => {
+                PrimaryBlockKind::Iteration(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SecondaryBlock<'_> {
+    type Owned = SecondaryBlock;
+    fn to_owned_ast(&self) -> SecondaryBlock {
+        let ast::SecondaryBlock { at, statement } = self;
+        SecondaryBlock {
+            at: at.to_owned_ast(),
+            statement: statement.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::Label<'_> {
+    type Owned = Label;
+    fn to_owned_ast(&self) -> Label {
+        let ast::Label {
+            at,
+            attributes,
+            kind,
+            colon,
+        } = self;
+        Label {
+            at: at.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            colon: colon.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::LabelKind<'_> {
+    type Owned = LabelKind;
+    fn to_owned_ast(&self) -> LabelKind {
+        match self {
+            ast::LabelKind::Name(value) => LabelKind::Name(value.to_owned_ast()),
+            ast::LabelKind::Case {
+                case_keyword,
+                value,
+            } => LabelKind::Case {
+                case_keyword: case_keyword.to_owned_ast(),
+                value: value.to_owned_ast(),
+            },
+            ast::LabelKind::CaseRange {
+                case_keyword,
+                low,
+                ellipses,
+                high,
+            } => LabelKind::CaseRange {
+                case_keyword: case_keyword.to_owned_ast(),
+                low: low.to_owned_ast(),
+                ellipses: ellipses.to_owned_ast(),
+                high: high.to_owned_ast(),
+            },
+            ast::LabelKind::Default { default_keyword } => LabelKind::Default {
+                default_keyword: default_keyword.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::LabeledStatement<'_> {
+    type Owned = LabeledStatement;
+    fn to_owned_ast(&self) -> LabeledStatement {
+        let ast::LabeledStatement {
+            at,
+            label,
+            statement,
+        } = self;
+        LabeledStatement {
+            at: at.to_owned_ast(),
+            label: label.to_owned_ast(),
+            statement: statement.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::CompoundStatement<'_> {
+    type Owned = CompoundStatement;
+    fn to_owned_ast(&self) -> CompoundStatement {
+        let ast::CompoundStatement {
+            at,
+            open_brace,
+            items,
+            close_brace,
+        } = self;
+        CompoundStatement {
+            at: at.to_owned_ast(),
+            open_brace: open_brace.to_owned_ast(),
+            items: items.to_owned_ast(),
+            close_brace: close_brace.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::BlockItem<'_> {
+    type Owned = BlockItem;
+    fn to_owned_ast(&self) -> BlockItem {
+        let ast::BlockItem { at, kind } = self;
+        BlockItem {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::BlockItemKind<'_> {
+    type Owned = BlockItemKind;
+    fn to_owned_ast(&self) -> BlockItemKind {
+        match self {
+            ast::BlockItemKind::Declaration(value) => {
+                BlockItemKind::Declaration(value.to_owned_ast())
+            }
+            ast::BlockItemKind::Unlabeled(value) => BlockItemKind::Unlabeled(value.to_owned_ast()),
+            ast::BlockItemKind::Label(value) => BlockItemKind::Label(value.to_owned_ast()),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ExpressionStatement<'_> {
+    type Owned = ExpressionStatement;
+    fn to_owned_ast(&self) -> ExpressionStatement {
+        let ast::ExpressionStatement {
+            at,
+            attributes,
+            expression,
+            semicolon,
+        } = self;
+        ExpressionStatement {
+            at: at.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            expression: expression.to_owned_ast(),
+            semicolon: semicolon.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SelectionStatement<'_> {
+    type Owned = SelectionStatement;
+    fn to_owned_ast(&self) -> SelectionStatement {
+        let ast::SelectionStatement { at, kind } = self;
+        SelectionStatement {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::SelectionStatementKind<'_> {
+    type Owned = SelectionStatementKind;
+    fn to_owned_ast(&self) -> SelectionStatementKind {
+        match self {
+            ast::SelectionStatementKind::If {
+                if_keyword,
+                open_parenthesis,
+                condition,
+                close_parenthesis,
+                then_body,
+                else_body,
+            } => SelectionStatementKind::If {
+                if_keyword: if_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                condition: condition.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                then_body: then_body.to_owned_ast(),
+                else_body: else_body.to_owned_ast(),
+            },
+            ast::SelectionStatementKind::Switch {
+                switch_keyword,
+                open_parenthesis,
+                controlling_expression,
+                close_parenthesis,
+                body,
+            } => SelectionStatementKind::Switch {
+                switch_keyword: switch_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                controlling_expression: controlling_expression.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                body: body.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::IterationStatement<'_> {
+    type Owned = IterationStatement;
+    fn to_owned_ast(&self) -> IterationStatement {
+        let ast::IterationStatement { at, kind } = self;
+        IterationStatement {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::IterationStatementKind<'_> {
+    type Owned = IterationStatementKind;
+    fn to_owned_ast(&self) -> IterationStatementKind {
+        match self {
+            ast::IterationStatementKind::While {
+                while_keyword,
+                open_parenthesis,
+                condition,
+                close_parenthesis,
+                body,
+            } => IterationStatementKind::While {
+                while_keyword: while_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                condition: condition.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                body: body.to_owned_ast(),
+            },
+            ast::IterationStatementKind::DoWhile {
+                do_keyword,
+                body,
+                while_keyword,
+                open_parenthesis,
+                condition,
+                close_parenthesis,
+                semicolon,
+            } => IterationStatementKind::DoWhile {
+                do_keyword: do_keyword.to_owned_ast(),
+                body: body.to_owned_ast(),
+                while_keyword: while_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                condition: condition.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                semicolon: semicolon.to_owned_ast(),
+            },
+            ast::IterationStatementKind::For {
+                for_keyword,
+                open_parenthesis,
+                initializer,
+                condition,
+                semicolon,
+                counter,
+                close_parenthesis,
+                body,
+            } => IterationStatementKind::For {
+                for_keyword: for_keyword.to_owned_ast(),
+                open_parenthesis: open_parenthesis.to_owned_ast(),
+                initializer: initializer.to_owned_ast(),
+                condition: condition.to_owned_ast(),
+                semicolon: semicolon.to_owned_ast(),
+                counter: counter.to_owned_ast(),
+                close_parenthesis: close_parenthesis.to_owned_ast(),
+                body: body.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::JumpStatement<'_> {
+    type Owned = JumpStatement;
+    fn to_owned_ast(&self) -> JumpStatement {
+        let ast::JumpStatement {
+            at,
+            kind,
+            semicolon,
+        } = self;
+        JumpStatement {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+            semicolon: semicolon.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::JumpStatementKind<'_> {
+    type Owned = JumpStatementKind;
+    fn to_owned_ast(&self) -> JumpStatementKind {
+        match self {
+            ast::JumpStatementKind::Goto {
+                goto_keyword,
+                target,
+            } => JumpStatementKind::Goto {
+                goto_keyword: goto_keyword.to_owned_ast(),
+                target: target.to_owned_ast(),
+            },
+            ast::JumpStatementKind::ComputedGoto {
+                goto_keyword,
+                asterisk,
+                target,
+            } => JumpStatementKind::ComputedGoto {
+                goto_keyword: goto_keyword.to_owned_ast(),
+                asterisk: asterisk.to_owned_ast(),
+                target: target.to_owned_ast(),
+            },
+            ast::JumpStatementKind::Continue { continue_keyword } => JumpStatementKind::Continue {
+                continue_keyword: continue_keyword.to_owned_ast(),
+            },
+            ast::JumpStatementKind::Break { break_keyword } => JumpStatementKind::Break {
+                break_keyword: break_keyword.to_owned_ast(),
+            },
+            ast::JumpStatementKind::Return {
+                return_keyword,
+                value,
+            } => JumpStatementKind::Return {
+                return_keyword: return_keyword.to_owned_ast(),
+                value: value.to_owned_ast(),
+            },
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ForInitializer<'_> {
+    type Owned = ForInitializer;
+    fn to_owned_ast(&self) -> ForInitializer {
+        match self {
+            ast::ForInitializer::Expression(first, second) => {
+                ForInitializer::Expression(first.to_owned_ast(), second.to_owned_ast())
+            }
+            ast::ForInitializer::Declaration(value) => {
+                ForInitializer::Declaration(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ExternalDeclaration<'_> {
+    type Owned = ExternalDeclaration;
+    fn to_owned_ast(&self) -> ExternalDeclaration {
+        let ast::ExternalDeclaration { at, kind } = self;
+        ExternalDeclaration {
+            at: at.to_owned_ast(),
+            kind: kind.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for ast::ExternalDeclarationKind<'_> {
+    type Owned = ExternalDeclarationKind;
+    fn to_owned_ast(&self) -> ExternalDeclarationKind {
+        match self {
+            ast::ExternalDeclarationKind::Function(value) => {
+                ExternalDeclarationKind::Function(value.to_owned_ast())
+            }
+            ast::ExternalDeclarationKind::Declaration(value) => {
+                ExternalDeclarationKind::Declaration(value.to_owned_ast())
+            }
+        }
+    }
+}
+
+impl ToOwnedAst for ast::FunctionDefinition<'_> {
+    type Owned = FunctionDefinition;
+    fn to_owned_ast(&self) -> FunctionDefinition {
+        let ast::FunctionDefinition {
+            at,
+            attributes,
+            specifiers,
+            declarator,
+            body,
+        } = self;
+        FunctionDefinition {
+            at: at.to_owned_ast(),
+            attributes: attributes.to_owned_ast(),
+            specifiers: specifiers.to_owned_ast(),
+            declarator: declarator.to_owned_ast(),
+            body: body.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for token::TokenKind<'_> {
+    type Owned = TokenKind;
+    fn to_owned_ast(&self) -> TokenKind {
+        match self {
+            token::TokenKind::Identifier(value) => TokenKind::Identifier(value.to_owned_ast()),
+            token::TokenKind::Integer(value) => TokenKind::Integer(value.to_owned_ast()),
+            token::TokenKind::Floating(value) => TokenKind::Floating(value.to_owned_ast()),
+            token::TokenKind::String(first, second) => {
+                TokenKind::String(first.to_owned_ast(), second.to_owned_ast())
+            }
+            token::TokenKind::Character(first, second) => {
+                TokenKind::Character(first.to_owned_ast(), second.to_owned_ast())
+            }
+            token::TokenKind::OpenBracket => TokenKind::OpenBracket,
+            token::TokenKind::CloseBracket => TokenKind::CloseBracket,
+            token::TokenKind::OpenParenthesis => TokenKind::OpenParenthesis,
+            token::TokenKind::CloseParenthesis => TokenKind::CloseParenthesis,
+            token::TokenKind::OpenBrace => TokenKind::OpenBrace,
+            token::TokenKind::CloseBrace => TokenKind::CloseBrace,
+            token::TokenKind::Period => TokenKind::Period,
+            token::TokenKind::ArrowLeft => TokenKind::ArrowLeft,
+            token::TokenKind::DoublePlus => TokenKind::DoublePlus,
+            token::TokenKind::DoubleMinus => TokenKind::DoubleMinus,
+            token::TokenKind::Ampersand => TokenKind::Ampersand,
+            token::TokenKind::Asterisk => TokenKind::Asterisk,
+            token::TokenKind::Plus => TokenKind::Plus,
+            token::TokenKind::Minus => TokenKind::Minus,
+            token::TokenKind::Tilde => TokenKind::Tilde,
+            token::TokenKind::Exclamation => TokenKind::Exclamation,
+            token::TokenKind::Slash => TokenKind::Slash,
+            token::TokenKind::Percent => TokenKind::Percent,
+            token::TokenKind::DoubleLess => TokenKind::DoubleLess,
+            token::TokenKind::DoubleGreater => TokenKind::DoubleGreater,
+            token::TokenKind::Less => TokenKind::Less,
+            token::TokenKind::Greater => TokenKind::Greater,
+            token::TokenKind::LessEqual => TokenKind::LessEqual,
+            token::TokenKind::GreaterEqual => TokenKind::GreaterEqual,
+            token::TokenKind::DoubleEqual => TokenKind::DoubleEqual,
+            token::TokenKind::NotEqual => TokenKind::NotEqual,
+            token::TokenKind::Caret => TokenKind::Caret,
+            token::TokenKind::Bar => TokenKind::Bar,
+            token::TokenKind::DoubleAmpersand => TokenKind::DoubleAmpersand,
+            token::TokenKind::DoubleBar => TokenKind::DoubleBar,
+            token::TokenKind::Question => TokenKind::Question,
+            token::TokenKind::Colon => TokenKind::Colon,
+            token::TokenKind::DoubleColon => TokenKind::DoubleColon,
+            token::TokenKind::Semicolon => TokenKind::Semicolon,
+            token::TokenKind::Ellipses => TokenKind::Ellipses,
+            token::TokenKind::Equal => TokenKind::Equal,
+            token::TokenKind::AsteriskEqual => TokenKind::AsteriskEqual,
+            token::TokenKind::SlashEqual => TokenKind::SlashEqual,
+            token::TokenKind::PercentEqual => TokenKind::PercentEqual,
+            token::TokenKind::PlusEqual => TokenKind::PlusEqual,
+            token::TokenKind::MinusEqual => TokenKind::MinusEqual,
+            token::TokenKind::DoubleLessEqual => TokenKind::DoubleLessEqual,
+            token::TokenKind::DoubleGreaterEqual => TokenKind::DoubleGreaterEqual,
+            token::TokenKind::AmpersandEqual => TokenKind::AmpersandEqual,
+            token::TokenKind::CaretEqual => TokenKind::CaretEqual,
+            token::TokenKind::BarEqual => TokenKind::BarEqual,
+            token::TokenKind::Comma => TokenKind::Comma,
+            token::TokenKind::Alignas => TokenKind::Alignas,
+            token::TokenKind::Alignof => TokenKind::Alignof,
+            token::TokenKind::Auto => TokenKind::Auto,
+            token::TokenKind::Bool => TokenKind::Bool,
+            token::TokenKind::Break => TokenKind::Break,
+            token::TokenKind::Case => TokenKind::Case,
+            token::TokenKind::Char => TokenKind::Char,
+            token::TokenKind::Const => TokenKind::Const,
+            token::TokenKind::Constexpr => TokenKind::Constexpr,
+            token::TokenKind::Continue => TokenKind::Continue,
+            token::TokenKind::Default => TokenKind::Default,
+            token::TokenKind::Do => TokenKind::Do,
+            token::TokenKind::Double => TokenKind::Double,
+            token::TokenKind::Else => TokenKind::Else,
+            token::TokenKind::Enum => TokenKind::Enum,
+            token::TokenKind::Extern => TokenKind::Extern,
+            token::TokenKind::False => TokenKind::False,
+            token::TokenKind::Float => TokenKind::Float,
+            token::TokenKind::For => TokenKind::For,
+            token::TokenKind::Goto => TokenKind::Goto,
+            token::TokenKind::If => TokenKind::If,
+            token::TokenKind::Inline => TokenKind::Inline,
+            token::TokenKind::Int => TokenKind::Int,
+            token::TokenKind::Long => TokenKind::Long,
+            token::TokenKind::Nullptr => TokenKind::Nullptr,
+            token::TokenKind::Register => TokenKind::Register,
+            token::TokenKind::Restrict => TokenKind::Restrict,
+            token::TokenKind::Return => TokenKind::Return,
+            token::TokenKind::Short => TokenKind::Short,
+            token::TokenKind::Signed => TokenKind::Signed,
+            token::TokenKind::Sizeof => TokenKind::Sizeof,
+            token::TokenKind::Static => TokenKind::Static,
+            token::TokenKind::StaticAssert => TokenKind::StaticAssert,
+            token::TokenKind::Struct => TokenKind::Struct,
+            token::TokenKind::Switch => TokenKind::Switch,
+            token::TokenKind::ThreadLocal => TokenKind::ThreadLocal,
+            token::TokenKind::True => TokenKind::True,
+            token::TokenKind::Typedef => TokenKind::Typedef,
+            token::TokenKind::Typeof => TokenKind::Typeof,
+            token::TokenKind::TypeofUnqual => TokenKind::TypeofUnqual,
+            token::TokenKind::Union => TokenKind::Union,
+            token::TokenKind::Unsigned => TokenKind::Unsigned,
+            token::TokenKind::Void => TokenKind::Void,
+            token::TokenKind::Volatile => TokenKind::Volatile,
+            token::TokenKind::While => TokenKind::While,
+            token::TokenKind::Atomic => TokenKind::Atomic,
+            token::TokenKind::BitInt => TokenKind::BitInt,
+            token::TokenKind::Complex => TokenKind::Complex,
+            token::TokenKind::Decimal128 => TokenKind::Decimal128,
+            token::TokenKind::Decimal32 => TokenKind::Decimal32,
+            token::TokenKind::Decimal64 => TokenKind::Decimal64,
+            token::TokenKind::Float16 => TokenKind::Float16,
+            token::TokenKind::Float32 => TokenKind::Float32,
+            token::TokenKind::Float64 => TokenKind::Float64,
+            token::TokenKind::Float128 => TokenKind::Float128,
+            token::TokenKind::Float32x => TokenKind::Float32x,
+            token::TokenKind::Float64x => TokenKind::Float64x,
+            token::TokenKind::Float128x => TokenKind::Float128x,
+            token::TokenKind::Int128 => TokenKind::Int128,
+            token::TokenKind::Generic => TokenKind::Generic,
+            token::TokenKind::Imaginary => TokenKind::Imaginary,
+            token::TokenKind::Noreturn => TokenKind::Noreturn,
+            token::TokenKind::Pragma => TokenKind::Pragma,
+            token::TokenKind::LineComment(value) => TokenKind::LineComment(value.to_owned_ast()),
+            token::TokenKind::BlockComment(value) => TokenKind::BlockComment(value.to_owned_ast()),
+            token::TokenKind::Eof => TokenKind::Eof,
+            token::TokenKind::Error => TokenKind::Error,
+        }
+    }
+}
+
+impl ToOwnedAst for token::IntegerToken<'_> {
+    type Owned = IntegerToken;
+    fn to_owned_ast(&self) -> IntegerToken {
+        let token::IntegerToken {
+            source,
+            format,
+            suffix,
+        } = self;
+        IntegerToken {
+            source: source.to_owned_ast(),
+            format: format.to_owned_ast(),
+            suffix: suffix.to_owned_ast(),
+        }
+    }
+}
+
+impl ToOwnedAst for token::FloatToken<'_> {
+    type Owned = FloatToken;
+    fn to_owned_ast(&self) -> FloatToken {
+        let token::FloatToken {
+            source,
+            format,
+            suffix,
+        } = self;
+        FloatToken {
+            source: source.to_owned_ast(),
+            format: format.to_owned_ast(),
+            suffix: suffix.to_owned_ast(),
+        }
+    }
+}
@@ -1,32 +1,166 @@
-use std::collections::HashSet;
+use std::{collections::HashMap, fmt};
 
 use super::ast::*;
 use crate::token::{At, Token, TokenKind};
 
+/// The language extensions accepted on top of standard C.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+    #[default]
+    Standard,
+    Gnu,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParserOptions {
+    pub dialect: Dialect,
+    /// Accept a comma after the last argument of a function call, as in
+    /// `f(a, b,)`, which generated code sometimes contains.
+    pub trailing_argument_comma: bool,
+}
+
 pub struct Parser<'a, 'b> {
     tokens: &'b [Token<'a>],
     index: usize,
     errors: Vec<ParseErr<'a>>,
-    scopes: Vec<HashSet<&'a str>>,
+    /// The identifiers declared in each open scope, innermost last, mapped to
+    /// whether they are typedef names. An ordinary identifier hides a typedef
+    /// name of an enclosing scope.
+    scopes: Vec<HashMap<&'a str, bool>>,
+    options: ParserOptions,
 }
 impl<'a, 'b> Parser<'a, 'b> {
+    /// Creates a parser over `tokens`, which need not end with the `Eof` token
+    /// the lexer appends; the end of the slice is treated as the end of input.
+    /// Comment tokens are skipped.
     pub fn new(tokens: &'b [Token<'a>]) -> Self {
-        Self {
+        Self::with_options(tokens, ParserOptions::default())
+    }
+    pub fn with_options(tokens: &'b [Token<'a>], options: ParserOptions) -> Self {
+        let mut parser = Self {
             tokens,
             index: 0,
             errors: Vec::new(),
-            scopes: Vec::new(),
+            scopes: vec![HashMap::new()],
+            options,
+        };
+        parser.skip_comments();
+        parser
+    }
+    /// Seeds the file scope with typedef names whose definitions are not part of the input,
+    /// such as `size_t` from a header that was not preprocessed.
+    pub fn with_predeclared_typedefs(mut self, names: &[&'a str]) -> Self {
+        self.scopes[0].extend(names.iter().map(|&name| (name, true)));
+        self
+    }
+
+    pub fn parse(mut self) -> ParseResult<'a> {
+        let ast = self.parse_translation_unit().ok();
+        ParseResult {
+            ast,
+            errors: self.errors,
         }
     }
+    /// Parses a translation unit like [`Parser::parse`], but passes each external
+    /// declaration to `callback` as soon as it is parsed instead of collecting them.
+    pub fn parse_streaming(
+        mut self,
+        mut callback: impl FnMut(ExternalDeclaration<'a>),
+    ) -> Vec<ParseErr<'a>> {
+        if self.check_not_empty().is_ok()
+            && let Ok(first) = self.parse_external_declaration()
+        {
+            callback(first);
+            while let Ok(declaration) = self.try_to(Self::parse_external_declaration) {
+                callback(declaration);
+            }
+            self.check_at_end();
+        }
+        self.errors
+    }
+    /// Starts parsing at token index `position` instead of at the first token,
+    /// such as a position returned by [`Parser::position`] of an earlier parser.
+    /// Typedef names declared before `position` must be passed to
+    /// [`Parser::with_predeclared_typedefs`].
+    pub fn starting_at(mut self, position: usize) -> Self {
+        self.index = position;
+        self.skip_comments();
+        self
+    }
+    /// Returns the index of the next token to be parsed.
+    pub fn position(&self) -> usize {
+        self.index
+    }
+    /// Returns the tokens that have not been parsed yet.
+    pub fn remaining(&self) -> &'b [Token<'a>] {
+        &self.tokens[self.index.min(self.tokens.len())..]
+    }
+    /// Parses a single external declaration, leaving the position after it.
+    /// On failure, the position is unchanged.
+    pub fn parse_next_declaration(&mut self) -> Result<ExternalDeclaration<'a>, Vec<ParseErr<'a>>> {
+        self.parse_next(Self::parse_external_declaration)
+    }
+    /// Parses a single expression, including comma expressions, like [`Parser::parse_next_declaration`].
+    pub fn parse_next_expression(&mut self) -> Result<Expression<'a>, Vec<ParseErr<'a>>> {
+        self.parse_next(Self::parse_expression)
+    }
+    fn parse_next<T>(&mut self, parse: fn(&mut Self) -> Res<T>) -> Result<T, Vec<ParseErr<'a>>> {
+        let index = self.index;
+        let err_length = self.errors.len();
+        let scopes_length = self.scopes.len();
+        self.scopes.push(HashMap::new());
 
-    pub fn parse(mut self) -> (Result<TranslationUnit<'a>, ()>, Vec<ParseErr<'a>>) {
-        let ast = self.parse_translation_unit();
-        (ast, self.errors)
+        let parsed = parse(self);
+        let top = self.scopes.drain(scopes_length..).next().unwrap();
+        match parsed {
+            Ok(parsed) => {
+                self.scopes.last_mut().unwrap().extend(top);
+                Ok(parsed)
+            }
+            Err(()) => {
+                self.index = index;
+                Err(self.errors.drain(err_length..).collect())
+            }
+        }
     }
 
     fn parse_primary_expression(&mut self) -> Res<Expression<'a>> {
         let at = self.at();
         let kind = match self.kind() {
+            TokenKind::Identifier("__builtin_offsetof") if self.options.dialect == Dialect::Gnu => {
+                let builtin = self.next();
+                let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+                let type_name = Box::new(self.parse_type_name()?);
+                let comma = self.take(TokenKind::Comma)?;
+                let member = self.parse_member_designator()?;
+                let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+                ExpressionKind::Offsetof {
+                    builtin,
+                    open_parenthesis,
+                    type_name,
+                    comma,
+                    member,
+                    close_parenthesis,
+                }
+            }
+            TokenKind::Identifier("__builtin_types_compatible_p")
+                if self.options.dialect == Dialect::Gnu =>
+            {
+                let builtin = self.next();
+                let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+                let left = Box::new(self.parse_type_name()?);
+                let comma = self.take(TokenKind::Comma)?;
+                let right = Box::new(self.parse_type_name()?);
+                let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+                ExpressionKind::TypesCompatible {
+                    builtin,
+                    open_parenthesis,
+                    left,
+                    comma,
+                    right,
+                    close_parenthesis,
+                }
+            }
             TokenKind::Identifier(name) => {
                 self.next();
                 ExpressionKind::Identifier(name)
@@ -35,6 +169,10 @@ impl<'a, 'b> Parser<'a, 'b> {
                 self.next();
                 ExpressionKind::Integer(int)
             }
+            TokenKind::Floating(float) => {
+                self.next();
+                ExpressionKind::Float(float)
+            }
             TokenKind::String(literal, encoding) => {
                 self.next();
                 ExpressionKind::String(StringLiteral {
@@ -43,6 +181,35 @@ impl<'a, 'b> Parser<'a, 'b> {
                     encoding,
                 })
             }
+            TokenKind::Character(literal, encoding) => {
+                self.next();
+                ExpressionKind::Character(CharLiteral {
+                    at,
+                    literal,
+                    encoding,
+                })
+            }
+            kind @ (TokenKind::True | TokenKind::False) => {
+                self.next();
+                ExpressionKind::Bool(kind == TokenKind::True)
+            }
+            TokenKind::Nullptr => {
+                self.next();
+                ExpressionKind::Nullptr
+            }
+            TokenKind::OpenParenthesis
+                if self.options.dialect == Dialect::Gnu
+                    && self.peek_kind(1) == TokenKind::OpenBrace =>
+            {
+                let open_parenthesis = self.next();
+                let body = self.parse_compound_statement()?;
+                let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+                ExpressionKind::StatementExpression {
+                    open_parenthesis,
+                    body,
+                    close_parenthesis,
+                }
+            }
             TokenKind::OpenParenthesis => {
                 let open_parenthesis = self.next();
                 let inner = Box::new(self.parse_expression()?);
@@ -54,6 +221,10 @@ impl<'a, 'b> Parser<'a, 'b> {
                 }
             }
             TokenKind::Generic => ExpressionKind::GenericSelection(self.parse_generic_selection()?),
+            TokenKind::Ellipses => {
+                self.err_unrecoverable(self.cur(), Expected::EllipsisAfterParameters);
+                return Err(());
+            }
             _ => {
                 self.err(Expected::PrimaryExpression);
                 return Err(());
@@ -61,6 +232,16 @@ impl<'a, 'b> Parser<'a, 'b> {
         };
         Ok(Expression { at, kind })
     }
+    fn parse_member_designator(&mut self) -> Res<MemberDesignator<'a>> {
+        let at = self.at();
+        let name = self.take_identifier()?;
+        let designators = self.maybe(Self::parse_designator_list);
+        Ok(MemberDesignator {
+            at,
+            name,
+            designators,
+        })
+    }
     fn parse_generic_selection(&mut self) -> Res<GenericSelection<'a>> {
         let at = self.at();
         let generic_keyword = self.take(TokenKind::Generic)?;
@@ -123,11 +304,18 @@ impl<'a, 'b> Parser<'a, 'b> {
                 TokenKind::OpenParenthesis => {
                     let open_parenthesis = self.next();
                     let arguments = self.maybe(Self::parse_argument_expression_list);
+                    let trailing_comma =
+                        if arguments.is_some() && self.options.trailing_argument_comma {
+                            self.maybe(|p| p.take(TokenKind::Comma))
+                        } else {
+                            None
+                        };
                     let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
                     ExpressionKind::Call {
                         left: Box::new(left),
                         open_parenthesis,
                         arguments,
+                        trailing_comma,
                         close_parenthesis,
                     }
                 }
@@ -171,6 +359,9 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(left)
     }
     fn parse_postfix_expression_leaf(&mut self) -> Res<Expression<'a>> {
+        if !self.is(TokenKind::OpenParenthesis) || !self.starts_compound_literal_type(1) {
+            return self.parse_primary_expression();
+        }
         self.one_of(
             [
                 &mut Self::parse_compound_literal_expression,
@@ -181,7 +372,11 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
 
     fn parse_argument_expression_list(&mut self) -> Res<ArgumentExpressionList<'a>> {
-        self.comma_list(Self::parse_assignment_expression)
+        if self.options.trailing_argument_comma {
+            self.comma_list_items(Self::parse_assignment_expression)
+        } else {
+            self.comma_list(Self::parse_assignment_expression)
+        }
     }
 
     fn parse_compound_literal_expression(&mut self) -> Res<Expression<'a>> {
@@ -252,19 +447,34 @@ impl<'a, 'b> Parser<'a, 'b> {
                 let right = self.parse_cast_expression()?;
                 ExpressionKind::Unary(operator, Box::new(right))
             }
+            TokenKind::DoubleAmpersand if self.options.dialect == Dialect::Gnu => {
+                self.next();
+                ExpressionKind::LabelAddress(self.take_identifier()?)
+            }
             TokenKind::Sizeof => {
                 let sizeof_keyword = self.next();
-                let kind = if let Ok(expr) = self.try_to(Self::parse_unary_expression) {
-                    SizeofKind::Expression(Box::new(expr))
+                let type_name = if self.is(TokenKind::OpenParenthesis) && self.starts_type_name(1) {
+                    // A braced initializer after the type name makes it a compound literal.
+                    self.try_to(|p| {
+                        let open_parenthesis = p.take(TokenKind::OpenParenthesis)?;
+                        let type_name = p.parse_type_name()?;
+                        let close_parenthesis = p.take(TokenKind::CloseParenthesis)?;
+                        if p.is(TokenKind::OpenBrace) {
+                            return Err(());
+                        }
+                        Ok(SizeofKind::Type {
+                            open_parenthesis,
+                            type_name,
+                            close_parenthesis,
+                        })
+                    })
+                    .ok()
                 } else {
-                    let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
-                    let type_name = self.parse_type_name()?;
-                    let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
-                    SizeofKind::Type {
-                        open_parenthesis,
-                        type_name,
-                        close_parenthesis,
-                    }
+                    None
+                };
+                let kind = match type_name {
+                    Some(kind) => kind,
+                    None => SizeofKind::Expression(Box::new(self.parse_unary_expression()?)),
                 };
 
                 ExpressionKind::Sizeof {
@@ -274,9 +484,17 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
             TokenKind::Alignof => {
                 let alignof_keyword = self.next();
-                let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
-                let type_name = self.parse_type_name()?;
-                let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+                // Unlike `sizeof`, `alignof` has no expression form.
+                let operand = self.cur();
+                let Ok((open_parenthesis, type_name, close_parenthesis)) = self.try_to(|p| {
+                    let open_parenthesis = p.take(TokenKind::OpenParenthesis)?;
+                    let type_name = p.parse_type_name()?;
+                    let close_parenthesis = p.take(TokenKind::CloseParenthesis)?;
+                    Ok((open_parenthesis, type_name, close_parenthesis))
+                }) else {
+                    self.err_unrecoverable(operand, Expected::AlignofOperand);
+                    return Err(());
+                };
                 ExpressionKind::Alignof {
                     alignof_keyword,
                     open_parenthesis,
@@ -290,7 +508,9 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(Expression { at, kind })
     }
     fn parse_cast_expression(&mut self) -> Res<Expression<'a>> {
-        if let Ok(e) = self.try_to(Self::parse_cast_expression_prime) {
+        if !self.is(TokenKind::OpenParenthesis) || !self.starts_type_name(1) {
+            self.parse_unary_expression()
+        } else if let Ok(e) = self.try_to(Self::parse_cast_expression_prime) {
             Ok(e)
         } else {
             self.parse_unary_expression()
@@ -401,6 +621,7 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         let condition = Box::new(left);
         let question = self.next();
+        // The middle operand may contain a comma, but the last one may not.
         let then_value = Box::new(self.parse_expression()?);
         let colon = self.take(TokenKind::Colon)?;
         let else_value = Box::new(self.parse_conditional_expression()?);
@@ -484,12 +705,14 @@ impl<'a, 'b> Parser<'a, 'b> {
         let mut is_typedef = false;
 
         let at = self.at();
-        let kind = if let Ok(assert) = self.try_to(Self::parse_static_assert_declaration) {
+        let kind = if self.is(TokenKind::Pragma) {
+            DeclarationKind::Pragma(self.parse_pragma_operator()?)
+        } else if let Ok(assert) = self.try_to(Self::parse_static_assert_declaration) {
             DeclarationKind::Assert(assert)
         } else if let Ok(attribute) = self.try_to(Self::parse_attribute_declaration) {
             DeclarationKind::Attribute(attribute)
         } else if let Ok(attribute) = self.try_to(Self::parse_attribute_specifier_sequence) {
-            let specifiers = self.parse_declaration_specifiers(&mut is_typedef)?;
+            let specifiers = self.parse_declaration_specifiers(&mut is_typedef, false)?;
             let init_declarators = self.parse_init_declarator_list(is_typedef)?;
             let semicolon = self.take(TokenKind::Semicolon)?;
 
@@ -500,7 +723,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 semicolon,
             }
         } else {
-            let specifiers = self.parse_declaration_specifiers(&mut is_typedef)?;
+            let specifiers = self.parse_declaration_specifiers(&mut is_typedef, false)?;
             let init_declarators = self.maybe(|p| Self::parse_init_declarator_list(p, is_typedef));
             let semicolon = self.take(TokenKind::Semicolon)?;
             DeclarationKind::Normal {
@@ -513,19 +736,38 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         Ok(Declaration { at, kind })
     }
+    /// A typedef name is only a type specifier if no other type specifier precedes it,
+    /// so that in `int T;` the identifier `T` is declared even if it names a type.
     fn parse_declaration_specifiers(
         &mut self,
         is_typedef: &mut bool,
+        has_type_specifier: bool,
     ) -> Res<DeclarationSpecifiers<'a>> {
         let at = self.at();
         let specifier = self.parse_declaration_specifier(is_typedef)?;
-        let kind =
-            if let Ok(cons) = self.try_to(|p| Self::parse_declaration_specifiers(p, is_typedef)) {
-                DeclarationSpecifiersKind::Cons(Box::new(cons))
-            } else {
-                let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
-                DeclarationSpecifiersKind::Leaf(attributes)
-            };
+        let has_type_specifier = has_type_specifier
+            || matches!(
+                specifier.kind,
+                DeclarationSpecifierKind::Type(TypeSpecifierQualifier {
+                    kind: TypeSpecifierQualifierKind::TypeSpecifier(_),
+                    ..
+                })
+            );
+        let ends = has_type_specifier && matches!(self.kind(), TokenKind::Identifier(_));
+        let kind = if let Some(cons) = (!ends)
+            .then(|| {
+                self.try_to(|p| {
+                    Self::parse_declaration_specifiers(p, is_typedef, has_type_specifier)
+                })
+                .ok()
+            })
+            .flatten()
+        {
+            DeclarationSpecifiersKind::Cons(Box::new(cons))
+        } else {
+            let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
+            DeclarationSpecifiersKind::Leaf(attributes)
+        };
 
         Ok(DeclarationSpecifiers {
             at,
@@ -551,7 +793,13 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
     fn parse_init_declarator(&mut self, is_typedef: bool) -> Res<InitDeclarator<'a>> {
         let at = self.at();
-        let declarator = self.parse_declarator(is_typedef)?;
+        let mut declarator = self.parse_declarator(is_typedef)?;
+        if !is_typedef {
+            self.declare(declarator.name().0, false);
+        }
+        if self.is_asm_keyword() {
+            declarator.asm_label = Some(self.parse_asm_label()?);
+        }
         let initializer = if self.is(TokenKind::Equal) {
             let equal = self.next();
             let initializer = self.parse_initializer()?;
@@ -660,6 +908,38 @@ impl<'a, 'b> Parser<'a, 'b> {
                 self.next();
                 TypeSpecifierKind::Decimal128
             }
+            TokenKind::Float16 => {
+                self.next();
+                TypeSpecifierKind::Float16
+            }
+            TokenKind::Float32 => {
+                self.next();
+                TypeSpecifierKind::Float32
+            }
+            TokenKind::Float64 => {
+                self.next();
+                TypeSpecifierKind::Float64
+            }
+            TokenKind::Float128 => {
+                self.next();
+                TypeSpecifierKind::Float128
+            }
+            TokenKind::Float32x => {
+                self.next();
+                TypeSpecifierKind::Float32x
+            }
+            TokenKind::Float64x => {
+                self.next();
+                TypeSpecifierKind::Float64x
+            }
+            TokenKind::Float128x => {
+                self.next();
+                TypeSpecifierKind::Float128x
+            }
+            TokenKind::Int128 => {
+                self.next();
+                TypeSpecifierKind::Int128
+            }
             TokenKind::Identifier(name) => {
                 if !self.is_typedef_name(name) {
                     self.err(Expected::TypeSpecifier);
@@ -739,7 +1019,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         } else {
             let at = self.at();
             let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
-            let specifier_qualifiers = self.parse_specifier_qualifier_list()?;
+            let specifier_qualifiers = self.parse_specifier_qualifier_list(false)?;
             let member_declarators = self.maybe(Self::parse_member_declarator_list);
             let semicolon = self.take(TokenKind::Semicolon)?;
             Ok(MemberDeclaration {
@@ -753,10 +1033,26 @@ impl<'a, 'b> Parser<'a, 'b> {
             })
         }
     }
-    fn parse_specifier_qualifier_list(&mut self) -> Res<SpecifierQualifierList<'a>> {
+    /// See `parse_declaration_specifiers` for the treatment of typedef names.
+    fn parse_specifier_qualifier_list(
+        &mut self,
+        has_type_specifier: bool,
+    ) -> Res<SpecifierQualifierList<'a>> {
         let at = self.at();
         let specifier_qualifier = Box::new(self.parse_type_specifier_qualifier()?);
-        let kind = if let Ok(cons) = self.try_to(Self::parse_specifier_qualifier_list) {
+        let has_type_specifier = has_type_specifier
+            || matches!(
+                specifier_qualifier.kind,
+                TypeSpecifierQualifierKind::TypeSpecifier(_)
+            );
+        let ends = has_type_specifier && matches!(self.kind(), TokenKind::Identifier(_));
+        let kind = if let Some(cons) = (!ends)
+            .then(|| {
+                self.try_to(|p| Self::parse_specifier_qualifier_list(p, has_type_specifier))
+                    .ok()
+            })
+            .flatten()
+        {
             SpecifierQualifierListKind::Cons(Box::new(cons))
         } else {
             let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
@@ -844,6 +1140,7 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_enumerator(&mut self) -> Res<Enumerator<'a>> {
         let at = self.at();
         let name = self.take_identifier()?;
+        self.declare(name, false);
         let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
         let value = if self.is(TokenKind::Equal) {
             let equal = self.next();
@@ -863,7 +1160,7 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_enum_type_specifier(&mut self) -> Res<EnumTypeSpecifier<'a>> {
         let at = self.at();
         let colon = self.take(TokenKind::Colon)?;
-        let specifier_qualifiers = self.parse_specifier_qualifier_list()?;
+        let specifier_qualifiers = self.parse_specifier_qualifier_list(false)?;
 
         Ok(EnumTypeSpecifier {
             at,
@@ -935,6 +1232,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 return Err(());
             }
         };
+        self.next();
 
         Ok(TypeQualifier { at, kind })
     }
@@ -948,6 +1246,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 return Err(());
             }
         };
+        self.next();
 
         Ok(FunctionSpecifier { at, kind })
     }
@@ -985,6 +1284,29 @@ impl<'a, 'b> Parser<'a, 'b> {
             at,
             pointer,
             direct,
+            asm_label: None,
+        })
+    }
+    fn parse_asm_label(&mut self) -> Res<AsmLabel<'a>> {
+        let at = self.at();
+        if !self.is_asm_keyword() {
+            self.err(Expected::Token(TokenKind::Identifier("asm")));
+            return Err(());
+        }
+        let asm_keyword = self.next();
+        let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+        if !matches!(self.kind(), TokenKind::String(..)) {
+            self.err_unrecoverable(self.cur(), Expected::AsmLabelName);
+            return Err(());
+        }
+        let name = self.list(Self::parse_string_literal)?;
+        let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+        Ok(AsmLabel {
+            at,
+            asm_keyword,
+            open_parenthesis,
+            name,
+            close_parenthesis,
         })
     }
     fn parse_direct_declarator(&mut self, is_typedef: bool) -> Res<DirectDeclarator<'a>> {
@@ -1018,7 +1340,7 @@ impl<'a, 'b> Parser<'a, 'b> {
             let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
 
             if is_typedef {
-                self.scopes.last_mut().unwrap().insert(name);
+                self.declare(name, true);
             }
 
             Ok(DirectDeclarator {
@@ -1105,7 +1427,11 @@ impl<'a, 'b> Parser<'a, 'b> {
         let at = self.at();
         let left = Box::new(left);
         let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+        // The parameters have prototype scope, so their names hide typedef
+        // names only up to the closing parenthesis.
+        self.scopes.push(HashMap::new());
         let parameters = self.maybe(Self::parse_parameter_type_list);
+        self.scopes.pop();
         let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
         let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
 
@@ -1144,16 +1470,29 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_parameter_type_list(&mut self) -> Res<ParameterTypeList<'a>> {
         let at = self.at();
         let parameters = self.maybe(Self::parse_parameter_list);
-        let final_comma = if parameters.is_some() && self.is(TokenKind::Comma) {
-            Some(self.next())
-        } else {
-            None
-        };
-        let ellipses = if parameters.is_none() || final_comma.is_some() {
-            self.maybe(|p| p.take(TokenKind::Ellipses))
+        let (final_comma, ellipses) = if parameters.is_some() {
+            let variadic = self.try_to(|p| {
+                let comma = p.take(TokenKind::Comma)?;
+                let ellipses = p.take(TokenKind::Ellipses)?;
+                Ok((comma, ellipses))
+            });
+            match variadic {
+                Ok((comma, ellipses)) => (Some(comma), Some(ellipses)),
+                Err(()) => (None, None),
+            }
         } else {
-            None
+            (None, self.maybe(|p| p.take(TokenKind::Ellipses)))
         };
+        if let Some(at) = ellipses
+            && self.is(TokenKind::Comma)
+        {
+            let ellipses = Token {
+                at,
+                kind: TokenKind::Ellipses,
+                end: at.offset + "...".len(),
+            };
+            self.err_unrecoverable(ellipses, Expected::EllipsisAfterParameters);
+        }
 
         let parameters = parameters.map(|p| (p, final_comma));
 
@@ -1169,13 +1508,16 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_parameter_declaration(&mut self) -> Res<ParameterDeclaration<'a>> {
         let at = self.at();
         let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
-        let specifiers = self.parse_declaration_specifiers(&mut false)?;
+        let specifiers = self.parse_declaration_specifiers(&mut false, false)?;
         let kind = self.one_of(
             [
                 &mut |p| {
-                    Ok(ParameterDeclarationKind::Concrete(
-                        p.parse_declarator(false)?,
-                    ))
+                    let mut declarator = p.parse_declarator(false)?;
+                    p.declare(declarator.name().0, false);
+                    if p.is_asm_keyword() {
+                        declarator.asm_label = Some(p.parse_asm_label()?);
+                    }
+                    Ok(ParameterDeclarationKind::Concrete(declarator))
                 },
                 &mut |p| {
                     Ok(ParameterDeclarationKind::Abstract(
@@ -1195,7 +1537,7 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
     fn parse_type_name(&mut self) -> Res<TypeName<'a>> {
         let at = self.at();
-        let specifier_qualifiers = self.parse_specifier_qualifier_list()?;
+        let specifier_qualifiers = self.parse_specifier_qualifier_list(false)?;
         let declarator = self.maybe(Self::parse_abstract_declarator);
 
         Ok(TypeName {
@@ -1423,16 +1765,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         let condition = self.parse_constant_expression()?;
         let message = if self.is(TokenKind::Comma) {
             let comma = self.next();
-            let string_at = self.at();
-            let TokenKind::String(literal, encoding) = self.kind() else {
-                self.err(Expected::StringLiteral);
-                return Err(());
-            };
-            let string_literal = StringLiteral {
-                at: string_at,
-                literal,
-                encoding,
-            };
+            let string_literal = self.parse_string_literal()?;
             Some((comma, string_literal))
         } else {
             None
@@ -1450,6 +1783,20 @@ impl<'a, 'b> Parser<'a, 'b> {
             semicolon,
         })
     }
+    fn parse_pragma_operator(&mut self) -> Res<PragmaOperator<'a>> {
+        let at = self.at();
+        let pragma_keyword = self.take(TokenKind::Pragma)?;
+        let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+        let directive = self.parse_string_literal()?;
+        let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+        Ok(PragmaOperator {
+            at,
+            pragma_keyword,
+            open_parenthesis,
+            directive,
+            close_parenthesis,
+        })
+    }
     fn parse_attribute_specifier_sequence(&mut self) -> Res<AttributeSpecifierSequence<'a>> {
         let left = self.parse_attribute_specifier()?;
         let mut left = AttributeSpecifierSequence {
@@ -1469,10 +1816,107 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(left)
     }
     fn parse_attribute_specifier(&mut self) -> Res<AttributeSpecifier<'a>> {
-        let _at = self.at();
-        let _open_bracket_0 = self.take(TokenKind::OpenBracket)?;
-        let _open_bracket_1 = self.take(TokenKind::OpenBracket)?;
-        todo!();
+        let at = self.at();
+        let open_bracket_0 = self.take(TokenKind::OpenBracket)?;
+        let open_bracket_1 = self.take(TokenKind::OpenBracket)?;
+        let attributes = self.comma_list(|p| Ok(p.maybe(Self::parse_attribute)))?;
+        let close_bracket_0 = self.take(TokenKind::CloseBracket)?;
+        let close_bracket_1 = self.take(TokenKind::CloseBracket)?;
+
+        Ok(AttributeSpecifier {
+            at,
+            open_bracket_0,
+            open_bracket_1,
+            attributes,
+            close_bracket_0,
+            close_bracket_1,
+        })
+    }
+    fn parse_attribute(&mut self) -> Res<Attribute<'a>> {
+        let at = self.at();
+        let token = self.parse_attribute_token()?;
+        let argument_clause = self.maybe(Self::parse_attribute_argument_clause);
+        Ok(Attribute {
+            at,
+            token,
+            argument_clause,
+        })
+    }
+    fn parse_attribute_token(&mut self) -> Res<AttributeToken<'a>> {
+        let at = self.at();
+        let first = self.take_identifier()?;
+        let (prefix, token) = if self.is(TokenKind::DoubleColon) {
+            let double_colon = self.next();
+            let token = self.take_identifier()?;
+            (Some((first, double_colon)), token)
+        } else {
+            (None, first)
+        };
+
+        Ok(AttributeToken { at, prefix, token })
+    }
+    fn parse_attribute_argument_clause(&mut self) -> Res<AttributeArgumentClause<'a>> {
+        let at = self.at();
+        let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
+        let tokens = self.maybe(Self::parse_balanced_token_sequence);
+        let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+        Ok(AttributeArgumentClause {
+            at,
+            open_parenthesis,
+            tokens,
+            close_parenthesis,
+        })
+    }
+    fn parse_balanced_token_sequence(&mut self) -> Res<BalancedTokenSequence<'a>> {
+        self.list(Self::parse_balanced_token)
+    }
+    fn parse_balanced_token(&mut self) -> Res<BalancedToken<'a>> {
+        let at = self.at();
+        let kind = match self.kind() {
+            TokenKind::OpenParenthesis => {
+                let open_parenthesis = self.next();
+                let inner = self.maybe(Self::parse_balanced_token_sequence);
+                let close_parenthesis = self.take(TokenKind::CloseParenthesis)?;
+                BalancedTokenKind::Parenthesized {
+                    open_parenthesis,
+                    inner,
+                    close_parenthesis,
+                }
+            }
+            TokenKind::OpenBracket => {
+                let open_bracket = self.next();
+                let inner = self.maybe(Self::parse_balanced_token_sequence);
+                let close_bracket = self.take(TokenKind::CloseBracket)?;
+                BalancedTokenKind::Bracketed {
+                    open_bracket,
+                    inner,
+                    close_bracket,
+                }
+            }
+            TokenKind::OpenBrace => {
+                let open_brace = self.next();
+                let inner = self.maybe(Self::parse_balanced_token_sequence);
+                let close_brace = self.take(TokenKind::CloseBrace)?;
+                BalancedTokenKind::Braced {
+                    open_brace,
+                    inner,
+                    close_brace,
+                }
+            }
+            TokenKind::CloseParenthesis
+            | TokenKind::CloseBracket
+            | TokenKind::CloseBrace
+            | TokenKind::Eof => {
+                self.err(Expected::BalancedToken);
+                return Err(());
+            }
+            kind => {
+                self.next();
+                BalancedTokenKind::Token(kind)
+            }
+        };
+
+        Ok(BalancedToken { at, kind })
     }
 
     fn parse_statement(&mut self) -> Res<Statement<'a>> {
@@ -1537,11 +1981,23 @@ impl<'a, 'b> Parser<'a, 'b> {
         } else if self.is(TokenKind::Case) {
             let case_keyword = self.next();
             let value = self.parse_constant_expression()?;
-            LabelKind::Case {
-                case_keyword,
-                value,
+            if self.options.dialect == Dialect::Gnu && self.is(TokenKind::Ellipses) {
+                let ellipses = self.next();
+                let high = self.parse_constant_expression()?;
+                LabelKind::CaseRange {
+                    case_keyword,
+                    low: value,
+                    ellipses,
+                    high,
+                }
+            } else {
+                LabelKind::Case {
+                    case_keyword,
+                    value,
+                }
             }
         } else {
+            // Labels have their own name space, so typedef names are ordinary labels here.
             let name = self.take_identifier()?;
             LabelKind::Name(name)
         };
@@ -1565,10 +2021,15 @@ impl<'a, 'b> Parser<'a, 'b> {
         })
     }
     fn parse_compound_statement(&mut self) -> Res<CompoundStatement<'a>> {
+        self.parse_block(HashMap::new())
+    }
+    /// Parses a compound statement whose scope starts out with the identifiers in
+    /// `scope`, such as the parameters of a function whose body it is.
+    fn parse_block(&mut self, scope: HashMap<&'a str, bool>) -> Res<CompoundStatement<'a>> {
         let at = self.at();
         let open_brace = self.take(TokenKind::OpenBrace)?;
 
-        self.scopes.push(HashSet::new());
+        self.scopes.push(scope);
         let items = self.maybe(Self::parse_block_item_list);
         self.scopes.pop();
 
@@ -1586,6 +2047,8 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
     fn parse_block_item(&mut self) -> Res<BlockItem<'a>> {
         let at = self.at();
+        // A leading attribute sequence only forms a declaration if a declaration
+        // (or a lone `;`) follows it; otherwise it appertains to the statement or label.
         let kind = self.one_of(
             [
                 &mut |p| Ok(BlockItemKind::Declaration(p.parse_declaration()?)),
@@ -1600,12 +2063,14 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_expression_statement(&mut self) -> Res<ExpressionStatement<'a>> {
         let at = self.at();
         if let Ok(attributes) = self.try_to(Self::parse_attribute_specifier_sequence) {
-            let expression = self.parse_expression()?;
+            // `[[fallthrough]];` in statement position is an attributed null statement.
+            // Among block items, it is parsed as an attribute declaration instead.
+            let expression = self.maybe(Self::parse_expression);
             let semicolon = self.take(TokenKind::Semicolon)?;
             Ok(ExpressionStatement {
                 at,
                 attributes: Some(attributes),
-                expression: Some(expression),
+                expression,
                 semicolon,
             })
         } else {
@@ -1696,10 +2161,13 @@ impl<'a, 'b> Parser<'a, 'b> {
                 semicolon,
             }
         } else if self.is(TokenKind::For) {
-            self.scopes.push(HashSet::new());
+            self.scopes.push(HashMap::new());
             let for_keyword = self.next();
             let open_parenthesis = self.take(TokenKind::OpenParenthesis)?;
             let initializer = self.parse_for_initializer()?;
+            // All three clauses are full expressions in the C grammar,
+            // so `for (i = 0, j = 0; i < n, j < n; i++, j++)` is valid and the
+            // comma operator is permitted in the condition as well.
             let condition = self.maybe(Self::parse_expression);
             let semicolon = self.take(TokenKind::Semicolon)?;
             let counter = self.maybe(Self::parse_expression);
@@ -1736,10 +2204,20 @@ impl<'a, 'b> Parser<'a, 'b> {
         let at = self.at();
         let kind = if self.is(TokenKind::Goto) {
             let goto_keyword = self.next();
-            let target = self.take_identifier()?;
-            JumpStatementKind::Goto {
-                goto_keyword,
-                target,
+            if self.options.dialect == Dialect::Gnu && self.is(TokenKind::Asterisk) {
+                let asterisk = self.next();
+                let target = self.parse_expression()?;
+                JumpStatementKind::ComputedGoto {
+                    goto_keyword,
+                    asterisk,
+                    target,
+                }
+            } else {
+                let target = self.take_identifier()?;
+                JumpStatementKind::Goto {
+                    goto_keyword,
+                    target,
+                }
             }
         } else if self.is(TokenKind::Continue) {
             let continue_keyword = self.next();
@@ -1768,10 +2246,26 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
 
     fn parse_translation_unit(&mut self) -> Res<TranslationUnit<'a>> {
-        self.scopes.push(HashSet::new());
-        let out = self.list(Self::parse_external_declaration);
-        self.scopes.pop();
-        out
+        self.check_not_empty()?;
+        let unit = self.list(Self::parse_external_declaration)?;
+        self.check_at_end();
+        Ok(unit)
+    }
+    /// Reports input without any tokens besides comments, which cannot form a
+    /// translation unit, more clearly than a missing declaration.
+    fn check_not_empty(&mut self) -> Res<()> {
+        if self.is(TokenKind::Eof) {
+            self.err(Expected::NonEmptyTranslationUnit);
+            return Err(());
+        }
+        Ok(())
+    }
+    /// Reports the external declaration a list of them stopped at, if any,
+    /// by parsing it once more outside of `try_to`.
+    fn check_at_end(&mut self) {
+        if !self.is(TokenKind::Eof) {
+            let _ = self.parse_external_declaration();
+        }
     }
     fn parse_external_declaration(&mut self) -> Res<ExternalDeclaration<'a>> {
         let at = self.at();
@@ -1791,9 +2285,22 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn parse_function_definition(&mut self) -> Res<FunctionDefinition<'a>> {
         let at = self.at();
         let attributes = self.maybe(Self::parse_attribute_specifier_sequence);
-        let specifiers = self.parse_declaration_specifiers(&mut false)?;
+        let specifiers = self.parse_declaration_specifiers(&mut false, false)?;
         let declarator = self.parse_declarator(false)?;
-        let body = self.parse_compound_statement()?;
+        self.declare(declarator.name().0, false);
+
+        // The parameters are declared in the outermost block of the body,
+        // hiding typedef names of the same spelling.
+        let mut parameters = HashMap::new();
+        let list = declarator.function().and_then(|f| f.parameters.as_ref());
+        for (parameter, _) in list.and_then(|l| l.parameters.as_ref()).into_iter() {
+            for parameter in parameter.iter() {
+                if let ParameterDeclarationKind::Concrete(declarator) = &parameter.kind {
+                    parameters.insert(declarator.name().0, false);
+                }
+            }
+        }
+        let body = self.parse_block(parameters)?;
 
         Ok(FunctionDefinition {
             at,
@@ -1804,15 +2311,85 @@ impl<'a, 'b> Parser<'a, 'b> {
         })
     }
 
-    fn is_typedef_name(&self, name: &str) -> bool {
+    /// Returns whether the token `offset` ahead can begin a type name,
+    /// which lets `(x)` be parsed as an expression without attempting a cast first.
+    fn starts_type_name(&self, offset: usize) -> bool {
+        match self.peek_kind(offset) {
+            TokenKind::Identifier(name) => self.is_typedef_name(name),
+            TokenKind::Void
+            | TokenKind::Int128
+            | TokenKind::Char
+            | TokenKind::Short
+            | TokenKind::Int
+            | TokenKind::Long
+            | TokenKind::Float
+            | TokenKind::Double
+            | TokenKind::Signed
+            | TokenKind::Unsigned
+            | TokenKind::BitInt
+            | TokenKind::Bool
+            | TokenKind::Complex
+            | TokenKind::Decimal32
+            | TokenKind::Decimal64
+            | TokenKind::Decimal128
+            | TokenKind::Float16
+            | TokenKind::Float32
+            | TokenKind::Float64
+            | TokenKind::Float128
+            | TokenKind::Float32x
+            | TokenKind::Float64x
+            | TokenKind::Float128x
+            | TokenKind::Atomic
+            | TokenKind::Struct
+            | TokenKind::Union
+            | TokenKind::Enum
+            | TokenKind::Typeof
+            | TokenKind::TypeofUnqual
+            | TokenKind::Const
+            | TokenKind::Restrict
+            | TokenKind::Volatile
+            | TokenKind::Alignas => true,
+            _ => false,
+        }
+    }
+    /// Returns whether the token `offset` ahead can begin the storage-class
+    /// specifiers or type name of a compound literal. Any storage-class specifier
+    /// is accepted here; those a compound literal may not have are left to validation.
+    fn starts_compound_literal_type(&self, offset: usize) -> bool {
+        self.starts_type_name(offset)
+            || matches!(
+                self.peek_kind(offset),
+                TokenKind::Auto
+                    | TokenKind::Constexpr
+                    | TokenKind::Extern
+                    | TokenKind::Register
+                    | TokenKind::Static
+                    | TokenKind::ThreadLocal
+                    | TokenKind::Typedef
+            )
+    }
+    /// Returns whether `name` currently denotes a typedef name in the scopes open at this point.
+    pub fn is_typedef_name(&self, name: &str) -> bool {
         for scope in self.scopes.iter().rev() {
-            if scope.contains(name) {
-                return true;
+            if let Some(&is_typedef) = scope.get(name) {
+                return is_typedef;
             }
         }
 
         false
     }
+    /// Returns the identifiers declared in each open scope, innermost last,
+    /// mapped to whether they are typedef names.
+    #[cfg(feature = "debug-scopes")]
+    pub fn scopes(&self) -> Vec<std::collections::BTreeMap<&'a str, bool>> {
+        let scopes = self.scopes.iter();
+        scopes
+            .map(|scope| scope.iter().map(|(&k, &v)| (k, v)).collect())
+            .collect()
+    }
+    fn declare(&mut self, name: &'a str, is_typedef: bool) {
+        self.scopes.last_mut().unwrap().insert(name, is_typedef);
+    }
 
     fn parse_binary_expression(
         &mut self,
@@ -1845,7 +2422,7 @@ impl<'a, 'b> Parser<'a, 'b> {
 
     fn one_of<T, const N: usize>(
         &mut self,
-        options: [&mut dyn FnMut(&mut Self) -> Res<T>; N],
+        options: [Alternative<'_, Self, T>; N],
         expected: Expected<'a>,
     ) -> Res<T> {
         for option in options {
@@ -1865,20 +2442,34 @@ impl<'a, 'b> Parser<'a, 'b> {
             kind: ListKind::Leaf(Box::new(left)),
         };
 
-        loop {
-            if let Ok(right) = self.try_to(&mut parse) {
-                left = List {
-                    at: left.at,
-                    kind: ListKind::Cons(Box::new(left), Box::new(right)),
-                };
-            } else {
-                break;
-            }
+        while let Ok(right) = self.try_to(&mut parse) {
+            left = List {
+                at: left.at,
+                kind: ListKind::Cons(Box::new(left), Box::new(right)),
+            };
         }
 
         Ok(left)
     }
-    fn comma_list<T>(&mut self, mut parse: impl FnMut(&mut Self) -> Res<T>) -> Res<CommaList<T>> {
+    fn comma_list<T>(&mut self, parse: impl FnMut(&mut Self) -> Res<T>) -> Res<CommaList<T>> {
+        let list = self.comma_list_items(parse)?;
+        // No list ends with a comma before a closing parenthesis, as an empty
+        // `__VA_ARGS__` can leave behind.
+        if self.is(TokenKind::Comma) && self.peek_kind(1) == TokenKind::CloseParenthesis {
+            let index = self.index;
+            self.next();
+            self.err_unrecoverable(self.cur(), Expected::ItemAfterComma);
+            self.index = index;
+        }
+        Ok(list)
+    }
+    /// Parses a comma-separated list, leaving any comma that is not followed
+    /// by another item for the caller, which may accept it as a trailing comma
+    /// or before an ellipsis.
+    fn comma_list_items<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut Self) -> Res<T>,
+    ) -> Res<CommaList<T>> {
         let at = self.at();
         let left = parse(self)?;
         let mut left = CommaList {
@@ -1886,12 +2477,10 @@ impl<'a, 'b> Parser<'a, 'b> {
             kind: CommaListKind::Leaf(Box::new(left)),
         };
 
-        loop {
-            if !self.is(TokenKind::Comma) {
-                break;
-            };
-            let comma = self.next();
-            let right = parse(self)?;
+        while let Ok((comma, right)) = self.try_to(|p| {
+            let comma = p.take(TokenKind::Comma)?;
+            Ok((comma, parse(p)?))
+        }) {
             left = CommaList {
                 at: left.at,
                 kind: CommaListKind::Cons {
@@ -1906,17 +2495,13 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
 
     fn maybe<T>(&mut self, parse: impl FnMut(&mut Self) -> Res<T>) -> Option<T> {
-        if let Ok(t) = self.try_to(parse) {
-            Some(t)
-        } else {
-            None
-        }
+        self.try_to(parse).ok()
     }
     fn try_to<T>(&mut self, mut parse: impl FnMut(&mut Self) -> Res<T>) -> Res<T> {
         let index = self.index;
         let err_length = self.errors.len();
         let scopes_length = self.scopes.len();
-        self.scopes.push(HashSet::new());
+        self.scopes.push(HashMap::new());
 
         match parse(self) {
             Ok(t) => {
@@ -1927,13 +2512,39 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
             Err(()) => {
                 self.scopes.drain(scopes_length..);
-                self.errors.drain(err_length..);
+                let kept: Vec<_> = self
+                    .errors
+                    .drain(err_length..)
+                    .filter(|err| err.expected.is_unrecoverable())
+                    .collect();
+                self.errors.extend(kept);
                 self.index = index;
                 Err(())
             }
         }
     }
 
+    fn parse_string_literal(&mut self) -> Res<StringLiteral<'a>> {
+        let at = self.at();
+        let TokenKind::String(literal, encoding) = self.kind() else {
+            self.err(Expected::StringLiteral);
+            return Err(());
+        };
+        self.next();
+        Ok(StringLiteral {
+            at,
+            literal,
+            encoding,
+        })
+    }
+    fn is_asm_keyword(&self) -> bool {
+        self.options.dialect == Dialect::Gnu
+            && matches!(
+                self.kind(),
+                TokenKind::Identifier("asm" | "__asm" | "__asm__")
+            )
+    }
+
     fn take_identifier(&mut self) -> Res<&'a str> {
         let TokenKind::Identifier(name) = self.kind() else {
             self.err(Expected::Identifier);
@@ -1952,19 +2563,55 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn next(&mut self) -> At {
         let at = self.at();
         self.index += 1;
+        self.skip_comments();
         at
     }
+    fn skip_comments(&mut self) {
+        while self
+            .tokens
+            .get(self.index)
+            .is_some_and(|t| is_comment(t.kind))
+        {
+            self.index += 1;
+        }
+    }
     fn is(&self, kind: TokenKind) -> bool {
         self.kind() == kind
     }
     fn kind(&self) -> TokenKind<'a> {
         self.cur().kind
     }
+    fn peek_kind(&self, offset: usize) -> TokenKind<'a> {
+        self.tokens[self.index.min(self.tokens.len())..]
+            .iter()
+            .map(|t| t.kind)
+            .filter(|&kind| !is_comment(kind))
+            .nth(offset)
+            .map_or(TokenKind::Eof, |kind| kind.in_dialect(self.options.dialect))
+    }
     fn at(&self) -> At {
         self.cur().at
     }
     fn cur(&self) -> Token<'a> {
-        self.tokens[self.index]
+        let Some(&token) = self.tokens.get(self.index) else {
+            return self.eof();
+        };
+        Token {
+            kind: token.kind.in_dialect(self.options.dialect),
+            ..token
+        }
+    }
+    /// Returns an `Eof` token after the last token, for token slices without one.
+    fn eof(&self) -> Token<'a> {
+        let (at, end) = self
+            .tokens
+            .last()
+            .map_or((At::new(0, 1, 1, 0), 0), |t| (t.at, t.end));
+        Token {
+            at,
+            kind: TokenKind::Eof,
+            end,
+        }
     }
 
     fn err(&mut self, expected: Expected<'a>) {
@@ -1974,15 +2621,72 @@ impl<'a, 'b> Parser<'a, 'b> {
     fn err_at(&mut self, at: Token<'a>, expected: Expected<'a>) {
         self.errors.push(ParseErr { at, expected });
     }
+    /// Reports an error that no alternative parse can avoid. `try_to` keeps
+    /// such errors when it backtracks, and each is reported once however often
+    /// its token is reached.
+    fn err_unrecoverable(&mut self, at: Token<'a>, expected: Expected<'a>) {
+        debug_assert!(expected.is_unrecoverable());
+        let err = ParseErr { at, expected };
+        if !self.errors.contains(&err) {
+            self.errors.push(err);
+        }
+    }
+}
+
+fn is_comment(kind: TokenKind) -> bool {
+    matches!(kind, TokenKind::LineComment(_) | TokenKind::BlockComment(_))
 }
 
 type Res<T> = Result<T, ()>;
+type Alternative<'f, P, T> = &'f mut dyn FnMut(&mut P) -> Res<T>;
+
+/// The outcome of parsing a translation unit.
+///
+/// The parser recovers from some errors, so an AST may be present even
+/// when `errors` is not empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseResult<'a> {
+    pub ast: Option<TranslationUnit<'a>>,
+    pub errors: Vec<ParseErr<'a>>,
+}
+impl<'a> ParseResult<'a> {
+    /// Returns whether an AST was produced without any errors.
+    pub fn is_ok(&self) -> bool {
+        self.ast.is_some() && self.errors.is_empty()
+    }
+    pub fn errors(&self) -> &[ParseErr<'a>] {
+        &self.errors
+    }
+    /// Returns the AST, panicking if parsing reported any error.
+    pub fn unwrap(self) -> TranslationUnit<'a> {
+        match self.ast {
+            Some(ast) if self.errors.is_empty() => ast,
+            _ => panic!(
+                "called `ParseResult::unwrap` with errors: {:?}",
+                self.errors
+            ),
+        }
+    }
+    pub fn into_parts(self) -> (Result<TranslationUnit<'a>, ()>, Vec<ParseErr<'a>>) {
+        (self.ast.ok_or(()), self.errors)
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ParseErr<'a> {
     pub at: Token<'a>,
     pub expected: Expected<'a>,
 }
+impl fmt::Display for ParseErr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let at = self.at.at;
+        write!(
+            f,
+            "{}:{}: {}, found `{}`",
+            at.line, at.column, self.expected, self.at.kind
+        )
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Expected<'a> {
@@ -1998,6 +2702,7 @@ pub enum Expected<'a> {
     TypeofSpecifierArgument,
     TypeQualifier,
     AlignasArgument,
+    BalancedToken,
     ParameterDeclarationDeclarator,
     DirectAbstractDeclarator,
     Initializer,
@@ -2010,4 +2715,63 @@ pub enum Expected<'a> {
     IterationStatement,
     JumpStatement,
     ExternalDeclaration,
+    /// The input is empty or contains only comments.
+    NonEmptyTranslationUnit,
+    /// An ellipsis appears somewhere other than at the end of a parameter list.
+    EllipsisAfterParameters,
+    /// A comma is directly followed by a closing parenthesis.
+    ItemAfterComma,
+    /// `alignof` is applied to something other than a parenthesized type name.
+    AlignofOperand,
+    /// A GNU asm label holds something other than string literals.
+    AsmLabelName,
+}
+impl Expected<'_> {
+    fn is_unrecoverable(&self) -> bool {
+        matches!(
+            self,
+            Expected::EllipsisAfterParameters
+                | Expected::ItemAfterComma
+                | Expected::AlignofOperand
+                | Expected::AsmLabelName
+        )
+    }
+}
+impl fmt::Display for Expected<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self {
+            Expected::Token(TokenKind::Eof) => "end of file",
+            Expected::Token(kind) => return write!(f, "expected `{kind}`"),
+            Expected::PrimaryExpression => "a primary expression",
+            Expected::Identifier => "an identifier",
+            Expected::AssignmentOperator => "an assignment operator",
+            Expected::DeclarationSpecifier => "a declaration specifier",
+            Expected::StorageClassSpecifier => "a storage-class specifier",
+            Expected::TypeSpecifier => "a type specifier",
+            Expected::StructOrUnion => "`struct` or `union`",
+            Expected::TypeSpecifierQualifier => "a type specifier or qualifier",
+            Expected::TypeofSpecifierArgument => "an expression or type name",
+            Expected::TypeQualifier => "a type qualifier",
+            Expected::AlignasArgument => "a constant expression or type name",
+            Expected::BalancedToken => "a token",
+            Expected::ParameterDeclarationDeclarator => "a declarator or abstract declarator",
+            Expected::DirectAbstractDeclarator => "an abstract declarator",
+            Expected::Initializer => "an initializer",
+            Expected::StringLiteral => "a string literal",
+            Expected::Statement => "a statement",
+            Expected::UnlabeledStatement => "a statement",
+            Expected::PrimaryBlock => "a compound, selection, or iteration statement",
+            Expected::BlockItem => "a declaration or statement",
+            Expected::SelectionStatement => "`if` or `switch`",
+            Expected::IterationStatement => "`while`, `do`, or `for`",
+            Expected::JumpStatement => "`goto`, `continue`, `break`, or `return`",
+            Expected::ExternalDeclaration => "a declaration or function definition",
+            Expected::NonEmptyTranslationUnit => "at least one declaration",
+            Expected::EllipsisAfterParameters => "`...` only after the last parameter",
+            Expected::ItemAfterComma => "another item after `,`",
+            Expected::AlignofOperand => "a parenthesized type name after `alignof`",
+            Expected::AsmLabelName => "a string literal in the asm label",
+        };
+        write!(f, "expected {what}")
+    }
 }
@@ -0,0 +1,585 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::*,
+    eval::{DataModel, EvalConfig, Evaluator},
+    token::At,
+    types::{
+        BaseType, Tag, Type, TypeKind, canonicalize, declarator_type, parameter_adjusted_type,
+    },
+    visit::{self, Visitor},
+};
+
+pub type SymbolId = usize;
+pub type EnumerationId = usize;
+
+/// The ordinary identifiers, enumeration tags and structure and union members
+/// declared in a translation unit, together with the declaration each identifier expression refers to.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTable<'a> {
+    symbols: Vec<Symbol<'a>>,
+    enumerations: Vec<Enumeration<'a>>,
+    references: HashMap<At, SymbolId>,
+    /// The members of each structure and union. A tag defined in a block is
+    /// [`Tag::Scoped`], so it does not replace one defined outside the block.
+    records: HashMap<Tag<'a>, Vec<Member<'a>>>,
+}
+impl<'a> SymbolTable<'a> {
+    pub fn collect(unit: &TranslationUnit<'a>) -> Self {
+        let mut collector = Collector {
+            table: SymbolTable::default(),
+            scopes: vec![Scope::default()],
+            enum_specifiers: HashMap::new(),
+            parameter_scope: false,
+        };
+        collector.visit_translation_unit(unit);
+        collector.table
+    }
+
+    pub fn symbols(&self) -> &[Symbol<'a>] {
+        &self.symbols
+    }
+    pub fn symbol(&self, id: SymbolId) -> &Symbol<'a> {
+        &self.symbols[id]
+    }
+    pub fn enumeration(&self, id: EnumerationId) -> &Enumeration<'a> {
+        &self.enumerations[id]
+    }
+    /// Returns the symbol the identifier expression or typedef name specifier at `at` refers to.
+    pub fn resolve(&self, at: At) -> Option<&Symbol<'a>> {
+        self.references.get(&at).map(|&id| &self.symbols[id])
+    }
+    /// Returns the member `name` of an object of type `object`, looking through
+    /// anonymous structures and unions.
+    pub fn member(&self, object: &Type<'a>, name: &str) -> Option<&Member<'a>> {
+        let TypeKind::Base(BaseType::Struct(tag) | BaseType::Union(tag)) = &object.kind else {
+            return None;
+        };
+        self.records
+            .get(tag)?
+            .iter()
+            .find_map(|member| match member.name {
+                Some(member_name) => (member_name == name).then_some(member),
+                None => self.member(member.ty.as_ref()?, name),
+            })
+    }
+    /// Returns the size and alignment of `ty` as the System V ABI lays it out,
+    /// or `None` if it is incomplete or unresolved.
+    pub fn layout(&self, ty: &Type<'a>, data_model: DataModel) -> Option<Layout> {
+        match &ty.kind {
+            TypeKind::Base(BaseType::Struct(tag)) => {
+                Some(self.record_layout(tag, false, data_model)?.0)
+            }
+            TypeKind::Base(BaseType::Union(tag)) => {
+                Some(self.record_layout(tag, true, data_model)?.0)
+            }
+            // Enumerations without a fixed underlying type are laid out as `int`.
+            TypeKind::Base(BaseType::Enum(_)) => Some(Layout {
+                size: 4,
+                alignment: 4,
+            }),
+            TypeKind::Base(base) => Some(Layout {
+                size: base.size(data_model)?,
+                alignment: base.alignment(data_model)?,
+            }),
+            TypeKind::Pointer(_) => {
+                let bytes = u64::from(data_model.pointer_width() / 8);
+                Some(Layout {
+                    size: bytes,
+                    alignment: bytes,
+                })
+            }
+            TypeKind::Array { element, length } => {
+                let element = self.layout(element, data_model)?;
+                Some(Layout {
+                    size: element.size.checked_mul((*length)?)?,
+                    alignment: element.alignment,
+                })
+            }
+            TypeKind::Function { .. } => None,
+        }
+    }
+    /// Returns the byte offset of the member `name` within an object of type
+    /// `object`, looking through anonymous structures and unions.
+    pub fn member_offset(
+        &self,
+        object: &Type<'a>,
+        name: &str,
+        data_model: DataModel,
+    ) -> Option<(u64, &Member<'a>)> {
+        let (tag, is_union) = match &object.kind {
+            TypeKind::Base(BaseType::Struct(tag)) => (tag, false),
+            TypeKind::Base(BaseType::Union(tag)) => (tag, true),
+            _ => return None,
+        };
+        let (_, offsets) = self.record_layout(tag, is_union, data_model)?;
+        self.records
+            .get(tag)?
+            .iter()
+            .zip(offsets)
+            .find_map(|(member, offset)| match (member.name, member.width) {
+                (Some(member_name), _) => (member_name == name).then_some((offset, member)),
+                (None, None) => {
+                    let (inner, member) =
+                        self.member_offset(member.ty.as_ref()?, name, data_model)?;
+                    Some((offset + inner, member))
+                }
+                (None, Some(_)) => None,
+            })
+    }
+    /// Returns the layout of a structure or union and the byte offset of each
+    /// of its members. A bit-field's offset is that of the byte holding its first bit.
+    fn record_layout(
+        &self,
+        tag: &Tag<'a>,
+        is_union: bool,
+        data_model: DataModel,
+    ) -> Option<(Layout, Vec<u64>)> {
+        let members = self.records.get(tag)?;
+        let mut offsets = Vec::with_capacity(members.len());
+        let mut alignment = 1;
+        let mut bits: u64 = 0;
+        let mut size_bits: u64 = 0;
+        for member in members {
+            let ty = member.ty.as_ref()?;
+            let layout = match &ty.kind {
+                // A flexible array member adds no size.
+                TypeKind::Array {
+                    element,
+                    length: None,
+                } => Layout {
+                    size: 0,
+                    ..self.layout(element, data_model)?
+                },
+                _ => self.layout(ty, data_model)?,
+            };
+            // A bit-field is allocated within units the size of its type.
+            let unit = (layout.size * 8).max(8);
+            let start = match member.width {
+                _ if is_union => 0,
+                // A zero-width bit-field ends the unit the previous one is in.
+                Some(0) => bits.next_multiple_of(unit),
+                // A bit-field starts a new unit only if it would straddle one.
+                Some(width) if bits / unit != (bits + width - 1) / unit => {
+                    bits.next_multiple_of(unit)
+                }
+                Some(_) => bits,
+                None => bits.next_multiple_of(layout.alignment * 8),
+            };
+            // Unnamed bit-fields do not affect the alignment.
+            if member.name.is_some() || member.width.is_none() {
+                alignment = alignment.max(layout.alignment);
+            }
+            let end = start + member.width.unwrap_or(layout.size * 8);
+            offsets.push(start / 8);
+            size_bits = size_bits.max(end);
+            if !is_union {
+                bits = end;
+            }
+        }
+
+        let size = size_bits.div_ceil(8).next_multiple_of(alignment);
+        Some((Layout { size, alignment }, offsets))
+    }
+}
+
+/// The size and alignment of a complete object type, in bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    pub size: u64,
+    pub alignment: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol<'a> {
+    pub name: &'a str,
+    pub at: At,
+    pub kind: SymbolKind,
+    /// The block nesting depth of the declaration; zero at file scope.
+    pub depth: usize,
+    pub storage_class: Option<StorageClassSpecifierKind>,
+    /// The enumeration this symbol's type is, if it is a plain enumerated object.
+    pub enumeration: Option<EnumerationId>,
+    /// The declared type, with typedef names resolved, or `None` if it is invalid.
+    /// Parameters have their adjusted types, and enumerators have type `int`.
+    pub ty: Option<Type<'a>>,
+    /// The parameters of a function, or of a typedef name for a function type.
+    pub function: Option<FunctionInfo<'a>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInfo<'a> {
+    /// Whether the parameters were declared, which they are not for `()`.
+    /// Calls to a function without a prototype cannot be checked against it.
+    pub prototyped: bool,
+    /// The adjusted parameter types, which are empty for `(void)` and `()`.
+    pub params: Vec<Type<'a>>,
+    pub variadic: bool,
+}
+impl<'a> FunctionInfo<'a> {
+    /// Returns the parameters of `ty` if it is a function type.
+    pub fn of(ty: &Type<'a>) -> Option<Self> {
+        let TypeKind::Function {
+            parameters,
+            is_variadic,
+            ..
+        } = &ty.kind
+        else {
+            return None;
+        };
+        Some(Self {
+            prototyped: parameters.is_some(),
+            params: parameters.clone().unwrap_or_default(),
+            variadic: *is_variadic,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    Object,
+    Function,
+    Parameter,
+    Typedef,
+    Enumerator,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Enumeration<'a> {
+    pub at: At,
+    pub tag: Option<&'a str>,
+    pub enumerators: Vec<(&'a str, At)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member<'a> {
+    /// The name, or `None` for an anonymous structure or union or an unnamed bit-field.
+    pub name: Option<&'a str>,
+    /// The declared type, with typedef names resolved, or `None` if it is invalid.
+    pub ty: Option<Type<'a>>,
+    /// The width of a bit-field, or `None` if the member is not one or the
+    /// width is not a valid constant.
+    pub width: Option<u64>,
+}
+
+#[derive(Default)]
+struct Scope<'a> {
+    names: HashMap<&'a str, SymbolId>,
+    tags: HashMap<&'a str, EnumerationId>,
+    records: HashMap<&'a str, Tag<'a>>,
+}
+
+struct Collector<'a> {
+    table: SymbolTable<'a>,
+    scopes: Vec<Scope<'a>>,
+    enum_specifiers: HashMap<At, EnumerationId>,
+    parameter_scope: bool,
+}
+impl<'a> Collector<'a> {
+    fn declare(
+        &mut self,
+        (name, at): (&'a str, At),
+        kind: SymbolKind,
+        storage_class: Option<StorageClassSpecifierKind>,
+        enumeration: Option<EnumerationId>,
+        ty: Option<Type<'a>>,
+    ) -> SymbolId {
+        let id = self.table.symbols.len();
+        let function = ty.as_ref().and_then(FunctionInfo::of);
+        self.table.symbols.push(Symbol {
+            name,
+            at,
+            kind,
+            depth: self.scopes.len() - 1,
+            storage_class,
+            enumeration,
+            ty,
+            function,
+        });
+        self.scopes.last_mut().unwrap().names.insert(name, id);
+        id
+    }
+    fn lookup(&self, name: &str) -> Option<SymbolId> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.names.get(name).copied())
+    }
+    fn lookup_tag(&self, tag: &str) -> Option<EnumerationId> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.tags.get(tag).copied())
+    }
+    fn lookup_record(&self, tag: &str) -> Option<Tag<'a>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.records.get(tag).copied())
+    }
+
+    /// Returns the symbol of the typedef name `name` visible here.
+    fn lookup_typedef(&self, name: &str) -> Option<&Symbol<'a>> {
+        let symbol = &self.table.symbols[self.lookup(name)?];
+        (symbol.kind == SymbolKind::Typedef).then_some(symbol)
+    }
+    /// Resolves the tags and typedef names in `ty` to those visible here. The
+    /// types of typedef names had their tags resolved where they were declared.
+    fn resolve(&self, ty: Type<'a>) -> Type<'a> {
+        ty.resolve_tags(&|tag| self.lookup_record(tag))
+            .resolve_typedefs(&|name| self.lookup_typedef(name)?.ty.clone())
+    }
+
+    fn declare_declarator(
+        &mut self,
+        specifiers: &DeclarationSpecifiers<'a>,
+        declarator: &Declarator<'a>,
+        ty: Option<Type<'a>>,
+        parameter: bool,
+    ) {
+        let storage_class = storage_class(specifiers);
+        let derivations = declarator.derivations();
+        let kind = if storage_class == Some(StorageClassSpecifierKind::Typedef) {
+            SymbolKind::Typedef
+        } else if parameter {
+            SymbolKind::Parameter
+        } else if let Some(Derivation::Function(_)) = derivations.first() {
+            SymbolKind::Function
+        } else {
+            SymbolKind::Object
+        };
+        let enumeration = if derivations.is_empty() {
+            self.specified_enumeration(specifiers.iter().filter_map(|s| match &s.kind {
+                DeclarationSpecifierKind::Type(t) => Some(t),
+                _ => None,
+            }))
+        } else {
+            None
+        };
+
+        let ty = ty.map(|ty| self.resolve(ty));
+        self.declare(declarator.name(), kind, storage_class, enumeration, ty);
+    }
+    fn specified_enumeration<'s>(
+        &self,
+        specifiers: impl Iterator<Item = &'s TypeSpecifierQualifier<'a>>,
+    ) -> Option<EnumerationId>
+    where
+        'a: 's,
+    {
+        for specifier in specifiers {
+            let TypeSpecifierQualifierKind::TypeSpecifier(specifier) = &specifier.kind else {
+                continue;
+            };
+            match &specifier.kind {
+                TypeSpecifierKind::Enum(e) => {
+                    return self
+                        .enum_specifiers
+                        .get(&e.at)
+                        .copied()
+                        .or_else(|| e.tag.and_then(|tag| self.lookup_tag(tag)));
+                }
+                TypeSpecifierKind::TypedefName(name) => {
+                    let id = self.lookup(name)?;
+                    return self.table.symbols[id].enumeration;
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+}
+impl<'a> Visitor<'a> for Collector<'a> {
+    fn visit_function_definition(&mut self, function: &FunctionDefinition<'a>) {
+        visit::walk_declaration_specifiers(self, &function.specifiers);
+        let ty = declarator_type(
+            function.specifiers.type_specifier_qualifiers(),
+            &function.declarator,
+        );
+        self.declare_declarator(&function.specifiers, &function.declarator, ty.ok(), false);
+
+        self.scopes.push(Scope::default());
+        self.parameter_scope = true;
+        self.visit_declarator(&function.declarator);
+        self.parameter_scope = false;
+        // The outermost block of the body shares the scope of the parameters.
+        visit::walk_compound_statement(self, &function.body);
+        self.scopes.pop();
+    }
+    fn visit_declaration(&mut self, declaration: &Declaration<'a>) {
+        let DeclarationKind::Normal {
+            specifiers,
+            init_declarators,
+            ..
+        } = &declaration.kind
+        else {
+            return visit::walk_declaration(self, declaration);
+        };
+
+        visit::walk_declaration_specifiers(self, specifiers);
+        for init_declarator in init_declarators.iter().flat_map(|d| d.iter()) {
+            let declarator = &init_declarator.declarator;
+            self.visit_declarator(declarator);
+            let ty = declarator_type(specifiers.type_specifier_qualifiers(), declarator);
+            self.declare_declarator(specifiers, declarator, ty.ok(), false);
+            if let Some((_, initializer)) = &init_declarator.initializer {
+                self.visit_initializer(initializer);
+            }
+        }
+    }
+    fn visit_parameter_type_list(&mut self, parameters: &ParameterTypeList<'a>) {
+        if std::mem::take(&mut self.parameter_scope) {
+            return visit::walk_parameter_type_list(self, parameters);
+        }
+
+        self.scopes.push(Scope::default());
+        visit::walk_parameter_type_list(self, parameters);
+        self.scopes.pop();
+    }
+    fn visit_parameter_declaration(&mut self, parameter: &ParameterDeclaration<'a>) {
+        visit::walk_parameter_declaration(self, parameter);
+        if let ParameterDeclarationKind::Concrete(declarator) = &parameter.kind {
+            let ty = parameter_adjusted_type(parameter).ok();
+            self.declare_declarator(&parameter.specifiers, declarator, ty, true);
+        }
+    }
+    fn visit_struct_or_union_specifier(&mut self, specifier: &StructOrUnionSpecifier<'a>) {
+        visit::walk_struct_or_union_specifier(self, specifier);
+        let Some((_, declarations, _)) = &specifier.members else {
+            return;
+        };
+        // The tag is visible from its own members, such as a `next` pointer.
+        let tag = match specifier.tag {
+            Some(name) if self.scopes.len() > 1 => Tag::Scoped(name, specifier.at),
+            Some(name) => Tag::Named(name),
+            None => Tag::Anonymous(specifier.at),
+        };
+        if let Some(name) = specifier.tag {
+            self.scopes.last_mut().unwrap().records.insert(name, tag);
+        }
+
+        let mut members = Vec::new();
+        for declaration in declarations.iter() {
+            let MemberDeclarationKind::Member {
+                specifier_qualifiers,
+                member_declarators,
+                ..
+            } = &declaration.kind
+            else {
+                continue;
+            };
+            let Some(declarators) = member_declarators else {
+                let ty = canonicalize(specifier_qualifiers.iter()).ok();
+                members.push(Member {
+                    name: None,
+                    ty: ty.map(|ty| self.resolve(ty.into())),
+                    width: None,
+                });
+                continue;
+            };
+            // Unnamed bit-fields are kept for the layout, but cannot be looked up.
+            for member in declarators.iter() {
+                let width = member.width.as_ref().and_then(|(_, width)| {
+                    let width = Evaluator::new(EvalConfig::default()).evaluate(width).ok()?;
+                    u64::try_from(width.value).ok()
+                });
+                let (name, ty) = match &member.declarator {
+                    Some(declarator) => (
+                        Some(declarator.name().0),
+                        declarator_type(specifier_qualifiers.iter(), declarator).ok(),
+                    ),
+                    None if width.is_some() => (
+                        None,
+                        canonicalize(specifier_qualifiers.iter())
+                            .ok()
+                            .map(Into::into),
+                    ),
+                    None => continue,
+                };
+                members.push(Member {
+                    name,
+                    ty: ty.map(|ty| self.resolve(ty)),
+                    width,
+                });
+            }
+        }
+
+        self.table.records.insert(tag, members);
+    }
+    fn visit_member_declarator(&mut self, declarator: &MemberDeclarator<'a>) {
+        if let Some((_, width)) = &declarator.width {
+            self.visit_expression(width);
+        }
+    }
+    fn visit_enum_specifier(&mut self, specifier: &EnumSpecifier<'a>) {
+        if let Some(enum_type) = &specifier.enum_type {
+            visit::walk_specifier_qualifier_list(self, &enum_type.specifier_qualifiers);
+        }
+        let Some((_, enumerators, _, _)) = &specifier.enumerators else {
+            return;
+        };
+
+        let id = self.table.enumerations.len();
+        self.table.enumerations.push(Enumeration {
+            at: specifier.at,
+            tag: specifier.tag,
+            enumerators: Vec::new(),
+        });
+        self.enum_specifiers.insert(specifier.at, id);
+        if let Some(tag) = specifier.tag {
+            self.scopes.last_mut().unwrap().tags.insert(tag, id);
+        }
+
+        for enumerator in enumerators.iter() {
+            if let Some((_, value)) = &enumerator.value {
+                self.visit_expression(value);
+            }
+            self.declare(
+                (enumerator.name, enumerator.at),
+                SymbolKind::Enumerator,
+                None,
+                Some(id),
+                Some(Type {
+                    kind: TypeKind::Base(BaseType::Int),
+                    qualifiers: Default::default(),
+                }),
+            );
+            let entry = (enumerator.name, enumerator.at);
+            self.table.enumerations[id].enumerators.push(entry);
+        }
+    }
+    fn visit_type_specifier(&mut self, specifier: &TypeSpecifier<'a>) {
+        if let TypeSpecifierKind::TypedefName(name) = specifier.kind
+            && let Some(id) = self.lookup(name)
+        {
+            self.table.references.insert(specifier.at, id);
+        }
+        visit::walk_type_specifier(self, specifier);
+    }
+    fn visit_compound_statement(&mut self, statement: &CompoundStatement<'a>) {
+        self.scopes.push(Scope::default());
+        visit::walk_compound_statement(self, statement);
+        self.scopes.pop();
+    }
+    fn visit_iteration_statement(&mut self, statement: &IterationStatement<'a>) {
+        self.scopes.push(Scope::default());
+        visit::walk_iteration_statement(self, statement);
+        self.scopes.pop();
+    }
+    fn visit_expression(&mut self, expression: &Expression<'a>) {
+        if let ExpressionKind::Identifier(name) = expression.kind
+            && let Some(id) = self.lookup(name)
+        {
+            self.table.references.insert(expression.at, id);
+        }
+        visit::walk_expression(self, expression);
+    }
+}
+
+/// Returns the first storage-class specifier among `specifiers`.
+pub fn storage_class(specifiers: &DeclarationSpecifiers) -> Option<StorageClassSpecifierKind> {
+    specifiers.iter().find_map(|s| match &s.kind {
+        DeclarationSpecifierKind::StorageClass(s) => Some(s.kind),
+        _ => None,
+    })
+}
@@ -0,0 +1,87 @@
+//! Helpers for building ASTs from source snippets, for use in tests.
+//!
+//! The snippets are lexed with the default options and parsed in the GNU dialect.
+//! Parse errors panic with their positions in the snippet, reported at the caller.
+
+use crate::{
+    ast::{Expression, ExternalDeclaration},
+    lexer::Lexer,
+    parser::{Dialect, ParseErr, Parser, ParserOptions},
+    token::{Token, TokenKind},
+};
+
+/// Parses `src` as a single external declaration or function definition.
+#[macro_export]
+macro_rules! parse_decl {
+    ($src:expr) => {
+        $crate::test_util::parse_declaration($src)
+    };
+}
+
+/// Parses `src` as a single expression.
+#[macro_export]
+macro_rules! parse_expr {
+    ($src:expr) => {
+        $crate::test_util::parse_expression($src)
+    };
+}
+
+#[track_caller]
+pub fn parse_declaration(src: &str) -> ExternalDeclaration<'_> {
+    parse_snippet(src, "declaration", |p| p.parse_next_declaration())
+}
+#[track_caller]
+pub fn parse_expression(src: &str) -> Expression<'_> {
+    parse_snippet(src, "expression", |p| p.parse_next_expression())
+}
+
+#[track_caller]
+fn parse_snippet<'a, T>(
+    src: &'a str,
+    what: &str,
+    parse: impl for<'b> FnOnce(&mut Parser<'a, 'b>) -> Result<T, Vec<ParseErr<'a>>>,
+) -> T {
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let mut parser = Parser::with_options(&tokens, options);
+    let errors = match parse(&mut parser) {
+        Ok(parsed) => match parser.remaining().first() {
+            None
+            | Some(Token {
+                kind: TokenKind::Eof,
+                ..
+            }) => return parsed,
+            Some(&token) => {
+                panic!(
+                    "{what} `{src}` is followed by `{}` at {}:{}",
+                    token.kind, token.at.line, token.at.column
+                )
+            }
+        },
+        Err(errors) => errors,
+    };
+
+    let mut message = format!("failed to parse {what} `{src}`:");
+    for error in errors {
+        let (line, column) = match error.at.kind {
+            TokenKind::Eof => end_of(src),
+            _ => (error.at.at.line, error.at.at.column),
+        };
+        message += &format!(
+            "\n    {line}:{column}: {}, found `{}`",
+            error.expected, error.at.kind
+        );
+    }
+    panic!("{message}");
+}
+
+/// Returns the line and column just past the end of `src`; the `Eof` token has
+/// a position of its own, outside of the snippet.
+fn end_of(src: &str) -> (u32, u32) {
+    let line = src.matches('\n').count() + 1;
+    let last_line = src.rsplit('\n').next().unwrap_or_default();
+    (line as u32, last_line.chars().count() as u32 + 1)
+}
@@ -1,16 +1,32 @@
-use std::ops::Index;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Index,
+};
+
+use crate::parser::Dialect;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Token<'a> {
     pub at: At,
     pub kind: TokenKind<'a>,
+    /// The byte offset just past the end of the token in the lexed source.
+    pub end: usize,
+}
+impl Token<'_> {
+    /// Returns the token's spelling in `src`, the source it was lexed from.
+    pub fn text<'s>(&self, src: &'s str) -> &'s str {
+        &src[self.at.offset..self.end]
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TokenKind<'a> {
     Identifier(&'a str),
     Integer(IntegerToken<'a>),
+    Floating(FloatToken<'a>),
     String(&'a str, StringEncoding),
+    Character(&'a str, StringEncoding),
 
     OpenBracket,
     CloseBracket,
@@ -111,20 +127,496 @@ pub enum TokenKind<'a> {
     Decimal128,
     Decimal32,
     Decimal64,
+    Float16,
+    Float32,
+    Float64,
+    Float128,
+    Float32x,
+    Float64x,
+    Float128x,
+    /// The GNU `__int128` keyword.
+    Int128,
     Generic,
     Imaginary,
     Noreturn,
+    Pragma,
+
+    /// The text of a `//` comment, only lexed if [`LexerOptions::keep_comments`] is set.
+    ///
+    /// [`LexerOptions::keep_comments`]: crate::lexer::LexerOptions::keep_comments
+    LineComment(&'a str),
+    /// The text between `/*` and `*/`, only lexed if [`LexerOptions::keep_comments`] is set.
+    ///
+    /// [`LexerOptions::keep_comments`]: crate::lexer::LexerOptions::keep_comments
+    BlockComment(&'a str),
 
     Eof,
     Error,
 }
 
+impl<'a> TokenKind<'a> {
+    /// Returns the same kind with every slice of the source it borrows passed
+    /// through `rebase`, such as to move it to an edited copy of the source.
+    pub fn map_source<'b>(self, rebase: impl Fn(&'a str) -> &'b str) -> TokenKind<'b> {
+        match self {
+            TokenKind::Identifier(name) => TokenKind::Identifier(rebase(name)),
+            TokenKind::Integer(int) => TokenKind::Integer(IntegerToken {
+                source: rebase(int.source),
+                format: int.format,
+                suffix: int.suffix,
+            }),
+            TokenKind::Floating(float) => TokenKind::Floating(FloatToken {
+                source: rebase(float.source),
+                format: float.format,
+                suffix: float.suffix,
+            }),
+            TokenKind::String(literal, encoding) => TokenKind::String(rebase(literal), encoding),
+            TokenKind::Character(literal, encoding) => {
+                TokenKind::Character(rebase(literal), encoding)
+            }
+            TokenKind::LineComment(text) => TokenKind::LineComment(rebase(text)),
+            TokenKind::BlockComment(text) => TokenKind::BlockComment(rebase(text)),
+            TokenKind::OpenBracket => TokenKind::OpenBracket,
+            TokenKind::CloseBracket => TokenKind::CloseBracket,
+            TokenKind::OpenParenthesis => TokenKind::OpenParenthesis,
+            TokenKind::CloseParenthesis => TokenKind::CloseParenthesis,
+            TokenKind::OpenBrace => TokenKind::OpenBrace,
+            TokenKind::CloseBrace => TokenKind::CloseBrace,
+            TokenKind::Period => TokenKind::Period,
+            TokenKind::ArrowLeft => TokenKind::ArrowLeft,
+            TokenKind::DoublePlus => TokenKind::DoublePlus,
+            TokenKind::DoubleMinus => TokenKind::DoubleMinus,
+            TokenKind::Ampersand => TokenKind::Ampersand,
+            TokenKind::Asterisk => TokenKind::Asterisk,
+            TokenKind::Plus => TokenKind::Plus,
+            TokenKind::Minus => TokenKind::Minus,
+            TokenKind::Tilde => TokenKind::Tilde,
+            TokenKind::Exclamation => TokenKind::Exclamation,
+            TokenKind::Slash => TokenKind::Slash,
+            TokenKind::Percent => TokenKind::Percent,
+            TokenKind::DoubleLess => TokenKind::DoubleLess,
+            TokenKind::DoubleGreater => TokenKind::DoubleGreater,
+            TokenKind::Less => TokenKind::Less,
+            TokenKind::Greater => TokenKind::Greater,
+            TokenKind::LessEqual => TokenKind::LessEqual,
+            TokenKind::GreaterEqual => TokenKind::GreaterEqual,
+            TokenKind::DoubleEqual => TokenKind::DoubleEqual,
+            TokenKind::NotEqual => TokenKind::NotEqual,
+            TokenKind::Caret => TokenKind::Caret,
+            TokenKind::Bar => TokenKind::Bar,
+            TokenKind::DoubleAmpersand => TokenKind::DoubleAmpersand,
+            TokenKind::DoubleBar => TokenKind::DoubleBar,
+            TokenKind::Question => TokenKind::Question,
+            TokenKind::Colon => TokenKind::Colon,
+            TokenKind::DoubleColon => TokenKind::DoubleColon,
+            TokenKind::Semicolon => TokenKind::Semicolon,
+            TokenKind::Ellipses => TokenKind::Ellipses,
+            TokenKind::Equal => TokenKind::Equal,
+            TokenKind::AsteriskEqual => TokenKind::AsteriskEqual,
+            TokenKind::SlashEqual => TokenKind::SlashEqual,
+            TokenKind::PercentEqual => TokenKind::PercentEqual,
+            TokenKind::PlusEqual => TokenKind::PlusEqual,
+            TokenKind::MinusEqual => TokenKind::MinusEqual,
+            TokenKind::DoubleLessEqual => TokenKind::DoubleLessEqual,
+            TokenKind::DoubleGreaterEqual => TokenKind::DoubleGreaterEqual,
+            TokenKind::AmpersandEqual => TokenKind::AmpersandEqual,
+            TokenKind::CaretEqual => TokenKind::CaretEqual,
+            TokenKind::BarEqual => TokenKind::BarEqual,
+            TokenKind::Comma => TokenKind::Comma,
+            TokenKind::Alignas => TokenKind::Alignas,
+            TokenKind::Alignof => TokenKind::Alignof,
+            TokenKind::Auto => TokenKind::Auto,
+            TokenKind::Bool => TokenKind::Bool,
+            TokenKind::Break => TokenKind::Break,
+            TokenKind::Case => TokenKind::Case,
+            TokenKind::Char => TokenKind::Char,
+            TokenKind::Const => TokenKind::Const,
+            TokenKind::Constexpr => TokenKind::Constexpr,
+            TokenKind::Continue => TokenKind::Continue,
+            TokenKind::Default => TokenKind::Default,
+            TokenKind::Do => TokenKind::Do,
+            TokenKind::Double => TokenKind::Double,
+            TokenKind::Else => TokenKind::Else,
+            TokenKind::Enum => TokenKind::Enum,
+            TokenKind::Extern => TokenKind::Extern,
+            TokenKind::False => TokenKind::False,
+            TokenKind::Float => TokenKind::Float,
+            TokenKind::For => TokenKind::For,
+            TokenKind::Goto => TokenKind::Goto,
+            TokenKind::If => TokenKind::If,
+            TokenKind::Inline => TokenKind::Inline,
+            TokenKind::Int => TokenKind::Int,
+            TokenKind::Long => TokenKind::Long,
+            TokenKind::Nullptr => TokenKind::Nullptr,
+            TokenKind::Register => TokenKind::Register,
+            TokenKind::Restrict => TokenKind::Restrict,
+            TokenKind::Return => TokenKind::Return,
+            TokenKind::Short => TokenKind::Short,
+            TokenKind::Signed => TokenKind::Signed,
+            TokenKind::Sizeof => TokenKind::Sizeof,
+            TokenKind::Static => TokenKind::Static,
+            TokenKind::StaticAssert => TokenKind::StaticAssert,
+            TokenKind::Struct => TokenKind::Struct,
+            TokenKind::Switch => TokenKind::Switch,
+            TokenKind::ThreadLocal => TokenKind::ThreadLocal,
+            TokenKind::True => TokenKind::True,
+            TokenKind::Typedef => TokenKind::Typedef,
+            TokenKind::Typeof => TokenKind::Typeof,
+            TokenKind::TypeofUnqual => TokenKind::TypeofUnqual,
+            TokenKind::Union => TokenKind::Union,
+            TokenKind::Unsigned => TokenKind::Unsigned,
+            TokenKind::Void => TokenKind::Void,
+            TokenKind::Volatile => TokenKind::Volatile,
+            TokenKind::While => TokenKind::While,
+            TokenKind::Atomic => TokenKind::Atomic,
+            TokenKind::BitInt => TokenKind::BitInt,
+            TokenKind::Complex => TokenKind::Complex,
+            TokenKind::Decimal128 => TokenKind::Decimal128,
+            TokenKind::Decimal32 => TokenKind::Decimal32,
+            TokenKind::Decimal64 => TokenKind::Decimal64,
+            TokenKind::Float16 => TokenKind::Float16,
+            TokenKind::Float32 => TokenKind::Float32,
+            TokenKind::Float64 => TokenKind::Float64,
+            TokenKind::Float128 => TokenKind::Float128,
+            TokenKind::Float32x => TokenKind::Float32x,
+            TokenKind::Float64x => TokenKind::Float64x,
+            TokenKind::Float128x => TokenKind::Float128x,
+            TokenKind::Int128 => TokenKind::Int128,
+            TokenKind::Generic => TokenKind::Generic,
+            TokenKind::Imaginary => TokenKind::Imaginary,
+            TokenKind::Noreturn => TokenKind::Noreturn,
+            TokenKind::Pragma => TokenKind::Pragma,
+            TokenKind::Eof => TokenKind::Eof,
+            TokenKind::Error => TokenKind::Error,
+        }
+    }
+    /// Returns the kind the parser sees in `dialect`. The lexer does not know
+    /// the dialect, so outside of GNU C it is only here that `__int128`
+    /// becomes an ordinary identifier.
+    pub fn in_dialect(self, dialect: Dialect) -> TokenKind<'a> {
+        match self {
+            TokenKind::Int128 if dialect != Dialect::Gnu => TokenKind::Identifier("__int128"),
+            kind => kind,
+        }
+    }
+}
+impl TokenKind<'_> {
+    /// Classifies the token as lexed, in which `__int128` is always a keyword;
+    /// classify `kind.in_dialect(dialect)` to agree with the parser.
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            TokenKind::Identifier(_) => TokenCategory::Identifier,
+            TokenKind::Integer(_)
+            | TokenKind::Floating(_)
+            | TokenKind::String(..)
+            | TokenKind::Character(..) => TokenCategory::Literal,
+            TokenKind::OpenBracket
+            | TokenKind::CloseBracket
+            | TokenKind::OpenParenthesis
+            | TokenKind::CloseParenthesis
+            | TokenKind::OpenBrace
+            | TokenKind::CloseBrace
+            | TokenKind::Period
+            | TokenKind::ArrowLeft
+            | TokenKind::DoublePlus
+            | TokenKind::DoubleMinus
+            | TokenKind::Ampersand
+            | TokenKind::Asterisk
+            | TokenKind::Plus
+            | TokenKind::Minus
+            | TokenKind::Tilde
+            | TokenKind::Exclamation
+            | TokenKind::Slash
+            | TokenKind::Percent
+            | TokenKind::DoubleLess
+            | TokenKind::DoubleGreater
+            | TokenKind::Less
+            | TokenKind::Greater
+            | TokenKind::LessEqual
+            | TokenKind::GreaterEqual
+            | TokenKind::DoubleEqual
+            | TokenKind::NotEqual
+            | TokenKind::Caret
+            | TokenKind::Bar
+            | TokenKind::DoubleAmpersand
+            | TokenKind::DoubleBar
+            | TokenKind::Question
+            | TokenKind::Colon
+            | TokenKind::DoubleColon
+            | TokenKind::Semicolon
+            | TokenKind::Ellipses
+            | TokenKind::Equal
+            | TokenKind::AsteriskEqual
+            | TokenKind::SlashEqual
+            | TokenKind::PercentEqual
+            | TokenKind::PlusEqual
+            | TokenKind::MinusEqual
+            | TokenKind::DoubleLessEqual
+            | TokenKind::DoubleGreaterEqual
+            | TokenKind::AmpersandEqual
+            | TokenKind::CaretEqual
+            | TokenKind::BarEqual
+            | TokenKind::Comma => TokenCategory::Punctuator,
+            TokenKind::Alignas
+            | TokenKind::Alignof
+            | TokenKind::Auto
+            | TokenKind::Bool
+            | TokenKind::Break
+            | TokenKind::Case
+            | TokenKind::Char
+            | TokenKind::Const
+            | TokenKind::Constexpr
+            | TokenKind::Continue
+            | TokenKind::Default
+            | TokenKind::Do
+            | TokenKind::Double
+            | TokenKind::Else
+            | TokenKind::Enum
+            | TokenKind::Extern
+            | TokenKind::False
+            | TokenKind::Float
+            | TokenKind::For
+            | TokenKind::Goto
+            | TokenKind::If
+            | TokenKind::Inline
+            | TokenKind::Int
+            | TokenKind::Long
+            | TokenKind::Nullptr
+            | TokenKind::Register
+            | TokenKind::Restrict
+            | TokenKind::Return
+            | TokenKind::Short
+            | TokenKind::Signed
+            | TokenKind::Sizeof
+            | TokenKind::Static
+            | TokenKind::StaticAssert
+            | TokenKind::Struct
+            | TokenKind::Switch
+            | TokenKind::ThreadLocal
+            | TokenKind::True
+            | TokenKind::Typedef
+            | TokenKind::Typeof
+            | TokenKind::TypeofUnqual
+            | TokenKind::Union
+            | TokenKind::Unsigned
+            | TokenKind::Void
+            | TokenKind::Volatile
+            | TokenKind::While
+            | TokenKind::Atomic
+            | TokenKind::BitInt
+            | TokenKind::Complex
+            | TokenKind::Decimal128
+            | TokenKind::Decimal32
+            | TokenKind::Decimal64
+            | TokenKind::Float16
+            | TokenKind::Float32
+            | TokenKind::Float64
+            | TokenKind::Float128
+            | TokenKind::Float32x
+            | TokenKind::Float64x
+            | TokenKind::Float128x
+            | TokenKind::Int128
+            | TokenKind::Generic
+            | TokenKind::Imaginary
+            | TokenKind::Noreturn
+            | TokenKind::Pragma => TokenCategory::Keyword,
+            TokenKind::LineComment(_) | TokenKind::BlockComment(_) => TokenCategory::Comment,
+            TokenKind::Eof => TokenCategory::Eof,
+            TokenKind::Error => TokenCategory::Error,
+        }
+    }
+}
+
+/// A coarse classification of tokens, as used for syntax highlighting.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TokenCategory {
+    Keyword,
+    Punctuator,
+    Identifier,
+    /// An integer, floating, string or character constant.
+    Literal,
+    Comment,
+    Eof,
+    Error,
+}
+
+impl fmt::Display for TokenKind<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spelling = match self {
+            TokenKind::Identifier(name) => name,
+            TokenKind::Integer(int) => return write!(f, "{int}"),
+            TokenKind::Floating(float) => return write!(f, "{float}"),
+            TokenKind::String(literal, encoding) => {
+                return write!(f, "{}\"{literal}\"", encoding.prefix());
+            }
+            TokenKind::Character(literal, encoding) => {
+                return write!(f, "{}'{literal}'", encoding.prefix());
+            }
+            TokenKind::LineComment(text) => return write!(f, "//{text}"),
+            TokenKind::BlockComment(text) => return write!(f, "/*{text}*/"),
+            TokenKind::OpenBracket => "[",
+            TokenKind::CloseBracket => "]",
+            TokenKind::OpenParenthesis => "(",
+            TokenKind::CloseParenthesis => ")",
+            TokenKind::OpenBrace => "{",
+            TokenKind::CloseBrace => "}",
+            TokenKind::Period => ".",
+            TokenKind::ArrowLeft => "->",
+            TokenKind::DoublePlus => "++",
+            TokenKind::DoubleMinus => "--",
+            TokenKind::Ampersand => "&",
+            TokenKind::Asterisk => "*",
+            TokenKind::Plus => "+",
+            TokenKind::Minus => "-",
+            TokenKind::Tilde => "~",
+            TokenKind::Exclamation => "!",
+            TokenKind::Slash => "/",
+            TokenKind::Percent => "%",
+            TokenKind::DoubleLess => "<<",
+            TokenKind::DoubleGreater => ">>",
+            TokenKind::Less => "<",
+            TokenKind::Greater => ">",
+            TokenKind::LessEqual => "<=",
+            TokenKind::GreaterEqual => ">=",
+            TokenKind::DoubleEqual => "==",
+            TokenKind::NotEqual => "!=",
+            TokenKind::Caret => "^",
+            TokenKind::Bar => "|",
+            TokenKind::DoubleAmpersand => "&&",
+            TokenKind::DoubleBar => "||",
+            TokenKind::Question => "?",
+            TokenKind::Colon => ":",
+            TokenKind::DoubleColon => "::",
+            TokenKind::Semicolon => ";",
+            TokenKind::Ellipses => "...",
+            TokenKind::Equal => "=",
+            TokenKind::AsteriskEqual => "*=",
+            TokenKind::SlashEqual => "/=",
+            TokenKind::PercentEqual => "%=",
+            TokenKind::PlusEqual => "+=",
+            TokenKind::MinusEqual => "-=",
+            TokenKind::DoubleLessEqual => "<<=",
+            TokenKind::DoubleGreaterEqual => ">>=",
+            TokenKind::AmpersandEqual => "&=",
+            TokenKind::CaretEqual => "^=",
+            TokenKind::BarEqual => "|=",
+            TokenKind::Comma => ",",
+            TokenKind::Alignas => "alignas",
+            TokenKind::Alignof => "alignof",
+            TokenKind::Auto => "auto",
+            TokenKind::Bool => "bool",
+            TokenKind::Break => "break",
+            TokenKind::Case => "case",
+            TokenKind::Char => "char",
+            TokenKind::Const => "const",
+            TokenKind::Constexpr => "constexpr",
+            TokenKind::Continue => "continue",
+            TokenKind::Default => "default",
+            TokenKind::Do => "do",
+            TokenKind::Double => "double",
+            TokenKind::Else => "else",
+            TokenKind::Enum => "enum",
+            TokenKind::Extern => "extern",
+            TokenKind::False => "false",
+            TokenKind::Float => "float",
+            TokenKind::For => "for",
+            TokenKind::Goto => "goto",
+            TokenKind::If => "if",
+            TokenKind::Inline => "inline",
+            TokenKind::Int => "int",
+            TokenKind::Long => "long",
+            TokenKind::Nullptr => "nullptr",
+            TokenKind::Register => "register",
+            TokenKind::Restrict => "restrict",
+            TokenKind::Return => "return",
+            TokenKind::Short => "short",
+            TokenKind::Signed => "signed",
+            TokenKind::Sizeof => "sizeof",
+            TokenKind::Static => "static",
+            TokenKind::StaticAssert => "static_assert",
+            TokenKind::Struct => "struct",
+            TokenKind::Switch => "switch",
+            TokenKind::ThreadLocal => "thread_local",
+            TokenKind::True => "true",
+            TokenKind::Typedef => "typedef",
+            TokenKind::Typeof => "typeof",
+            TokenKind::TypeofUnqual => "typeof_unqual",
+            TokenKind::Union => "union",
+            TokenKind::Unsigned => "unsigned",
+            TokenKind::Void => "void",
+            TokenKind::Volatile => "volatile",
+            TokenKind::While => "while",
+            TokenKind::Atomic => "_Atomic",
+            TokenKind::BitInt => "_BitInt",
+            TokenKind::Complex => "_Complex",
+            TokenKind::Decimal128 => "_Decimal128",
+            TokenKind::Decimal32 => "_Decimal32",
+            TokenKind::Decimal64 => "_Decimal64",
+            TokenKind::Float16 => "_Float16",
+            TokenKind::Float32 => "_Float32",
+            TokenKind::Float64 => "_Float64",
+            TokenKind::Float128 => "_Float128",
+            TokenKind::Float32x => "_Float32x",
+            TokenKind::Float64x => "_Float64x",
+            TokenKind::Float128x => "_Float128x",
+            TokenKind::Int128 => "__int128",
+            TokenKind::Generic => "_Generic",
+            TokenKind::Imaginary => "_Imaginary",
+            TokenKind::Noreturn => "_Noreturn",
+            TokenKind::Pragma => "_Pragma",
+            TokenKind::Eof => "end of file",
+            TokenKind::Error => "invalid token",
+        };
+        f.write_str(spelling)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct IntegerToken<'a> {
     pub source: &'a str,
     pub format: IntegerFormat,
     pub suffix: Option<IntegerSuffix>,
 }
+impl fmt::Display for IntegerToken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.format {
+            IntegerFormat::Decimal | IntegerFormat::Octal => "",
+            IntegerFormat::Hexadecimal => "0x",
+            IntegerFormat::Binary => "0b",
+        };
+        let suffix = match self.suffix {
+            None => "",
+            Some(IntegerSuffix::Unsigned) => "u",
+            Some(IntegerSuffix::Long) => "l",
+            Some(IntegerSuffix::LongUnsigned) => "ul",
+            Some(IntegerSuffix::LongLong) => "ll",
+            Some(IntegerSuffix::LongLongUnsigned) => "ull",
+            Some(IntegerSuffix::BitPrecise) => "wb",
+            Some(IntegerSuffix::BitPreciseUnsigned) => "uwb",
+        };
+        write!(f, "{prefix}{}{suffix}", self.source)
+    }
+}
+impl IntegerToken<'_> {
+    /// Returns the value of the literal, or `None` if it does not fit in a `u128`.
+    pub fn value(&self) -> Option<u128> {
+        let radix = match self.format {
+            IntegerFormat::Decimal => 10,
+            IntegerFormat::Octal => 8,
+            IntegerFormat::Hexadecimal => 16,
+            IntegerFormat::Binary => 2,
+        };
+
+        let mut value: u128 = 0;
+        for c in self.source.chars().filter(|&c| c != '\'') {
+            let digit = c.to_digit(radix)?;
+            value = value
+                .checked_mul(radix as u128)?
+                .checked_add(digit as u128)?;
+        }
+        Some(value)
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum IntegerFormat {
@@ -145,6 +637,52 @@ pub enum IntegerSuffix {
     BitPreciseUnsigned,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FloatToken<'a> {
+    /// The significand and exponent, without the `0x` prefix or the suffix.
+    pub source: &'a str,
+    pub format: FloatFormat,
+    pub suffix: Option<FloatSuffix>,
+}
+impl fmt::Display for FloatToken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.format {
+            FloatFormat::Decimal => "",
+            FloatFormat::Hexadecimal => "0x",
+        };
+        let suffix = match self.suffix {
+            None => "",
+            Some(FloatSuffix::Float) => "f",
+            Some(FloatSuffix::LongDouble) => "l",
+            Some(FloatSuffix::Decimal32) => "df",
+            Some(FloatSuffix::Decimal64) => "dd",
+            Some(FloatSuffix::Decimal128) => "dl",
+        };
+        write!(f, "{prefix}{}{suffix}", self.source)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FloatFormat {
+    Decimal,
+    Hexadecimal,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FloatSuffix {
+    Float,
+    LongDouble,
+    /// The `df`, `dd` and `dl` suffixes of decimal floating constants.
+    Decimal32,
+    Decimal64,
+    Decimal128,
+}
+impl FloatSuffix {
+    pub fn is_decimal(self) -> bool {
+        matches!(self, Self::Decimal32 | Self::Decimal64 | Self::Decimal128)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum StringEncoding {
     None,
@@ -153,16 +691,36 @@ pub enum StringEncoding {
     UTF32,
     Wide,
 }
+impl StringEncoding {
+    /// Returns the literal prefix selecting this encoding.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            StringEncoding::None => "",
+            StringEncoding::UTF8 => "u8",
+            StringEncoding::UTF16 => "u",
+            StringEncoding::UTF32 => "U",
+            StringEncoding::Wide => "L",
+        }
+    }
+}
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct At {
     pub file: usize,
     pub line: u32,
     pub column: u32,
+    /// The byte offset in the lexed source, which unlike `line` and `column`
+    /// is not remapped by line markers.
+    pub offset: usize,
 }
 impl At {
-    pub fn new(file: usize, line: u32, column: u32) -> Self {
-        Self { file, line, column }
+    pub fn new(file: usize, line: u32, column: u32, offset: usize) -> Self {
+        Self {
+            file,
+            line,
+            column,
+            offset,
+        }
     }
 
     pub fn next_column(&mut self, by: u32) {
@@ -174,26 +732,34 @@ impl At {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Files {
     files: Vec<String>,
+    ids: HashMap<String, usize>,
+    system_headers: HashSet<usize>,
 }
 impl Files {
     pub fn new() -> Self {
-        Self { files: Vec::new() }
+        Self::default()
     }
 
     pub fn get_file_id(&mut self, name: &str) -> usize {
-        for (i, file) in self.files.iter().enumerate() {
-            if file == name {
-                return i;
-            }
+        if let Some(&i) = self.ids.get(name) {
+            return i;
         }
 
         let i = self.files.len();
         self.files.push(name.to_string());
+        self.ids.insert(name.to_string(), i);
         i
     }
+    pub fn mark_system_header(&mut self, id: usize) {
+        self.system_headers.insert(id);
+    }
+    /// Whether a line marker entering the file flagged it as a system header.
+    pub fn is_system_header(&self, id: usize) -> bool {
+        self.system_headers.contains(&id)
+    }
 }
 impl Index<usize> for Files {
     type Output = str;
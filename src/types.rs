@@ -0,0 +1,1047 @@
+use crate::{
+    ast::*,
+    eval::{DataModel, EvalConfig, Evaluator, IntegerType, literal_type},
+    symbols::{Member, SymbolTable},
+    token::{At, FloatSuffix, IntegerSuffix, StringEncoding},
+};
+
+/// The type named by a list of specifiers and qualifiers, with the specifiers
+/// folded into a single base type, so that `unsigned const int` and
+/// `const unsigned int` compare equal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QualifiedType<'a> {
+    pub base: BaseType<'a>,
+    pub qualifiers: Qualifiers,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Qualifiers {
+    pub is_const: bool,
+    pub is_restrict: bool,
+    pub is_volatile: bool,
+    pub is_atomic: bool,
+}
+impl Qualifiers {
+    fn add(&mut self, kind: TypeQualifierKind) {
+        match kind {
+            TypeQualifierKind::Const => self.is_const = true,
+            TypeQualifierKind::Restrict => self.is_restrict = true,
+            TypeQualifierKind::Volatile => self.is_volatile = true,
+            TypeQualifierKind::Atomic => self.is_atomic = true,
+        }
+    }
+    fn from_list(list: Option<&TypeQualifierList>) -> Self {
+        let mut qualifiers = Self::default();
+        for qualifier in list.into_iter().flat_map(|l| l.iter()) {
+            qualifiers.add(qualifier.kind);
+        }
+        qualifiers
+    }
+}
+
+/// A type with the derivations of a declarator applied to its base type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Type<'a> {
+    pub kind: TypeKind<'a>,
+    pub qualifiers: Qualifiers,
+}
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeKind<'a> {
+    Base(BaseType<'a>),
+    Pointer(Box<Type<'a>>),
+    /// The length is `None` if it is unspecified, variable or could not be evaluated.
+    Array {
+        element: Box<Type<'a>>,
+        length: Option<u64>,
+    },
+    /// The parameters are `None` if the declarator has an empty parameter list.
+    Function {
+        returns: Box<Type<'a>>,
+        parameters: Option<Vec<Type<'a>>>,
+        is_variadic: bool,
+    },
+}
+impl<'a> From<QualifiedType<'a>> for Type<'a> {
+    fn from(ty: QualifiedType<'a>) -> Self {
+        Self {
+            kind: TypeKind::Base(ty.base),
+            qualifiers: ty.qualifiers,
+        }
+    }
+}
+impl<'a> Type<'a> {
+    /// Replaces the typedef names in the type with the types `lookup` returns for them,
+    /// adding the qualifiers applied to the typedef name.
+    pub fn resolve_typedefs(&self, lookup: &impl Fn(&str) -> Option<Type<'a>>) -> Type<'a> {
+        let kind = match &self.kind {
+            TypeKind::Base(BaseType::Typedef(name)) => {
+                let Some(mut named) = lookup(name) else {
+                    return self.clone();
+                };
+                named.qualifiers.is_const |= self.qualifiers.is_const;
+                named.qualifiers.is_restrict |= self.qualifiers.is_restrict;
+                named.qualifiers.is_volatile |= self.qualifiers.is_volatile;
+                named.qualifiers.is_atomic |= self.qualifiers.is_atomic;
+                return named;
+            }
+            TypeKind::Base(base) => TypeKind::Base(base.clone()),
+            TypeKind::Pointer(pointee) => {
+                TypeKind::Pointer(Box::new(pointee.resolve_typedefs(lookup)))
+            }
+            TypeKind::Array { element, length } => TypeKind::Array {
+                element: Box::new(element.resolve_typedefs(lookup)),
+                length: *length,
+            },
+            TypeKind::Function {
+                returns,
+                parameters,
+                is_variadic,
+            } => TypeKind::Function {
+                returns: Box::new(returns.resolve_typedefs(lookup)),
+                parameters: parameters
+                    .as_ref()
+                    .map(|list| list.iter().map(|p| p.resolve_typedefs(lookup)).collect()),
+                is_variadic: *is_variadic,
+            },
+        };
+        Type {
+            kind,
+            qualifiers: self.qualifiers,
+        }
+    }
+    /// Replaces the named structure and union tags in the type with the tags
+    /// `lookup` returns for them, such as those of a definition in a block.
+    pub fn resolve_tags(&self, lookup: &impl Fn(&'a str) -> Option<Tag<'a>>) -> Type<'a> {
+        let kind = match &self.kind {
+            TypeKind::Base(BaseType::Struct(Tag::Named(name))) => {
+                TypeKind::Base(BaseType::Struct(lookup(name).unwrap_or(Tag::Named(name))))
+            }
+            TypeKind::Base(BaseType::Union(Tag::Named(name))) => {
+                TypeKind::Base(BaseType::Union(lookup(name).unwrap_or(Tag::Named(name))))
+            }
+            TypeKind::Base(base) => TypeKind::Base(base.clone()),
+            TypeKind::Pointer(pointee) => TypeKind::Pointer(Box::new(pointee.resolve_tags(lookup))),
+            TypeKind::Array { element, length } => TypeKind::Array {
+                element: Box::new(element.resolve_tags(lookup)),
+                length: *length,
+            },
+            TypeKind::Function {
+                returns,
+                parameters,
+                is_variadic,
+            } => TypeKind::Function {
+                returns: Box::new(returns.resolve_tags(lookup)),
+                parameters: parameters
+                    .as_ref()
+                    .map(|list| list.iter().map(|p| p.resolve_tags(lookup)).collect()),
+                is_variadic: *is_variadic,
+            },
+        };
+        Type {
+            kind,
+            qualifiers: self.qualifiers,
+        }
+    }
+    /// Whether two declarations of the same entity with these types may coexist.
+    ///
+    /// Typedef names are compared by name, so they should be resolved first.
+    /// Types the pass does not resolve, like `typeof`, are compatible with any type.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        if self.qualifiers != other.qualifiers {
+            return false;
+        }
+        match (&self.kind, &other.kind) {
+            (TypeKind::Base(BaseType::Typeof(_) | BaseType::Atomic(_)), _)
+            | (_, TypeKind::Base(BaseType::Typeof(_) | BaseType::Atomic(_))) => true,
+            (TypeKind::Base(a), TypeKind::Base(b)) => a == b,
+            (TypeKind::Pointer(a), TypeKind::Pointer(b)) => a.is_compatible(b),
+            (
+                TypeKind::Array { element, length },
+                TypeKind::Array {
+                    element: other_element,
+                    length: other_length,
+                },
+            ) => {
+                let lengths_agree = match (length, other_length) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => true,
+                };
+                lengths_agree && element.is_compatible(other_element)
+            }
+            (
+                TypeKind::Function {
+                    returns,
+                    parameters,
+                    is_variadic,
+                },
+                TypeKind::Function {
+                    returns: other_returns,
+                    parameters: other_parameters,
+                    is_variadic: other_is_variadic,
+                },
+            ) => {
+                // An empty parameter list declares nothing about the parameters.
+                let parameters_agree = match (parameters, other_parameters) {
+                    (Some(a), Some(b)) => {
+                        is_variadic == other_is_variadic
+                            && a.len() == b.len()
+                            && a.iter().zip(b).all(|(a, b)| {
+                                // Qualifiers on a parameter do not affect the function type.
+                                let unqualified = |ty: &Self| Self {
+                                    qualifiers: Qualifiers::default(),
+                                    ..ty.clone()
+                                };
+                                unqualified(a).is_compatible(&unqualified(b))
+                            })
+                    }
+                    _ => true,
+                };
+                parameters_agree && returns.is_compatible(other_returns)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<IntegerType> for BaseType<'_> {
+    fn from(ty: IntegerType) -> Self {
+        match ty {
+            IntegerType::Bool => BaseType::Bool,
+            IntegerType::Char => BaseType::Char,
+            IntegerType::SignedChar => BaseType::SignedChar,
+            IntegerType::UnsignedChar => BaseType::UnsignedChar,
+            IntegerType::Short => BaseType::Short,
+            IntegerType::UnsignedShort => BaseType::UnsignedShort,
+            IntegerType::Int => BaseType::Int,
+            IntegerType::UnsignedInt => BaseType::UnsignedInt,
+            IntegerType::Long => BaseType::Long,
+            IntegerType::UnsignedLong => BaseType::UnsignedLong,
+            IntegerType::LongLong => BaseType::LongLong,
+            IntegerType::UnsignedLongLong => BaseType::UnsignedLongLong,
+        }
+    }
+}
+
+/// A type named by specifiers alone.
+///
+/// Types that depend on an operand the pass does not resolve, like `typeof`,
+/// are identified by the position of their specifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BaseType<'a> {
+    Void,
+    Bool,
+    Char,
+    SignedChar,
+    UnsignedChar,
+    Short,
+    UnsignedShort,
+    Int,
+    UnsignedInt,
+    Long,
+    UnsignedLong,
+    LongLong,
+    UnsignedLongLong,
+    Int128,
+    UnsignedInt128,
+    /// A `_BitInt(N)`; the width is `None` if `N` could not be evaluated.
+    BitInt {
+        unsigned: bool,
+        width: Option<u32>,
+    },
+    Float,
+    Double,
+    LongDouble,
+    ComplexFloat,
+    ComplexDouble,
+    ComplexLongDouble,
+    Decimal32,
+    Decimal64,
+    Decimal128,
+    Float16,
+    Float32,
+    Float64,
+    Float128,
+    Float32x,
+    Float64x,
+    Float128x,
+    Struct(Tag<'a>),
+    Union(Tag<'a>),
+    Enum(Tag<'a>),
+    Typedef(&'a str),
+    Typeof(At),
+    Atomic(At),
+}
+
+impl BaseType<'_> {
+    /// Whether the type is an integer type, including `bool`, `_BitInt` and enumerations.
+    pub fn is_integer(&self) -> bool {
+        use BaseType::*;
+        matches!(
+            self,
+            Bool | Char
+                | SignedChar
+                | UnsignedChar
+                | Short
+                | UnsignedShort
+                | Int
+                | UnsignedInt
+                | Long
+                | UnsignedLong
+                | LongLong
+                | UnsignedLongLong
+                | Int128
+                | UnsignedInt128
+                | BitInt { .. }
+                | Enum(_)
+        )
+    }
+    /// Whether the type is a real or complex floating type.
+    pub fn is_floating(&self) -> bool {
+        use BaseType::*;
+        matches!(
+            self,
+            Float
+                | Double
+                | LongDouble
+                | ComplexFloat
+                | ComplexDouble
+                | ComplexLongDouble
+                | Decimal32
+                | Decimal64
+                | Decimal128
+                | Float16
+                | Float32
+                | Float64
+                | Float128
+                | Float32x
+                | Float64x
+                | Float128x
+        )
+    }
+    /// Whether the type is `bool` or an unsigned integer type other than an enumeration.
+    pub fn is_unsigned(&self) -> bool {
+        use BaseType::*;
+        matches!(
+            self,
+            Bool | UnsignedChar
+                | UnsignedShort
+                | UnsignedInt
+                | UnsignedLong
+                | UnsignedLongLong
+                | UnsignedInt128
+                | BitInt { unsigned: true, .. }
+        )
+    }
+    pub fn is_complex(&self) -> bool {
+        use BaseType::*;
+        matches!(self, ComplexFloat | ComplexDouble | ComplexLongDouble)
+    }
+    /// Returns the alignment in bytes of a scalar type on x86 and x86-64,
+    /// or `None` for aggregate, incomplete and unresolved types.
+    pub fn alignment(&self, data_model: DataModel) -> Option<u64> {
+        let long = match data_model {
+            DataModel::LP64 => 8,
+            DataModel::ILP32 | DataModel::LLP64 => 4,
+        };
+        Some(match self {
+            BaseType::Bool | BaseType::Char | BaseType::SignedChar | BaseType::UnsignedChar => 1,
+            BaseType::Short | BaseType::UnsignedShort | BaseType::Float16 => 2,
+            BaseType::Int | BaseType::UnsignedInt => 4,
+            BaseType::Long | BaseType::UnsignedLong => long,
+            BaseType::LongLong | BaseType::UnsignedLongLong => 8,
+            BaseType::Int128 | BaseType::UnsignedInt128 => 16,
+            BaseType::BitInt { width, .. } => {
+                let bytes = width.as_ref()?.div_ceil(8).max(1);
+                u64::from(bytes.next_power_of_two().min(8))
+            }
+            BaseType::Float | BaseType::ComplexFloat | BaseType::Float32 => 4,
+            BaseType::Decimal32 => 4,
+            BaseType::Double | BaseType::ComplexDouble | BaseType::Float64 => 8,
+            BaseType::Decimal64 | BaseType::Float32x => 8,
+            BaseType::LongDouble | BaseType::ComplexLongDouble | BaseType::Float64x => 16,
+            BaseType::Decimal128 | BaseType::Float128 => 16,
+            BaseType::Void
+            | BaseType::Float128x
+            | BaseType::Struct(_)
+            | BaseType::Union(_)
+            | BaseType::Enum(_)
+            | BaseType::Typedef(_)
+            | BaseType::Typeof(_)
+            | BaseType::Atomic(_) => return None,
+        })
+    }
+    /// Returns the size in bytes of a scalar type on x86 and x86-64,
+    /// or `None` for aggregate, incomplete and unresolved types.
+    pub fn size(&self, data_model: DataModel) -> Option<u64> {
+        let alignment = self.alignment(data_model)?;
+        Some(match self {
+            BaseType::BitInt { width, .. } => {
+                u64::from(width.as_ref()?.div_ceil(8)).next_multiple_of(alignment)
+            }
+            BaseType::ComplexFloat | BaseType::ComplexDouble | BaseType::ComplexLongDouble => {
+                2 * alignment
+            }
+            _ => alignment,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Tag<'a> {
+    Named(&'a str),
+    Anonymous(At),
+    /// A tag defined in a block, which names a different type than the same tag
+    /// elsewhere. It is identified by the specifier defining it.
+    Scoped(&'a str, At),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TypeErr {
+    pub at: At,
+    pub kind: TypeErrKind,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TypeErrKind {
+    /// A type specifier cannot be combined with the others in the list.
+    InvalidCombination,
+    /// The list has qualifiers but no type specifier.
+    MissingTypeSpecifier,
+}
+
+/// Folds `items` into the type they name, independent of their order.
+/// Alignment specifiers do not contribute to the type and are ignored.
+pub fn canonicalize<'s, 'a: 's>(
+    items: impl IntoIterator<Item = &'s TypeSpecifierQualifier<'a>>,
+) -> Result<QualifiedType<'a>, TypeErr> {
+    let mut qualifiers = Qualifiers::default();
+    let mut specifiers = Specifiers::default();
+    let mut first = None;
+    for item in items {
+        first.get_or_insert(item.at);
+        match &item.kind {
+            TypeSpecifierQualifierKind::TypeSpecifier(specifier) => specifiers.add(specifier)?,
+            TypeSpecifierQualifierKind::TypeQualifier(qualifier) => qualifiers.add(qualifier.kind),
+            TypeSpecifierQualifierKind::Alignment(_) => (),
+        }
+    }
+
+    let base = specifiers.resolve().map_err(|at| TypeErr {
+        at: at.or(first).unwrap_or(At::new(0, 1, 1, 0)),
+        kind: match at {
+            Some(_) => TypeErrKind::InvalidCombination,
+            None => TypeErrKind::MissingTypeSpecifier,
+        },
+    })?;
+    Ok(QualifiedType { base, qualifiers })
+}
+
+/// Returns the type of a declared parameter after adjustment: an array of `T`
+/// becomes a pointer to `T` carrying the qualifiers written inside the brackets,
+/// and a function becomes a pointer to that function.
+pub fn parameter_adjusted_type<'a>(
+    parameter: &ParameterDeclaration<'a>,
+) -> Result<Type<'a>, TypeErr> {
+    let base = canonicalize(parameter.specifiers.type_specifier_qualifiers())?;
+    let mut derivations = Vec::new();
+    match &parameter.kind {
+        ParameterDeclarationKind::Concrete(declarator) => {
+            derivations.extend(declarator.derivations().into_iter().map(Step::from))
+        }
+        ParameterDeclarationKind::Abstract(declarator) => {
+            abstract_steps(&mut derivations, declarator.as_ref())
+        }
+    }
+
+    let Some((first, rest)) = derivations.split_first() else {
+        return Ok(base.into());
+    };
+    let inner = derive(base.into(), rest)?;
+    Ok(match first {
+        Step::Array { qualifiers, .. } => Type {
+            kind: TypeKind::Pointer(Box::new(inner)),
+            qualifiers: Qualifiers::from_list(*qualifiers),
+        },
+        Step::Function(_) => Type {
+            kind: TypeKind::Pointer(Box::new(derive(inner, &[*first])?)),
+            qualifiers: Qualifiers::default(),
+        },
+        Step::Pointer(_) => derive(inner, &[*first])?,
+    })
+}
+
+/// Returns the type `declarator` gives to its identifier when declared with `specifiers`.
+pub fn declarator_type<'s, 'a: 's>(
+    specifiers: impl IntoIterator<Item = &'s TypeSpecifierQualifier<'a>>,
+    declarator: &Declarator<'a>,
+) -> Result<Type<'a>, TypeErr> {
+    let steps: Vec<_> = declarator
+        .derivations()
+        .into_iter()
+        .map(Step::from)
+        .collect();
+    derive(canonicalize(specifiers)?.into(), &steps)
+}
+
+/// Returns the member a `.` or `->` expression accesses, if it is known.
+pub fn accessed_member<'s, 'a>(
+    expression: &Expression<'a>,
+    symbols: &'s SymbolTable<'a>,
+) -> Option<&'s Member<'a>> {
+    member_access(expression, symbols).map(|(_, member)| member)
+}
+/// Returns the type of the object whose member a `.` or `->` expression accesses,
+/// together with the member.
+fn member_access<'s, 'a>(
+    expression: &Expression<'a>,
+    symbols: &'s SymbolTable<'a>,
+) -> Option<(Type<'a>, &'s Member<'a>)> {
+    let (object, name) = match &expression.kind {
+        ExpressionKind::Member { left, name, .. } => (expression_type(left, symbols)?, name),
+        ExpressionKind::MemberIndirect { left, name, .. } => {
+            match decay(&expression_type(left, symbols)?).kind {
+                TypeKind::Pointer(object) => (*object, name),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let member = symbols.member(&object, name)?;
+    Some((object, member))
+}
+
+/// Returns the qualifiers of each level of `pointer`, in source order.
+pub fn pointer_qualifiers(pointer: &Pointer) -> Vec<Qualifiers> {
+    let levels = pointer.levels().into_iter();
+    levels
+        .map(|p| Qualifiers::from_list(p.qualifiers.as_ref()))
+        .collect()
+}
+
+/// Returns the type named by `type_name`.
+pub fn type_name_type<'a>(type_name: &TypeName<'a>) -> Result<Type<'a>, TypeErr> {
+    let mut steps = Vec::new();
+    abstract_steps(&mut steps, type_name.declarator.as_ref());
+    derive(
+        canonicalize(type_name.specifier_qualifiers.iter())?.into(),
+        &steps,
+    )
+}
+
+/// A derivation of either a declarator or an abstract declarator.
+#[derive(Copy, Clone)]
+enum Step<'d, 'a> {
+    Pointer(&'d Pointer<'a>),
+    Array {
+        qualifiers: Option<&'d TypeQualifierList>,
+        size: Option<&'d Expression<'a>>,
+    },
+    Function(Option<&'d ParameterTypeList<'a>>),
+}
+impl<'d, 'a> From<Derivation<'d, 'a>> for Step<'d, 'a> {
+    fn from(derivation: Derivation<'d, 'a>) -> Self {
+        match derivation {
+            Derivation::Pointer(pointer) => Step::Pointer(pointer),
+            Derivation::Array(array) => Step::Array {
+                qualifiers: array.qualifiers.as_ref(),
+                size: match &array.kind {
+                    ArrayDeclaratorKind::Normal { size, .. } => size.as_ref(),
+                    ArrayDeclaratorKind::Var { .. } => None,
+                },
+            },
+            Derivation::Function(function) => Step::Function(function.parameters.as_ref()),
+        }
+    }
+}
+
+/// Pushes the derivations of an abstract declarator, closest to the omitted identifier first.
+fn abstract_steps<'d, 'a>(
+    steps: &mut Vec<Step<'d, 'a>>,
+    declarator: Option<&'d AbstractDeclarator<'a>>,
+) {
+    let Some(declarator) = declarator else {
+        return;
+    };
+    direct_abstract_steps(steps, declarator.direct.as_ref());
+    let levels = declarator.pointer.as_ref().map(Pointer::levels);
+    steps.extend(levels.into_iter().flatten().rev().map(Step::Pointer));
+}
+fn direct_abstract_steps<'d, 'a>(
+    steps: &mut Vec<Step<'d, 'a>>,
+    declarator: Option<&'d DirectAbstractDeclarator<'a>>,
+) {
+    let Some(declarator) = declarator else {
+        return;
+    };
+    match &declarator.kind {
+        DirectAbstractDeclaratorKind::Parenthesized { inner, .. } => {
+            abstract_steps(steps, Some(inner))
+        }
+        DirectAbstractDeclaratorKind::Array(array, _) => {
+            direct_abstract_steps(steps, array.left.as_deref());
+            steps.push(match &array.kind {
+                ArrayAbstractDeclaratorKind::Normal {
+                    qualifiers, size, ..
+                } => Step::Array {
+                    qualifiers: qualifiers.as_ref(),
+                    size: size.as_deref(),
+                },
+                ArrayAbstractDeclaratorKind::Var { .. } => Step::Array {
+                    qualifiers: None,
+                    size: None,
+                },
+            });
+        }
+        DirectAbstractDeclaratorKind::Function(function, _) => {
+            direct_abstract_steps(steps, function.left.as_deref());
+            steps.push(Step::Function(function.parameters.as_ref()));
+        }
+    }
+}
+
+/// Applies `steps`, given closest to the identifier first, to `ty`.
+fn derive<'a>(mut ty: Type<'a>, steps: &[Step<'_, 'a>]) -> Result<Type<'a>, TypeErr> {
+    for step in steps.iter().rev() {
+        ty = match *step {
+            Step::Pointer(pointer) => Type {
+                kind: TypeKind::Pointer(Box::new(ty)),
+                qualifiers: Qualifiers::from_list(pointer.qualifiers.as_ref()),
+            },
+            Step::Array { size, .. } => Type {
+                kind: TypeKind::Array {
+                    element: Box::new(ty),
+                    length: size.and_then(|size| {
+                        let value = Evaluator::new(EvalConfig::default()).evaluate(size).ok()?;
+                        u64::try_from(value.value).ok()
+                    }),
+                },
+                qualifiers: Qualifiers::default(),
+            },
+            Step::Function(parameters) => Type {
+                kind: TypeKind::Function {
+                    returns: Box::new(ty),
+                    parameters: parameter_types(parameters)?,
+                    is_variadic: parameters.is_some_and(|list| list.ellipses.is_some()),
+                },
+                qualifiers: Qualifiers::default(),
+            },
+        };
+    }
+    Ok(ty)
+}
+
+/// Returns the adjusted parameter types of a parameter list, which are empty for `(void)`
+/// and `None` for `()`.
+fn parameter_types<'a>(
+    list: Option<&ParameterTypeList<'a>>,
+) -> Result<Option<Vec<Type<'a>>>, TypeErr> {
+    let Some(list) = list else {
+        return Ok(None);
+    };
+    let Some((parameters, _)) = &list.parameters else {
+        return Ok(list.ellipses.map(|_| Vec::new()));
+    };
+    let types = parameters
+        .iter()
+        .map(parameter_adjusted_type)
+        .collect::<Result<Vec<_>, _>>()?;
+    let is_void = |ty: &Type| ty.kind == TypeKind::Base(BaseType::Void);
+    if let [only] = types.as_slice()
+        && is_void(only)
+    {
+        return Ok(Some(Vec::new()));
+    }
+    Ok(Some(types))
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Size {
+    Short,
+    Long,
+    LongLong,
+}
+
+/// The type specifiers seen so far, each with its position.
+#[derive(Default)]
+struct Specifiers<'a> {
+    base: Option<BaseType<'a>>,
+    unsigned: Option<(At, bool)>,
+    size: Option<(At, Size)>,
+    int: Option<At>,
+    complex: Option<At>,
+}
+impl<'a> Specifiers<'a> {
+    fn add(&mut self, specifier: &TypeSpecifier<'a>) -> Result<(), TypeErr> {
+        let at = specifier.at;
+        let duplicate = match &specifier.kind {
+            TypeSpecifierKind::Signed => self.unsigned.replace((at, false)).is_some(),
+            TypeSpecifierKind::Unsigned => self.unsigned.replace((at, true)).is_some(),
+            TypeSpecifierKind::Short => self.size.replace((at, Size::Short)).is_some(),
+            TypeSpecifierKind::Long => match self.size {
+                None => {
+                    self.size = Some((at, Size::Long));
+                    false
+                }
+                Some((_, Size::Long)) => {
+                    self.size = Some((at, Size::LongLong));
+                    false
+                }
+                Some(_) => true,
+            },
+            TypeSpecifierKind::Int => self.int.replace(at).is_some(),
+            TypeSpecifierKind::Complex => self.complex.replace(at).is_some(),
+            kind => self.base.replace(base_type(kind)).is_some(),
+        };
+
+        if duplicate {
+            return Err(TypeErr {
+                at,
+                kind: TypeErrKind::InvalidCombination,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the base type, or the position of a specifier that does not fit,
+    /// or `None` if there are no type specifiers at all.
+    fn resolve(self) -> Result<BaseType<'a>, Option<At>> {
+        let Specifiers {
+            base,
+            unsigned,
+            size,
+            int,
+            complex,
+        } = self;
+        let reject = |at: Option<At>| match at {
+            Some(at) => Err(Some(at)),
+            None => Ok(()),
+        };
+        let is_unsigned = unsigned.is_some_and(|(_, unsigned)| unsigned);
+
+        let Some(base) = base else {
+            reject(complex)?;
+            if unsigned.is_none() && size.is_none() && int.is_none() {
+                return Err(None);
+            }
+            return Ok(match (size.map(|(_, size)| size), is_unsigned) {
+                (Some(Size::Short), false) => BaseType::Short,
+                (Some(Size::Short), true) => BaseType::UnsignedShort,
+                (None, false) => BaseType::Int,
+                (None, true) => BaseType::UnsignedInt,
+                (Some(Size::Long), false) => BaseType::Long,
+                (Some(Size::Long), true) => BaseType::UnsignedLong,
+                (Some(Size::LongLong), false) => BaseType::LongLong,
+                (Some(Size::LongLong), true) => BaseType::UnsignedLongLong,
+            });
+        };
+        reject(int)?;
+
+        match base {
+            BaseType::Char => {
+                reject(size.map(|(at, _)| at).or(complex))?;
+                Ok(match unsigned {
+                    None => BaseType::Char,
+                    Some((_, false)) => BaseType::SignedChar,
+                    Some((_, true)) => BaseType::UnsignedChar,
+                })
+            }
+            BaseType::Int128 => {
+                reject(size.map(|(at, _)| at).or(complex))?;
+                Ok(if is_unsigned {
+                    BaseType::UnsignedInt128
+                } else {
+                    BaseType::Int128
+                })
+            }
+            BaseType::BitInt { width, .. } => {
+                reject(size.map(|(at, _)| at).or(complex))?;
+                Ok(BaseType::BitInt {
+                    unsigned: is_unsigned,
+                    width,
+                })
+            }
+            BaseType::Float => {
+                reject(unsigned.map(|(at, _)| at).or(size.map(|(at, _)| at)))?;
+                Ok(match complex {
+                    None => BaseType::Float,
+                    Some(_) => BaseType::ComplexFloat,
+                })
+            }
+            BaseType::Double => {
+                reject(unsigned.map(|(at, _)| at))?;
+                match (size, complex) {
+                    (None, None) => Ok(BaseType::Double),
+                    (None, Some(_)) => Ok(BaseType::ComplexDouble),
+                    (Some((_, Size::Long)), None) => Ok(BaseType::LongDouble),
+                    (Some((_, Size::Long)), Some(_)) => Ok(BaseType::ComplexLongDouble),
+                    (Some((at, _)), _) => Err(Some(at)),
+                }
+            }
+            base => {
+                let modifier = unsigned.map(|(at, _)| at).or(size.map(|(at, _)| at));
+                reject(modifier.or(complex))?;
+                Ok(base)
+            }
+        }
+    }
+}
+
+fn base_type<'a>(kind: &TypeSpecifierKind<'a>) -> BaseType<'a> {
+    match kind {
+        TypeSpecifierKind::Void => BaseType::Void,
+        TypeSpecifierKind::Bool => BaseType::Bool,
+        TypeSpecifierKind::Char => BaseType::Char,
+        TypeSpecifierKind::Float => BaseType::Float,
+        TypeSpecifierKind::Double => BaseType::Double,
+        TypeSpecifierKind::BitInt { width, .. } => BaseType::BitInt {
+            unsigned: false,
+            width: Evaluator::new(EvalConfig::default())
+                .evaluate(width)
+                .ok()
+                .and_then(|width| u32::try_from(width.value).ok()),
+        },
+        TypeSpecifierKind::Decimal32 => BaseType::Decimal32,
+        TypeSpecifierKind::Decimal64 => BaseType::Decimal64,
+        TypeSpecifierKind::Decimal128 => BaseType::Decimal128,
+        TypeSpecifierKind::Float16 => BaseType::Float16,
+        TypeSpecifierKind::Float32 => BaseType::Float32,
+        TypeSpecifierKind::Float64 => BaseType::Float64,
+        TypeSpecifierKind::Float128 => BaseType::Float128,
+        TypeSpecifierKind::Float32x => BaseType::Float32x,
+        TypeSpecifierKind::Float64x => BaseType::Float64x,
+        TypeSpecifierKind::Float128x => BaseType::Float128x,
+        TypeSpecifierKind::Int128 => BaseType::Int128,
+        TypeSpecifierKind::StructOrUnion(specifier) => {
+            let tag = match specifier.tag {
+                Some(name) => Tag::Named(name),
+                None => Tag::Anonymous(specifier.at),
+            };
+            match specifier.struct_or_union.1 {
+                StructOrUnion::Struct => BaseType::Struct(tag),
+                StructOrUnion::Union => BaseType::Union(tag),
+            }
+        }
+        TypeSpecifierKind::Enum(specifier) => BaseType::Enum(match specifier.tag {
+            Some(name) => Tag::Named(name),
+            None => Tag::Anonymous(specifier.at),
+        }),
+        TypeSpecifierKind::TypedefName(name) => BaseType::Typedef(name),
+        TypeSpecifierKind::Typeof(specifier) => BaseType::Typeof(specifier.at),
+        TypeSpecifierKind::Atomic(specifier) => BaseType::Atomic(specifier.at),
+        TypeSpecifierKind::Short
+        | TypeSpecifierKind::Int
+        | TypeSpecifierKind::Long
+        | TypeSpecifierKind::Signed
+        | TypeSpecifierKind::Unsigned
+        | TypeSpecifierKind::Complex => unreachable!("handled as a modifier"),
+    }
+}
+
+/// Infers the type of `expression` from the types of the symbols it refers to.
+///
+/// Returns `None` if the type depends on something the pass does not track, like
+/// the members of a structure, or if the expression is invalid. Arithmetic results
+/// are floating if either operand is, and otherwise the operand type of greater rank,
+/// which approximates the usual arithmetic conversions.
+pub fn expression_type<'a>(
+    expression: &Expression<'a>,
+    symbols: &SymbolTable<'a>,
+) -> Option<Type<'a>> {
+    use ExpressionKind::*;
+    let base = |base| {
+        Some(Type::from(QualifiedType {
+            base,
+            qualifiers: Qualifiers::default(),
+        }))
+    };
+    let operand = |expression| expression_type(expression, symbols).map(|ty| decay(&ty));
+
+    match &expression.kind {
+        Identifier(_) => symbols.resolve(expression.at)?.ty.clone(),
+        Integer(int) => match int.suffix {
+            Some(IntegerSuffix::BitPrecise | IntegerSuffix::BitPreciseUnsigned) => {
+                base(BaseType::BitInt {
+                    unsigned: int.suffix == Some(IntegerSuffix::BitPreciseUnsigned),
+                    width: None,
+                })
+            }
+            _ => base(literal_type(int, DataModel::default())?.into()),
+        },
+        Float(float) => base(match float.suffix {
+            None => BaseType::Double,
+            Some(FloatSuffix::Float) => BaseType::Float,
+            Some(FloatSuffix::LongDouble) => BaseType::LongDouble,
+            Some(FloatSuffix::Decimal32) => BaseType::Decimal32,
+            Some(FloatSuffix::Decimal64) => BaseType::Decimal64,
+            Some(FloatSuffix::Decimal128) => BaseType::Decimal128,
+        }),
+        String(string) => Some(Type {
+            kind: TypeKind::Array {
+                element: Box::new(base(character_type(string.encoding, true))?),
+                length: None,
+            },
+            qualifiers: Qualifiers::default(),
+        }),
+        Character(character) => base(character_type(character.encoding, false)),
+        Bool(_) => base(BaseType::Bool),
+        Parenthesized { inner, .. } => expression_type(inner, symbols),
+        Member { .. } | MemberIndirect { .. } => {
+            let (object, member) = member_access(expression, symbols)?;
+            let mut ty = member.ty.clone()?;
+            ty.qualifiers.is_const |= object.qualifiers.is_const;
+            ty.qualifiers.is_volatile |= object.qualifiers.is_volatile;
+            Some(ty)
+        }
+        Nullptr | GenericSelection(_) | StatementExpression { .. } => None,
+        Index { left, index, .. } => match (operand(left)?.kind, operand(index)?.kind) {
+            (TypeKind::Pointer(pointee), _) | (_, TypeKind::Pointer(pointee)) => Some(*pointee),
+            _ => None,
+        },
+        Call { left, .. } => match operand(left)?.kind {
+            TypeKind::Pointer(function) => match function.kind {
+                TypeKind::Function { returns, .. } => Some(*returns),
+                _ => None,
+            },
+            _ => None,
+        },
+        PostIncrement { left: operand, .. }
+        | PostDecrement { left: operand, .. }
+        | PreIncrement { right: operand, .. }
+        | PreDecrement { right: operand, .. } => expression_type(operand, symbols),
+        CompoundLiteral(literal) => type_name_type_in(&literal.type_name, symbols),
+        Unary(operator, right) => match operator {
+            UnaryOperator::AddressOf => Some(Type {
+                kind: TypeKind::Pointer(Box::new(expression_type(right, symbols)?)),
+                qualifiers: Qualifiers::default(),
+            }),
+            UnaryOperator::Dereference => match operand(right)?.kind {
+                TypeKind::Pointer(pointee) => Some(*pointee),
+                _ => None,
+            },
+            UnaryOperator::Positive | UnaryOperator::Negative | UnaryOperator::BitNot => {
+                match operand(right)?.kind {
+                    TypeKind::Base(right) => base(promote(right)),
+                    _ => None,
+                }
+            }
+            UnaryOperator::LogicalNot => base(BaseType::Int),
+        },
+        LabelAddress(_) => Some(Type {
+            kind: TypeKind::Pointer(Box::new(base(BaseType::Void)?)),
+            qualifiers: Qualifiers::default(),
+        }),
+        Sizeof { .. } | Alignof { .. } | Offsetof { .. } => base(BaseType::UnsignedLong),
+        TypesCompatible { .. } => base(BaseType::Int),
+        Cast { type_name, .. } => type_name_type_in(type_name, symbols),
+        Binary {
+            left,
+            operator: (_, operator),
+            right,
+        } => {
+            use BinaryOperator as B;
+            let (left, right) = (operand(left)?, operand(right)?);
+            match operator {
+                B::Less
+                | B::Greater
+                | B::LessEqual
+                | B::GreaterEqual
+                | B::Equal
+                | B::NotEqual
+                | B::LogicalAnd
+                | B::LogicalOr => base(BaseType::Int),
+                B::ShiftLeft | B::ShiftRight => match left.kind {
+                    TypeKind::Base(left) => base(promote(left)),
+                    _ => None,
+                },
+                _ => match (left.kind, right.kind) {
+                    (TypeKind::Base(left), TypeKind::Base(right)) => {
+                        base(usual_arithmetic_conversion(left, right))
+                    }
+                    (TypeKind::Pointer(_), TypeKind::Pointer(_)) => base(BaseType::Long),
+                    (TypeKind::Pointer(pointee), _) | (_, TypeKind::Pointer(pointee)) => {
+                        Some(Type {
+                            kind: TypeKind::Pointer(pointee),
+                            qualifiers: Qualifiers::default(),
+                        })
+                    }
+                    _ => None,
+                },
+            }
+        }
+        Conditional {
+            then_value,
+            else_value,
+            ..
+        } => expression_type(then_value, symbols).or_else(|| expression_type(else_value, symbols)),
+        Assign { left, .. } => expression_type(left, symbols),
+        Comma { right, .. } => expression_type(right, symbols),
+    }
+}
+/// Returns the type named by `type_name`, resolving its typedef name in `symbols`.
+fn type_name_type_in<'a>(type_name: &TypeName<'a>, symbols: &SymbolTable<'a>) -> Option<Type<'a>> {
+    let ty = type_name_type(type_name).ok()?;
+    let typedef = type_name
+        .specifier_qualifiers
+        .iter()
+        .find_map(|item| match &item.kind {
+            TypeSpecifierQualifierKind::TypeSpecifier(TypeSpecifier {
+                at,
+                kind: TypeSpecifierKind::TypedefName(_),
+            }) => symbols.resolve(*at),
+            _ => None,
+        });
+    Some(ty.resolve_typedefs(&|_| typedef?.ty.clone()))
+}
+/// Converts an array to a pointer to its first element, and a function to a pointer to it.
+pub fn decay<'a>(ty: &Type<'a>) -> Type<'a> {
+    let pointee = match &ty.kind {
+        TypeKind::Array { element, .. } => element.clone(),
+        TypeKind::Function { .. } => Box::new(ty.clone()),
+        _ => return ty.clone(),
+    };
+    Type {
+        kind: TypeKind::Pointer(pointee),
+        qualifiers: Qualifiers::default(),
+    }
+}
+fn character_type<'a>(encoding: StringEncoding, is_string: bool) -> BaseType<'a> {
+    match encoding {
+        StringEncoding::None if is_string => BaseType::Char,
+        StringEncoding::None | StringEncoding::Wide => BaseType::Int,
+        StringEncoding::UTF8 => BaseType::UnsignedChar,
+        StringEncoding::UTF16 => BaseType::UnsignedShort,
+        StringEncoding::UTF32 => BaseType::UnsignedInt,
+    }
+}
+/// Applies the integer promotions, which convert integers of lower rank than `int` to `int`.
+fn promote(ty: BaseType) -> BaseType {
+    match ty {
+        BaseType::Bool
+        | BaseType::Char
+        | BaseType::SignedChar
+        | BaseType::UnsignedChar
+        | BaseType::Short
+        | BaseType::UnsignedShort
+        | BaseType::Enum(_) => BaseType::Int,
+        ty => ty,
+    }
+}
+fn usual_arithmetic_conversion<'a>(left: BaseType<'a>, right: BaseType<'a>) -> BaseType<'a> {
+    if left.is_floating() {
+        return left;
+    } else if right.is_floating() {
+        return right;
+    }
+    let (left, right) = (promote(left), promote(right));
+    let rank = |ty: &BaseType| match ty {
+        BaseType::Long | BaseType::UnsignedLong => 1,
+        BaseType::LongLong | BaseType::UnsignedLongLong => 2,
+        BaseType::Int128 | BaseType::UnsignedInt128 => 3,
+        _ => 0,
+    };
+    if rank(&right) > rank(&left) {
+        right
+    } else {
+        left
+    }
+}
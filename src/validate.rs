@@ -0,0 +1,1075 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{
+    ast::*,
+    eval::{DataModel, EvalConfig, Evaluator, literal_type},
+    symbols::storage_class,
+    token::{At, IntegerSuffix, TokenKind},
+    types::{
+        BaseType, Tag, Type, TypeKind, canonicalize, declarator_type, parameter_adjusted_type,
+        type_name_type,
+    },
+    visit::{self, Visitor},
+};
+
+/// A constraint violation found in a syntactically valid translation unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationErr {
+    pub at: At,
+    pub kind: ValidationErrKind,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValidationErrKind {
+    /// The left operand of an assignment is not a modifiable lvalue.
+    AssignmentToRvalue,
+    /// The operand of `++` or `--` is not a modifiable lvalue.
+    IncrementOfRvalue,
+    /// A storage-class specifier may not be combined with an earlier one.
+    ConflictingStorageClasses,
+    /// A storage-class specifier is not permitted in this kind of declaration,
+    /// or in a compound literal.
+    InvalidStorageClass,
+    /// `thread_local` at block scope lacks `static` or `extern`.
+    ThreadLocalAtBlockScope,
+    /// A flexible array member is followed by another member.
+    FlexibleArrayNotLast,
+    /// A flexible array member is the only named member of its struct, counting
+    /// the members of anonymous structures and unions.
+    FlexibleArrayOnlyMember,
+    /// A flexible array member is declared in a union.
+    FlexibleArrayInUnion,
+    /// `break` appears outside any loop or `switch`.
+    BreakOutsideLoopOrSwitch,
+    /// `continue` appears outside any loop.
+    ContinueOutsideLoop,
+    /// A `case` or `default` label appears outside any `switch`.
+    CaseOutsideSwitch,
+    /// The low value of a GNU case range is greater than its high value.
+    EmptyCaseRange,
+    /// A braced initializer has more elements than the object it initializes.
+    ExcessInitializer,
+    /// A designator names a member the structure or union does not have.
+    UnknownMember,
+    /// An array designator is negative or not less than the length of the array.
+    DesignatorOutOfBounds,
+    /// An alignment specifier's value is negative or not a power of two.
+    InvalidAlignment,
+    /// An alignment specifier is less strict than the declared type requires.
+    AlignmentTooWeak,
+    /// An alignment specifier appears in a typedef or on a bit-field.
+    AlignmentNotPermitted,
+    /// `inline` or `_Noreturn` appears in a declaration of something other than
+    /// a function, such as a function pointer.
+    FunctionSpecifierOnObject,
+    /// An integer literal is too large for every type its suffix and base permit.
+    IntegerLiteralTooLarge,
+    /// An identifier is redeclared in the same scope with an incompatible type.
+    ConflictingTypes,
+    /// An identifier is declared in the same scope both as a typedef name and as
+    /// an object, function or parameter.
+    ConflictingKinds,
+    /// An enumeration has a fixed underlying type but no enumerator list outside
+    /// a declaration of the form `enum E : T;`.
+    EnumTypeWithoutEnumerators,
+    /// The fixed underlying type of an enumeration is not an integer type, or is
+    /// an enumeration or bit-precise integer type.
+    InvalidEnumType,
+    /// A standard attribute has an argument clause it does not accept, such as
+    /// `[[maybe_unused(x)]]`, or one that is not a string literal for `deprecated`
+    /// or `nodiscard`.
+    InvalidAttributeArguments,
+    /// Two associations of a generic selection specify compatible types, or
+    /// both are `default`.
+    DuplicateGenericAssociation,
+    /// A declaration has no declarators and declares no tag or enumeration
+    /// constant, such as `int;` or `struct { int x; };`.
+    UselessDeclaration,
+    /// A typedef name is redefined in the same scope to denote a different type.
+    TypedefRedefinition,
+}
+
+pub fn validate(unit: &TranslationUnit) -> Vec<ValidationErr> {
+    let mut validator = Validator {
+        errors: Vec::new(),
+        depth: 0,
+        loops: 0,
+        switches: 0,
+        records: vec![HashMap::new()],
+        declarations: vec![HashMap::new()],
+        typedef_scopes: vec![HashMap::new()],
+        function_body: None,
+        forward_enum: None,
+    };
+    validator.visit_translation_unit(unit);
+    validator.errors
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum DeclarationContext {
+    File,
+    Block,
+    FunctionDefinition,
+    Parameter,
+}
+
+struct Validator<'a> {
+    errors: Vec<ValidationErr>,
+    /// The block nesting depth; zero at file scope.
+    depth: usize,
+    /// The number of enclosing loops and `switch` statements. A GNU statement
+    /// expression does not reset these, as jumps out of it are permitted.
+    loops: usize,
+    switches: usize,
+    /// The members of the structures and unions defined in each enclosing scope,
+    /// innermost last.
+    records: Vec<HashMap<Tag<'a>, Vec<Member<'a>>>>,
+    /// The types of the objects and functions declared in each enclosing scope,
+    /// innermost last, with typedef names resolved.
+    declarations: Vec<HashMap<&'a str, Type<'a>>>,
+    /// The types of the typedef names declared in each enclosing scope,
+    /// innermost last, with typedef names resolved.
+    typedef_scopes: Vec<HashMap<&'a str, Type<'a>>>,
+    /// The position of the body of the function definition being visited, whose
+    /// outermost block shares the scope of the parameters.
+    function_body: Option<At>,
+    /// The enum specifier of the declaration `enum E : T;` being visited, if any.
+    forward_enum: Option<At>,
+}
+impl<'a> Validator<'a> {
+    fn err(&mut self, at: At, kind: ValidationErrKind) {
+        self.errors.push(ValidationErr { at, kind });
+    }
+
+    fn check_storage_classes(
+        &mut self,
+        specifiers: &DeclarationSpecifiers,
+        context: DeclarationContext,
+    ) {
+        use StorageClassSpecifierKind::*;
+
+        let storage_classes: Vec<_> = specifiers
+            .iter()
+            .filter_map(|s| match &s.kind {
+                DeclarationSpecifierKind::StorageClass(s) => Some(s),
+                _ => None,
+            })
+            .collect();
+        let has_type_specifier = specifiers.iter().any(|s| {
+            matches!(
+                &s.kind,
+                DeclarationSpecifierKind::Type(TypeSpecifierQualifier {
+                    kind: TypeSpecifierQualifierKind::TypeSpecifier(_),
+                    ..
+                })
+            )
+        });
+
+        for (i, specifier) in storage_classes.iter().enumerate() {
+            let conflicts = storage_classes[..i].iter().any(|earlier| {
+                !matches!(
+                    (earlier.kind, specifier.kind),
+                    (ThreadLocal, Static | Extern)
+                        | (Static | Extern, ThreadLocal)
+                        | (Constexpr, Auto | Register | Static)
+                        | (Auto | Register | Static, Constexpr)
+                )
+            });
+            if conflicts {
+                self.err(specifier.at, ValidationErrKind::ConflictingStorageClasses);
+                continue;
+            }
+
+            let permitted = match context {
+                // `auto` is only permitted at file scope when it infers the type.
+                DeclarationContext::File => match specifier.kind {
+                    Register => false,
+                    Auto => !has_type_specifier,
+                    _ => true,
+                },
+                DeclarationContext::Block => true,
+                DeclarationContext::FunctionDefinition => matches!(specifier.kind, Extern | Static),
+                DeclarationContext::Parameter => specifier.kind == Register,
+            };
+            if !permitted {
+                self.err(specifier.at, ValidationErrKind::InvalidStorageClass);
+            } else if context == DeclarationContext::Block
+                && specifier.kind == ThreadLocal
+                && !storage_classes
+                    .iter()
+                    .any(|s| matches!(s.kind, Static | Extern))
+            {
+                self.err(specifier.at, ValidationErrKind::ThreadLocalAtBlockScope);
+            }
+        }
+    }
+    fn check_function_specifiers(
+        &mut self,
+        specifiers: &DeclarationSpecifiers,
+        declares_only_functions: bool,
+    ) {
+        if declares_only_functions {
+            return;
+        }
+        for specifier in specifiers.iter() {
+            if let DeclarationSpecifierKind::Function(function) = &specifier.kind {
+                self.err(function.at, ValidationErrKind::FunctionSpecifierOnObject);
+            }
+        }
+    }
+    fn check_useless_declaration(&mut self, at: At, specifiers: &DeclarationSpecifiers) {
+        let declares_something = specifiers.iter().any(|s| match &s.kind {
+            DeclarationSpecifierKind::Type(TypeSpecifierQualifier {
+                kind: TypeSpecifierQualifierKind::TypeSpecifier(specifier),
+                ..
+            }) => match &specifier.kind {
+                TypeSpecifierKind::StructOrUnion(specifier) => specifier.tag.is_some(),
+                TypeSpecifierKind::Enum(specifier) => {
+                    specifier.tag.is_some() || specifier.enumerators.is_some()
+                }
+                _ => false,
+            },
+            _ => false,
+        });
+        if !declares_something {
+            self.err(at, ValidationErrKind::UselessDeclaration);
+        }
+    }
+    fn record_members(&mut self, specifier: &StructOrUnionSpecifier<'a>) {
+        let Some((_, declarations, _)) = &specifier.members else {
+            return;
+        };
+        let mut members = Vec::new();
+        for declaration in declarations.iter() {
+            let MemberDeclarationKind::Member {
+                specifier_qualifiers,
+                member_declarators,
+                ..
+            } = &declaration.kind
+            else {
+                continue;
+            };
+            let Some(declarators) = member_declarators else {
+                // An anonymous structure or union.
+                let Ok(ty) = canonicalize(specifier_qualifiers.iter()) else {
+                    return;
+                };
+                members.push(Member {
+                    name: None,
+                    ty: ty.into(),
+                });
+                continue;
+            };
+            // Unnamed bit-fields are not members and take no initializer.
+            for declarator in declarators.iter().filter_map(|d| d.declarator.as_ref()) {
+                let Ok(ty) = declarator_type(specifier_qualifiers.iter(), declarator) else {
+                    return;
+                };
+                members.push(Member {
+                    name: Some(declarator.name().0),
+                    ty,
+                });
+            }
+        }
+
+        let tag = match specifier.tag {
+            Some(name) => Tag::Named(name),
+            None => Tag::Anonymous(specifier.at),
+        };
+        self.records.last_mut().unwrap().insert(tag, members);
+    }
+    /// Checks an initializer against the shape of `ty`, and completes an array
+    /// of unknown length with the length the initializer gives it.
+    fn check_initializer(&mut self, ty: &mut Type<'a>, initializer: &Initializer<'a>) {
+        let mut checker = InitializerChecker {
+            records: &self.records,
+            typedefs: &self.typedef_scopes,
+            errors: Vec::new(),
+        };
+        let inferred = match &initializer.kind {
+            InitializerKind::Braced(braced) => checker.check_braced(ty, braced),
+            InitializerKind::Expression(expression) => checker.string_length(ty, expression),
+        };
+        self.errors.extend(checker.errors);
+        if let TypeKind::Array { length: None, .. } = ty.kind
+            && let Some(inferred) = inferred
+        {
+            let TypeKind::Array { length, .. } = &mut ty.kind else {
+                unreachable!()
+            };
+            *length = Some(inferred);
+        }
+    }
+    fn check_enum_type(&mut self, enum_type: &EnumTypeSpecifier<'a>) {
+        let Ok(ty) = canonicalize(enum_type.specifier_qualifiers.iter()) else {
+            return;
+        };
+        let ty = self.resolve(&ty.into());
+        let TypeKind::Base(base) = ty.kind else {
+            self.err(enum_type.at, ValidationErrKind::InvalidEnumType);
+            return;
+        };
+        use BaseType::*;
+        match base {
+            Bool | Char | SignedChar | UnsignedChar | Short | UnsignedShort | Int | UnsignedInt
+            | Long | UnsignedLong | LongLong | UnsignedLongLong | Int128 | UnsignedInt128 => (),
+            // Unresolved types are given the benefit of the doubt.
+            Typedef(_) | Typeof(_) | Atomic(_) => (),
+            _ => self.err(enum_type.at, ValidationErrKind::InvalidEnumType),
+        }
+    }
+    fn check_redeclaration(&mut self, ty: &Type<'a>, declarator: &Declarator<'a>) {
+        let ty = self.resolve(ty);
+        let (name, at) = declarator.name();
+        if self.typedef_scopes.last().unwrap().contains_key(name) {
+            self.err(at, ValidationErrKind::ConflictingKinds);
+            return;
+        }
+        let scope = self.declarations.last_mut().unwrap();
+        match scope.get(name) {
+            Some(previous) if !previous.is_compatible(&ty) => {
+                self.err(at, ValidationErrKind::ConflictingTypes);
+            }
+            Some(_) => (),
+            None => {
+                scope.insert(name, ty);
+            }
+        }
+    }
+    fn check_typedef_redefinition(&mut self, ty: &Type<'a>, declarator: &Declarator<'a>) {
+        let ty = self.resolve(ty);
+        let (name, at) = declarator.name();
+        if self.declarations.last().unwrap().contains_key(name) {
+            self.err(at, ValidationErrKind::ConflictingKinds);
+            return;
+        }
+        let scope = self.typedef_scopes.last_mut().unwrap();
+        match scope.get(name) {
+            // Only the same type may be named again, not merely a compatible one.
+            Some(previous)
+                if *previous != ty && !is_unresolved(previous) && !is_unresolved(&ty) =>
+            {
+                self.err(at, ValidationErrKind::TypedefRedefinition);
+            }
+            Some(_) => (),
+            None => {
+                scope.insert(name, ty);
+            }
+        }
+    }
+    fn push_scope(&mut self) {
+        self.declarations.push(HashMap::new());
+        self.typedef_scopes.push(HashMap::new());
+        self.records.push(HashMap::new());
+    }
+    fn pop_scope(&mut self) {
+        self.records.pop();
+        self.typedef_scopes.pop();
+        self.declarations.pop();
+    }
+    /// Replaces the typedef names in `ty` with the types they name.
+    fn resolve(&self, ty: &Type<'a>) -> Type<'a> {
+        ty.resolve_typedefs(&|name| lookup(&self.typedef_scopes, &name).cloned())
+    }
+    fn check_member_alignments(&mut self, specifier: &StructOrUnionSpecifier<'a>) {
+        let Some((_, declarations, _)) = &specifier.members else {
+            return;
+        };
+        for declaration in declarations.iter() {
+            if let MemberDeclarationKind::Member {
+                specifier_qualifiers,
+                member_declarators: Some(declarators),
+                ..
+            } = &declaration.kind
+            {
+                let items: Vec<_> = specifier_qualifiers.iter().collect();
+                for member in declarators.iter() {
+                    match (&member.declarator, &member.width) {
+                        (_, Some(_)) => self.reject_alignment(&items),
+                        (Some(declarator), None) => self.check_alignment(&items, declarator),
+                        (None, None) => (),
+                    }
+                }
+            }
+        }
+    }
+    /// Reports every alignment specifier among `items`, which declare something
+    /// that cannot be aligned.
+    fn reject_alignment(&mut self, items: &[&TypeSpecifierQualifier<'a>]) {
+        for item in items {
+            if let TypeSpecifierQualifierKind::Alignment(specifier) = &item.kind {
+                self.err(specifier.at, ValidationErrKind::AlignmentNotPermitted);
+            }
+        }
+    }
+    /// Checks the alignment specifiers among `items` against the type `declarator` declares.
+    fn check_alignment(
+        &mut self,
+        items: &[&TypeSpecifierQualifier<'a>],
+        declarator: &Declarator<'a>,
+    ) {
+        let mut natural = None;
+        for item in items {
+            let TypeSpecifierQualifierKind::Alignment(specifier) = &item.kind else {
+                continue;
+            };
+            let alignment = match &specifier.kind {
+                AlignmentSpecifierKind::Expression(expression) => {
+                    let evaluator = Evaluator::new(EvalConfig::default());
+                    let Ok(value) = evaluator.evaluate(expression) else {
+                        continue;
+                    };
+                    // An alignment of zero has no effect.
+                    if value.value == 0 {
+                        continue;
+                    }
+                    match u64::try_from(value.value) {
+                        Ok(alignment) if alignment.is_power_of_two() => alignment,
+                        _ => {
+                            self.err(expression.at, ValidationErrKind::InvalidAlignment);
+                            continue;
+                        }
+                    }
+                }
+                AlignmentSpecifierKind::Type(type_name) => {
+                    let Some(alignment) = type_name_type(type_name)
+                        .ok()
+                        .and_then(|ty| self.alignment(&ty))
+                    else {
+                        continue;
+                    };
+                    alignment
+                }
+            };
+
+            if natural.is_none() {
+                natural = Some(
+                    declarator_type(items.iter().copied(), declarator)
+                        .ok()
+                        .and_then(|ty| self.alignment(&ty)),
+                );
+            }
+            if let Some(Some(natural)) = natural
+                && alignment < natural
+            {
+                self.err(specifier.at, ValidationErrKind::AlignmentTooWeak);
+            }
+        }
+    }
+    /// Returns the alignment of `ty` in bytes, if it is known.
+    fn alignment(&self, ty: &Type<'a>) -> Option<u64> {
+        let data_model = DataModel::default();
+        match &ty.kind {
+            TypeKind::Base(BaseType::Struct(tag) | BaseType::Union(tag)) => {
+                lookup(&self.records, tag)?
+                    .iter()
+                    .map(|member| self.alignment(&member.ty))
+                    .try_fold(1, |max, alignment| Some(max.max(alignment?)))
+            }
+            TypeKind::Base(BaseType::Typedef(name)) => {
+                self.alignment(lookup(&self.typedef_scopes, name)?)
+            }
+            TypeKind::Base(base) => base.alignment(data_model),
+            TypeKind::Pointer(_) => Some(u64::from(data_model.pointer_width() / 8)),
+            TypeKind::Array { element, .. } => self.alignment(element),
+            TypeKind::Function { .. } => None,
+        }
+    }
+    fn check_flexible_array_members(&mut self, specifier: &StructOrUnionSpecifier) {
+        let Some((_, members, _)) = &specifier.members else {
+            return;
+        };
+        // Anonymous structures and unions contribute their members, so they count
+        // as named members; unnamed bit-fields do not.
+        let mut declarators = Vec::new();
+        for member in members.iter() {
+            match &member.kind {
+                MemberDeclarationKind::Member {
+                    member_declarators: Some(member_declarators),
+                    ..
+                } => declarators.extend(
+                    member_declarators
+                        .iter()
+                        .filter_map(|d| d.declarator.as_ref())
+                        .map(Some),
+                ),
+                MemberDeclarationKind::Member {
+                    member_declarators: None,
+                    ..
+                } => declarators.push(None),
+                MemberDeclarationKind::Assert(_) => (),
+            }
+        }
+
+        for (i, declarator) in declarators.iter().enumerate() {
+            let Some(declarator) = declarator.filter(|d| is_flexible_array(d)) else {
+                continue;
+            };
+
+            let at = declarator.name().1;
+            if specifier.struct_or_union.1 == StructOrUnion::Union {
+                self.err(at, ValidationErrKind::FlexibleArrayInUnion);
+            } else if i + 1 != declarators.len() {
+                self.err(at, ValidationErrKind::FlexibleArrayNotLast);
+            } else if declarators.len() == 1 {
+                self.err(at, ValidationErrKind::FlexibleArrayOnlyMember);
+            }
+        }
+    }
+    fn check_generic_associations(&mut self, generic: &GenericSelection<'a>) {
+        let mut types: Vec<Type<'a>> = Vec::new();
+        let mut has_default = false;
+        for association in generic.generic_assocs.iter() {
+            let type_name = match &association.kind {
+                GenericAssociationKind::Default { default_keyword } => {
+                    if std::mem::replace(&mut has_default, true) {
+                        self.err(
+                            *default_keyword,
+                            ValidationErrKind::DuplicateGenericAssociation,
+                        );
+                    }
+                    continue;
+                }
+                GenericAssociationKind::ForType(type_name) => type_name,
+            };
+            let Ok(ty) = type_name_type(type_name) else {
+                continue;
+            };
+            let ty = self.resolve(&ty);
+            // Types the pass does not resolve are compatible with any other.
+            if is_unresolved(&ty) {
+                continue;
+            }
+            if types.iter().any(|other| other.is_compatible(&ty)) {
+                self.err(type_name.at, ValidationErrKind::DuplicateGenericAssociation);
+            } else {
+                types.push(ty);
+            }
+        }
+    }
+    /// Checks the argument clause of a standard attribute. Other attributes,
+    /// including prefixed ones, may take any arguments.
+    fn check_attribute_arguments(&mut self, attribute: &Attribute<'a>) {
+        let AttributeToken {
+            prefix: None,
+            token,
+            ..
+        } = attribute.token
+        else {
+            return;
+        };
+        // Each standard attribute may also be spelled `__name__`.
+        let name = token
+            .strip_prefix("__")
+            .and_then(|name| name.strip_suffix("__"))
+            .unwrap_or(token);
+        let takes_message = match name {
+            "deprecated" | "nodiscard" => true,
+            "fallthrough" | "maybe_unused" | "noreturn" | "reproducible" | "unsequenced" => false,
+            _ => return,
+        };
+
+        let Some(clause) = &attribute.argument_clause else {
+            return;
+        };
+        let is_message = clause.tokens.as_ref().is_some_and(|tokens| {
+            tokens
+                .iter()
+                .all(|token| matches!(token.kind, BalancedTokenKind::Token(TokenKind::String(..))))
+        });
+        if !takes_message || !is_message {
+            self.err(clause.at, ValidationErrKind::InvalidAttributeArguments);
+        }
+    }
+}
+impl<'a> Visitor<'a> for Validator<'a> {
+    fn visit_struct_or_union_specifier(&mut self, specifier: &StructOrUnionSpecifier<'a>) {
+        self.check_flexible_array_members(specifier);
+        self.record_members(specifier);
+        visit::walk_struct_or_union_specifier(self, specifier);
+        self.check_member_alignments(specifier);
+    }
+    fn visit_enum_specifier(&mut self, specifier: &EnumSpecifier<'a>) {
+        if let Some(enum_type) = &specifier.enum_type {
+            if specifier.enumerators.is_none() && self.forward_enum != Some(specifier.at) {
+                self.err(enum_type.at, ValidationErrKind::EnumTypeWithoutEnumerators);
+            }
+            self.check_enum_type(enum_type);
+        }
+        visit::walk_enum_specifier(self, specifier);
+    }
+    fn visit_function_definition(&mut self, function: &FunctionDefinition<'a>) {
+        self.check_storage_classes(&function.specifiers, DeclarationContext::FunctionDefinition);
+        let specifiers = function.specifiers.type_specifier_qualifiers();
+        if let Ok(ty) = declarator_type(specifiers, &function.declarator) {
+            self.check_redeclaration(&ty, &function.declarator);
+        }
+
+        // The parameters are declared in the outermost block of the body.
+        self.push_scope();
+        let list = function
+            .declarator
+            .function()
+            .and_then(|f| f.parameters.as_ref());
+        if let Some((parameters, _)) = list.and_then(|l| l.parameters.as_ref()) {
+            for parameter in parameters.iter() {
+                if let ParameterDeclarationKind::Concrete(declarator) = &parameter.kind
+                    && let Ok(ty) = parameter_adjusted_type(parameter)
+                {
+                    self.check_redeclaration(&ty, declarator);
+                }
+            }
+        }
+        self.function_body = Some(function.body.at);
+        visit::walk_function_definition(self, function);
+        self.pop_scope();
+    }
+    fn visit_declaration(&mut self, declaration: &Declaration<'a>) {
+        if let DeclarationKind::Normal {
+            specifiers,
+            init_declarators,
+            ..
+        } = &declaration.kind
+        {
+            let context = if self.depth == 0 {
+                DeclarationContext::File
+            } else {
+                DeclarationContext::Block
+            };
+            self.check_storage_classes(specifiers, context);
+
+            let declares_only_functions = init_declarators
+                .as_ref()
+                .is_some_and(|list| list.iter().all(|d| d.declarator.function().is_some()));
+            self.check_function_specifiers(specifiers, declares_only_functions);
+            if init_declarators.is_none() {
+                self.check_useless_declaration(declaration.at, specifiers);
+            }
+
+            if init_declarators.is_none()
+                && let DeclarationSpecifiersKind::Leaf(_) = specifiers.kind
+                && let DeclarationSpecifierKind::Type(TypeSpecifierQualifier {
+                    kind: TypeSpecifierQualifierKind::TypeSpecifier(specifier),
+                    ..
+                }) = &specifiers.specifier.kind
+                && let TypeSpecifierKind::Enum(specifier) = &specifier.kind
+            {
+                self.forward_enum = Some(specifier.at);
+            }
+        }
+        visit::walk_declaration(self, declaration);
+        self.forward_enum = None;
+
+        // Done after the walk, so that types defined by the specifiers are known.
+        if let DeclarationKind::Normal {
+            specifiers,
+            init_declarators: Some(init_declarators),
+            ..
+        } = &declaration.kind
+        {
+            let is_typedef = storage_class(specifiers) == Some(StorageClassSpecifierKind::Typedef);
+            let items: Vec<_> = specifiers.type_specifier_qualifiers().collect();
+            if is_typedef {
+                self.reject_alignment(&items);
+            }
+            for init_declarator in init_declarators.iter() {
+                let declarator = &init_declarator.declarator;
+                if !is_typedef {
+                    self.check_alignment(&items, declarator);
+                }
+                let Ok(mut ty) = declarator_type(items.iter().copied(), declarator) else {
+                    continue;
+                };
+                if is_typedef {
+                    self.check_typedef_redefinition(&ty, declarator);
+                    continue;
+                }
+                if let Some((_, initializer)) = &init_declarator.initializer {
+                    self.check_initializer(&mut ty, initializer);
+                }
+                self.check_redeclaration(&ty, declarator);
+            }
+        }
+    }
+    fn visit_parameter_declaration(&mut self, parameter: &ParameterDeclaration<'a>) {
+        self.check_storage_classes(&parameter.specifiers, DeclarationContext::Parameter);
+        // A parameter declared as a function is adjusted to a function pointer.
+        self.check_function_specifiers(&parameter.specifiers, false);
+        visit::walk_parameter_declaration(self, parameter);
+    }
+    fn visit_compound_statement(&mut self, statement: &CompoundStatement<'a>) {
+        self.depth += 1;
+        let is_function_body = self.function_body == Some(statement.at);
+        if is_function_body {
+            self.function_body = None;
+        } else {
+            self.push_scope();
+        }
+        visit::walk_compound_statement(self, statement);
+        if !is_function_body {
+            self.pop_scope();
+        }
+        self.depth -= 1;
+    }
+    fn visit_selection_statement(&mut self, statement: &SelectionStatement<'a>) {
+        let is_switch = matches!(statement.kind, SelectionStatementKind::Switch { .. });
+        self.switches += is_switch as usize;
+        visit::walk_selection_statement(self, statement);
+        self.switches -= is_switch as usize;
+    }
+    fn visit_iteration_statement(&mut self, statement: &IterationStatement<'a>) {
+        self.loops += 1;
+        // The declaration in a `for` clause has a scope of its own.
+        self.push_scope();
+        visit::walk_iteration_statement(self, statement);
+        self.pop_scope();
+        self.loops -= 1;
+    }
+    fn visit_jump_statement(&mut self, statement: &JumpStatement<'a>) {
+        match statement.kind {
+            JumpStatementKind::Break { break_keyword } if self.loops + self.switches == 0 => {
+                self.err(break_keyword, ValidationErrKind::BreakOutsideLoopOrSwitch)
+            }
+            JumpStatementKind::Continue { continue_keyword } if self.loops == 0 => {
+                self.err(continue_keyword, ValidationErrKind::ContinueOutsideLoop)
+            }
+            _ => (),
+        }
+        visit::walk_jump_statement(self, statement);
+    }
+    fn visit_label(&mut self, label: &Label<'a>) {
+        if !matches!(label.kind, LabelKind::Name(_)) && self.switches == 0 {
+            self.err(label.at, ValidationErrKind::CaseOutsideSwitch);
+        }
+        if let LabelKind::CaseRange {
+            low,
+            ellipses,
+            high,
+            ..
+        } = &label.kind
+        {
+            let evaluator = Evaluator::new(EvalConfig::default());
+            if let (Ok(low), Ok(high)) = (evaluator.evaluate(low), evaluator.evaluate(high))
+                && low.value > high.value
+            {
+                self.err(*ellipses, ValidationErrKind::EmptyCaseRange);
+            }
+        }
+        visit::walk_label(self, label);
+    }
+    fn visit_expression(&mut self, expression: &Expression<'a>) {
+        if let ExpressionKind::Assign { left, .. } = &expression.kind
+            && !is_lvalue(left)
+        {
+            self.err(left.at, ValidationErrKind::AssignmentToRvalue);
+        }
+        if let ExpressionKind::PostIncrement { left: operand, .. }
+        | ExpressionKind::PostDecrement { left: operand, .. }
+        | ExpressionKind::PreIncrement { right: operand, .. }
+        | ExpressionKind::PreDecrement { right: operand, .. } = &expression.kind
+            && !is_lvalue(operand)
+        {
+            self.err(operand.at, ValidationErrKind::IncrementOfRvalue);
+        }
+        // The largest permitted type is 64 bits wide in every data model.
+        if let ExpressionKind::Integer(int) = &expression.kind
+            && !matches!(
+                int.suffix,
+                Some(IntegerSuffix::BitPrecise | IntegerSuffix::BitPreciseUnsigned)
+            )
+            && literal_type(int, DataModel::default()).is_none()
+        {
+            self.err(expression.at, ValidationErrKind::IntegerLiteralTooLarge);
+        }
+        if let ExpressionKind::GenericSelection(generic) = &expression.kind {
+            self.check_generic_associations(generic);
+        }
+        if let ExpressionKind::CompoundLiteral(CompoundLiteral {
+            storage_class: Some(storage_classes),
+            ..
+        }) = &expression.kind
+        {
+            use StorageClassSpecifierKind::*;
+            for specifier in storage_classes.iter() {
+                if !matches!(specifier.kind, Constexpr | Static | Register | ThreadLocal) {
+                    self.err(specifier.at, ValidationErrKind::InvalidStorageClass);
+                }
+            }
+        }
+        visit::walk_expression(self, expression);
+    }
+    fn visit_attribute(&mut self, attribute: &Attribute<'a>) {
+        self.check_attribute_arguments(attribute);
+        visit::walk_attribute(self, attribute);
+    }
+}
+
+/// Looks `key` up in `scopes`, innermost last, returning the innermost entry.
+fn lookup<'m, K: Eq + Hash, V>(scopes: &'m [HashMap<K, V>], key: &K) -> Option<&'m V> {
+    scopes.iter().rev().find_map(|scope| scope.get(key))
+}
+
+struct Member<'a> {
+    /// The name, or `None` for an anonymous structure or union.
+    name: Option<&'a str>,
+    ty: Type<'a>,
+}
+
+/// Checks braced initializers against the shape of the object they initialize.
+///
+/// A list whose shape cannot be followed, such as one for an incomplete type,
+/// is not checked any further.
+struct InitializerChecker<'v, 'a> {
+    records: &'v [HashMap<Tag<'a>, Vec<Member<'a>>>],
+    typedefs: &'v [HashMap<&'a str, Type<'a>>],
+    errors: Vec<ValidationErr>,
+}
+impl<'v, 'a> InitializerChecker<'v, 'a> {
+    /// Returns the number of elements or members the initializer covers in the
+    /// outermost object, or `None` if it could not be followed to the end.
+    fn check_braced(&mut self, ty: &'v Type<'a>, braced: &BracedInitializer<'a>) -> Option<u64> {
+        let Some((initializers, _)) = &braced.initializers else {
+            return Some(0);
+        };
+        // A string literal, optionally enclosed in braces, initializes a whole
+        // character array.
+        if let [(None, initializer)] = initializers.iter().collect::<Vec<_>>()[..]
+            && let InitializerKind::Expression(expression) = &initializer.kind
+            && let Some(length) = self.string_length(ty, expression)
+        {
+            return Some(length);
+        }
+        let shape = self.shape(ty)?;
+        // The subobjects entered so far, the outermost being the object itself.
+        let mut stack = vec![Frame { shape, index: 0 }];
+        let mut covered = 0;
+
+        for (designation, initializer) in initializers.iter() {
+            if let Some(designation) = designation {
+                stack.truncate(1);
+                self.designate(&mut stack, designation)?;
+            } else {
+                while stack.last().unwrap().is_full() {
+                    if stack.len() == 1 {
+                        self.err(initializer.at, ValidationErrKind::ExcessInitializer);
+                        return None;
+                    }
+                    stack.pop();
+                    stack.last_mut().unwrap().advance();
+                }
+            }
+            covered = covered.max(stack[0].index + 1);
+
+            match &initializer.kind {
+                InitializerKind::Braced(braced) => {
+                    self.check_braced(stack.last().unwrap().slot(), braced);
+                }
+                // Without braces, the expression initializes the first scalar
+                // of the subobject, and the following ones continue from there.
+                InitializerKind::Expression(expression) => loop {
+                    let slot = stack.last().unwrap().slot();
+                    if string_literal(expression).is_some() && self.is_character_array(slot) {
+                        break;
+                    }
+                    match self.shape(slot)? {
+                        Shape::Scalar(_) => break,
+                        shape => stack.push(Frame { shape, index: 0 }),
+                    }
+                    if stack.last().unwrap().is_full() {
+                        return None;
+                    }
+                },
+            }
+            stack.last_mut().unwrap().advance();
+        }
+        Some(covered)
+    }
+    /// Moves to the subobject `designation` names, or returns `None` if it cannot be followed.
+    fn designate(
+        &mut self,
+        stack: &mut Vec<Frame<'v, 'a>>,
+        designation: &Designation<'a>,
+    ) -> Option<()> {
+        let designators: Vec<_> = designation.designators.iter().collect();
+        for (i, designator) in designators.iter().enumerate() {
+            let frame = stack.last_mut().unwrap();
+            frame.index = match (&designator.kind, &frame.shape) {
+                (DesignatorKind::InBrackets { value, .. }, Shape::Array { length, .. }) => {
+                    let value = Evaluator::new(EvalConfig::default()).evaluate(value).ok()?;
+                    match u64::try_from(value.value) {
+                        Ok(index) if length.is_none_or(|length| index < length) => index,
+                        _ => {
+                            self.err(designator.at, ValidationErrKind::DesignatorOutOfBounds);
+                            return None;
+                        }
+                    }
+                }
+                (DesignatorKind::AfterPeriod { name, .. }, Shape::Record { members, .. }) => {
+                    match members.iter().position(|m| m.name == Some(name)) {
+                        Some(index) => index as u64,
+                        // The name may belong to a member of an anonymous member.
+                        None if members.iter().any(|m| m.name.is_none()) => return None,
+                        None => {
+                            self.err(designator.at, ValidationErrKind::UnknownMember);
+                            return None;
+                        }
+                    }
+                }
+                _ => return None,
+            };
+
+            if i + 1 < designators.len() {
+                let shape = self.shape(frame.slot())?;
+                if let Shape::Scalar(_) = shape {
+                    return None;
+                }
+                stack.push(Frame { shape, index: 0 });
+            }
+        }
+        Some(())
+    }
+
+    fn shape(&self, ty: &'v Type<'a>) -> Option<Shape<'v, 'a>> {
+        match &ty.kind {
+            TypeKind::Array { element, length } => Some(Shape::Array {
+                element,
+                length: *length,
+            }),
+            TypeKind::Base(BaseType::Struct(tag)) => Some(Shape::Record {
+                members: lookup(self.records, tag)?,
+                is_union: false,
+            }),
+            TypeKind::Base(BaseType::Union(tag)) => Some(Shape::Record {
+                members: lookup(self.records, tag)?,
+                is_union: true,
+            }),
+            TypeKind::Base(BaseType::Typedef(name)) => self.shape(lookup(self.typedefs, name)?),
+            TypeKind::Base(BaseType::Typeof(_) | BaseType::Atomic(_)) => None,
+            TypeKind::Base(_) | TypeKind::Pointer(_) => Some(Shape::Scalar(ty)),
+            TypeKind::Function { .. } => None,
+        }
+    }
+    /// Returns the number of elements `expression` initializes in `ty`, counting
+    /// the terminating null, if it is a string literal and `ty` a character array.
+    fn string_length(&self, ty: &'v Type<'a>, expression: &Expression<'a>) -> Option<u64> {
+        let literal = string_literal(expression)?;
+        if !self.is_character_array(ty) {
+            return None;
+        }
+        Some(literal.decode().ok()?.len() as u64 + 1)
+    }
+    /// Returns whether `ty` is an array that a string literal can initialize on its own.
+    fn is_character_array(&self, ty: &'v Type<'a>) -> bool {
+        let Some(Shape::Array { element, .. }) = self.shape(ty) else {
+            return false;
+        };
+        matches!(
+            self.shape(element),
+            Some(Shape::Scalar(Type {
+                kind: TypeKind::Base(_),
+                ..
+            }))
+        )
+    }
+
+    fn err(&mut self, at: At, kind: ValidationErrKind) {
+        self.errors.push(ValidationErr { at, kind });
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Shape<'v, 'a> {
+    Scalar(&'v Type<'a>),
+    /// An array, whose length is `None` if it is not known.
+    Array {
+        element: &'v Type<'a>,
+        length: Option<u64>,
+    },
+    Record {
+        members: &'v [Member<'a>],
+        is_union: bool,
+    },
+}
+
+/// A subobject being initialized, and the index of its next element or member.
+struct Frame<'v, 'a> {
+    shape: Shape<'v, 'a>,
+    index: u64,
+}
+impl<'v, 'a> Frame<'v, 'a> {
+    fn is_full(&self) -> bool {
+        match self.shape {
+            Shape::Scalar(_) => self.index >= 1,
+            Shape::Array { length, .. } => length.is_some_and(|length| self.index >= length),
+            Shape::Record { members, .. } => self.index >= members.len() as u64,
+        }
+    }
+    fn slot(&self) -> &'v Type<'a> {
+        match self.shape {
+            Shape::Scalar(ty) => ty,
+            Shape::Array { element, .. } => element,
+            Shape::Record { members, .. } => &members[self.index as usize].ty,
+        }
+    }
+    fn advance(&mut self) {
+        match self.shape {
+            // Only one member of a union is initialized.
+            Shape::Record {
+                members,
+                is_union: true,
+            } => self.index = members.len() as u64,
+            _ => self.index += 1,
+        }
+    }
+}
+
+/// Returns whether `ty` involves a `typeof` or `_Atomic(...)` specifier.
+fn is_unresolved(ty: &Type) -> bool {
+    match &ty.kind {
+        TypeKind::Base(base) => matches!(base, BaseType::Typeof(_) | BaseType::Atomic(_)),
+        TypeKind::Pointer(inner) | TypeKind::Array { element: inner, .. } => is_unresolved(inner),
+        TypeKind::Function {
+            returns,
+            parameters,
+            ..
+        } => is_unresolved(returns) || parameters.iter().flatten().any(is_unresolved),
+    }
+}
+
+fn string_literal<'e, 'a>(expression: &'e Expression<'a>) -> Option<&'e StringLiteral<'a>> {
+    match &expression.kind {
+        ExpressionKind::String(literal) => Some(literal),
+        ExpressionKind::Parenthesized { inner, .. } => string_literal(inner),
+        _ => None,
+    }
+}
+
+/// Returns whether `declarator` declares an array of unspecified size, like `data[]`.
+fn is_flexible_array(declarator: &Declarator) -> bool {
+    matches!(
+        declarator.derivations().first(),
+        Some(Derivation::Array(ArrayDeclarator {
+            kind: ArrayDeclaratorKind::Normal {
+                static_keyword: None,
+                size: None,
+            },
+            ..
+        }))
+    )
+}
+
+/// Returns whether `expression` can designate a modifiable object,
+/// judging only by its syntactic form.
+pub fn is_lvalue(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Identifier(_)
+        | ExpressionKind::Index { .. }
+        | ExpressionKind::MemberIndirect { .. }
+        | ExpressionKind::CompoundLiteral(_)
+        | ExpressionKind::GenericSelection(_)
+        | ExpressionKind::Unary(UnaryOperator::Dereference, _) => true,
+        ExpressionKind::Parenthesized { inner, .. } => is_lvalue(inner),
+        ExpressionKind::Member { left, .. } => is_lvalue(left),
+        _ => false,
+    }
+}
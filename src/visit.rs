@@ -0,0 +1,1273 @@
+use crate::{
+    ast::*,
+    token::{At, Token, TokenKind},
+};
+
+/// A reference to any AST node that carries a source position.
+#[derive(Copy, Clone, Debug)]
+pub enum Node<'n, 'a> {
+    TranslationUnit(&'n TranslationUnit<'a>),
+    ExternalDeclaration(&'n ExternalDeclaration<'a>),
+    FunctionDefinition(&'n FunctionDefinition<'a>),
+    Declaration(&'n Declaration<'a>),
+    DeclarationSpecifier(&'n DeclarationSpecifier<'a>),
+    InitDeclarator(&'n InitDeclarator<'a>),
+    AttributeDeclaration(&'n AttributeDeclaration<'a>),
+    StorageClassSpecifier(&'n StorageClassSpecifier),
+    TypeSpecifierQualifier(&'n TypeSpecifierQualifier<'a>),
+    TypeSpecifier(&'n TypeSpecifier<'a>),
+    TypeQualifier(&'n TypeQualifier),
+    FunctionSpecifier(&'n FunctionSpecifier),
+    AlignmentSpecifier(&'n AlignmentSpecifier<'a>),
+    StructOrUnionSpecifier(&'n StructOrUnionSpecifier<'a>),
+    MemberDeclaration(&'n MemberDeclaration<'a>),
+    MemberDeclarator(&'n MemberDeclarator<'a>),
+    EnumSpecifier(&'n EnumSpecifier<'a>),
+    Enumerator(&'n Enumerator<'a>),
+    AtomicTypeSpecifier(&'n AtomicTypeSpecifier<'a>),
+    TypeofSpecifier(&'n TypeofSpecifier<'a>),
+    Declarator(&'n Declarator<'a>),
+    DirectDeclarator(&'n DirectDeclarator<'a>),
+    Pointer(&'n Pointer<'a>),
+    ParameterTypeList(&'n ParameterTypeList<'a>),
+    ParameterDeclaration(&'n ParameterDeclaration<'a>),
+    TypeName(&'n TypeName<'a>),
+    AbstractDeclarator(&'n AbstractDeclarator<'a>),
+    DirectAbstractDeclarator(&'n DirectAbstractDeclarator<'a>),
+    Initializer(&'n Initializer<'a>),
+    BracedInitializer(&'n BracedInitializer<'a>),
+    Designator(&'n Designator<'a>),
+    StaticAssertDeclaration(&'n StaticAssertDeclaration<'a>),
+    PragmaOperator(&'n PragmaOperator<'a>),
+    AttributeSpecifier(&'n AttributeSpecifier<'a>),
+    Attribute(&'n Attribute<'a>),
+    Statement(&'n Statement<'a>),
+    Label(&'n Label<'a>),
+    UnlabeledStatement(&'n UnlabeledStatement<'a>),
+    CompoundStatement(&'n CompoundStatement<'a>),
+    BlockItem(&'n BlockItem<'a>),
+    ExpressionStatement(&'n ExpressionStatement<'a>),
+    SelectionStatement(&'n SelectionStatement<'a>),
+    IterationStatement(&'n IterationStatement<'a>),
+    JumpStatement(&'n JumpStatement<'a>),
+    Expression(&'n Expression<'a>),
+    GenericAssociation(&'n GenericAssociation<'a>),
+}
+impl Node<'_, '_> {
+    pub fn at(&self) -> At {
+        match self {
+            Node::TranslationUnit(n) => n.at,
+            Node::ExternalDeclaration(n) => n.at,
+            Node::FunctionDefinition(n) => n.at,
+            Node::Declaration(n) => n.at,
+            Node::DeclarationSpecifier(n) => n.at,
+            Node::InitDeclarator(n) => n.at,
+            Node::AttributeDeclaration(n) => n.at,
+            Node::StorageClassSpecifier(n) => n.at,
+            Node::TypeSpecifierQualifier(n) => n.at,
+            Node::TypeSpecifier(n) => n.at,
+            Node::TypeQualifier(n) => n.at,
+            Node::FunctionSpecifier(n) => n.at,
+            Node::AlignmentSpecifier(n) => n.at,
+            Node::StructOrUnionSpecifier(n) => n.at,
+            Node::MemberDeclaration(n) => n.at,
+            Node::MemberDeclarator(n) => n.at,
+            Node::EnumSpecifier(n) => n.at,
+            Node::Enumerator(n) => n.at,
+            Node::AtomicTypeSpecifier(n) => n.at,
+            Node::TypeofSpecifier(n) => n.at,
+            Node::Declarator(n) => n.at,
+            Node::DirectDeclarator(n) => n.at,
+            Node::Pointer(n) => n.at,
+            Node::ParameterTypeList(n) => n.at,
+            Node::ParameterDeclaration(n) => n.at,
+            Node::TypeName(n) => n.at,
+            Node::AbstractDeclarator(n) => n.at,
+            Node::DirectAbstractDeclarator(n) => n.at,
+            Node::Initializer(n) => n.at,
+            Node::BracedInitializer(n) => n.at,
+            Node::Designator(n) => n.at,
+            Node::StaticAssertDeclaration(n) => n.at,
+            Node::PragmaOperator(n) => n.at,
+            Node::AttributeSpecifier(n) => n.at,
+            Node::Attribute(n) => n.at,
+            Node::Statement(n) => n.at,
+            Node::Label(n) => n.at,
+            Node::UnlabeledStatement(n) => n.at,
+            Node::CompoundStatement(n) => n.at,
+            Node::BlockItem(n) => n.at,
+            Node::ExpressionStatement(n) => n.at,
+            Node::SelectionStatement(n) => n.at,
+            Node::IterationStatement(n) => n.at,
+            Node::JumpStatement(n) => n.at,
+            Node::Expression(n) => n.at,
+            Node::GenericAssociation(n) => n.at,
+        }
+    }
+}
+
+/// Walks an AST in source order.
+///
+/// Every `visit_*` method defaults to the matching `walk_*` function,
+/// which reports the node to [`Visitor::enter`], visits its children and then reports it to [`Visitor::leave`].
+/// Overriding a `visit_*` method without calling the `walk_*` function prunes that subtree.
+pub trait Visitor<'a> {
+    fn enter(&mut self, _node: Node<'_, 'a>) {}
+    fn leave(&mut self, _node: Node<'_, 'a>) {}
+
+    fn visit_translation_unit(&mut self, unit: &TranslationUnit<'a>) {
+        walk_translation_unit(self, unit)
+    }
+    fn visit_external_declaration(&mut self, declaration: &ExternalDeclaration<'a>) {
+        walk_external_declaration(self, declaration)
+    }
+    fn visit_function_definition(&mut self, function: &FunctionDefinition<'a>) {
+        walk_function_definition(self, function)
+    }
+    fn visit_declaration(&mut self, declaration: &Declaration<'a>) {
+        walk_declaration(self, declaration)
+    }
+    fn visit_declaration_specifier(&mut self, specifier: &DeclarationSpecifier<'a>) {
+        walk_declaration_specifier(self, specifier)
+    }
+    fn visit_init_declarator(&mut self, declarator: &InitDeclarator<'a>) {
+        walk_init_declarator(self, declarator)
+    }
+    fn visit_attribute_declaration(&mut self, declaration: &AttributeDeclaration<'a>) {
+        walk_attribute_declaration(self, declaration)
+    }
+    fn visit_storage_class_specifier(&mut self, specifier: &StorageClassSpecifier) {
+        walk_storage_class_specifier(self, specifier)
+    }
+    fn visit_type_specifier_qualifier(&mut self, specifier: &TypeSpecifierQualifier<'a>) {
+        walk_type_specifier_qualifier(self, specifier)
+    }
+    fn visit_type_specifier(&mut self, specifier: &TypeSpecifier<'a>) {
+        walk_type_specifier(self, specifier)
+    }
+    fn visit_type_qualifier(&mut self, qualifier: &TypeQualifier) {
+        walk_type_qualifier(self, qualifier)
+    }
+    fn visit_function_specifier(&mut self, specifier: &FunctionSpecifier) {
+        walk_function_specifier(self, specifier)
+    }
+    fn visit_alignment_specifier(&mut self, specifier: &AlignmentSpecifier<'a>) {
+        walk_alignment_specifier(self, specifier)
+    }
+    fn visit_struct_or_union_specifier(&mut self, specifier: &StructOrUnionSpecifier<'a>) {
+        walk_struct_or_union_specifier(self, specifier)
+    }
+    fn visit_member_declaration(&mut self, member: &MemberDeclaration<'a>) {
+        walk_member_declaration(self, member)
+    }
+    fn visit_member_declarator(&mut self, declarator: &MemberDeclarator<'a>) {
+        walk_member_declarator(self, declarator)
+    }
+    fn visit_enum_specifier(&mut self, specifier: &EnumSpecifier<'a>) {
+        walk_enum_specifier(self, specifier)
+    }
+    fn visit_enumerator(&mut self, enumerator: &Enumerator<'a>) {
+        walk_enumerator(self, enumerator)
+    }
+    fn visit_atomic_type_specifier(&mut self, specifier: &AtomicTypeSpecifier<'a>) {
+        walk_atomic_type_specifier(self, specifier)
+    }
+    fn visit_typeof_specifier(&mut self, specifier: &TypeofSpecifier<'a>) {
+        walk_typeof_specifier(self, specifier)
+    }
+    fn visit_declarator(&mut self, declarator: &Declarator<'a>) {
+        walk_declarator(self, declarator)
+    }
+    fn visit_direct_declarator(&mut self, declarator: &DirectDeclarator<'a>) {
+        walk_direct_declarator(self, declarator)
+    }
+    fn visit_pointer(&mut self, pointer: &Pointer<'a>) {
+        walk_pointer(self, pointer)
+    }
+    fn visit_parameter_type_list(&mut self, parameters: &ParameterTypeList<'a>) {
+        walk_parameter_type_list(self, parameters)
+    }
+    fn visit_parameter_declaration(&mut self, parameter: &ParameterDeclaration<'a>) {
+        walk_parameter_declaration(self, parameter)
+    }
+    fn visit_type_name(&mut self, type_name: &TypeName<'a>) {
+        walk_type_name(self, type_name)
+    }
+    fn visit_abstract_declarator(&mut self, declarator: &AbstractDeclarator<'a>) {
+        walk_abstract_declarator(self, declarator)
+    }
+    fn visit_direct_abstract_declarator(&mut self, declarator: &DirectAbstractDeclarator<'a>) {
+        walk_direct_abstract_declarator(self, declarator)
+    }
+    fn visit_initializer(&mut self, initializer: &Initializer<'a>) {
+        walk_initializer(self, initializer)
+    }
+    fn visit_braced_initializer(&mut self, initializer: &BracedInitializer<'a>) {
+        walk_braced_initializer(self, initializer)
+    }
+    fn visit_designator(&mut self, designator: &Designator<'a>) {
+        walk_designator(self, designator)
+    }
+    fn visit_static_assert_declaration(&mut self, assert: &StaticAssertDeclaration<'a>) {
+        walk_static_assert_declaration(self, assert)
+    }
+    fn visit_pragma_operator(&mut self, pragma: &PragmaOperator<'a>) {
+        walk_pragma_operator(self, pragma)
+    }
+    fn visit_attribute_specifier(&mut self, specifier: &AttributeSpecifier<'a>) {
+        walk_attribute_specifier(self, specifier)
+    }
+    fn visit_attribute(&mut self, attribute: &Attribute<'a>) {
+        walk_attribute(self, attribute)
+    }
+    fn visit_statement(&mut self, statement: &Statement<'a>) {
+        walk_statement(self, statement)
+    }
+    fn visit_label(&mut self, label: &Label<'a>) {
+        walk_label(self, label)
+    }
+    fn visit_unlabeled_statement(&mut self, statement: &UnlabeledStatement<'a>) {
+        walk_unlabeled_statement(self, statement)
+    }
+    fn visit_compound_statement(&mut self, statement: &CompoundStatement<'a>) {
+        walk_compound_statement(self, statement)
+    }
+    fn visit_block_item(&mut self, item: &BlockItem<'a>) {
+        walk_block_item(self, item)
+    }
+    fn visit_expression_statement(&mut self, statement: &ExpressionStatement<'a>) {
+        walk_expression_statement(self, statement)
+    }
+    fn visit_selection_statement(&mut self, statement: &SelectionStatement<'a>) {
+        walk_selection_statement(self, statement)
+    }
+    fn visit_iteration_statement(&mut self, statement: &IterationStatement<'a>) {
+        walk_iteration_statement(self, statement)
+    }
+    fn visit_jump_statement(&mut self, statement: &JumpStatement<'a>) {
+        walk_jump_statement(self, statement)
+    }
+    fn visit_expression(&mut self, expression: &Expression<'a>) {
+        walk_expression(self, expression)
+    }
+    fn visit_generic_association(&mut self, association: &GenericAssociation<'a>) {
+        walk_generic_association(self, association)
+    }
+}
+
+pub fn walk_translation_unit<'a, V: Visitor<'a> + ?Sized>(v: &mut V, unit: &TranslationUnit<'a>) {
+    v.enter(Node::TranslationUnit(unit));
+    for declaration in unit.iter() {
+        v.visit_external_declaration(declaration);
+    }
+    v.leave(Node::TranslationUnit(unit));
+}
+pub fn walk_external_declaration<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declaration: &ExternalDeclaration<'a>,
+) {
+    v.enter(Node::ExternalDeclaration(declaration));
+    match &declaration.kind {
+        ExternalDeclarationKind::Function(function) => v.visit_function_definition(function),
+        ExternalDeclarationKind::Declaration(declaration) => v.visit_declaration(declaration),
+    }
+    v.leave(Node::ExternalDeclaration(declaration));
+}
+pub fn walk_function_definition<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    function: &FunctionDefinition<'a>,
+) {
+    v.enter(Node::FunctionDefinition(function));
+    walk_attributes(v, function.attributes.as_ref());
+    walk_declaration_specifiers(v, &function.specifiers);
+    v.visit_declarator(&function.declarator);
+    v.visit_compound_statement(&function.body);
+    v.leave(Node::FunctionDefinition(function));
+}
+pub fn walk_declaration<'a, V: Visitor<'a> + ?Sized>(v: &mut V, declaration: &Declaration<'a>) {
+    v.enter(Node::Declaration(declaration));
+    match &declaration.kind {
+        DeclarationKind::Normal {
+            attributes,
+            specifiers,
+            init_declarators,
+            ..
+        } => {
+            walk_attributes(v, attributes.as_ref());
+            walk_declaration_specifiers(v, specifiers);
+            for declarator in init_declarators.iter().flat_map(|d| d.iter()) {
+                v.visit_init_declarator(declarator);
+            }
+        }
+        DeclarationKind::Assert(assert) => v.visit_static_assert_declaration(assert),
+        DeclarationKind::Attribute(attribute) => v.visit_attribute_declaration(attribute),
+        DeclarationKind::Pragma(pragma) => v.visit_pragma_operator(pragma),
+    }
+    v.leave(Node::Declaration(declaration));
+}
+pub fn walk_declaration_specifiers<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifiers: &DeclarationSpecifiers<'a>,
+) {
+    for specifier in specifiers.iter() {
+        v.visit_declaration_specifier(specifier);
+    }
+    walk_attributes(v, specifiers.attributes());
+}
+pub fn walk_declaration_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &DeclarationSpecifier<'a>,
+) {
+    v.enter(Node::DeclarationSpecifier(specifier));
+    match &specifier.kind {
+        DeclarationSpecifierKind::StorageClass(s) => v.visit_storage_class_specifier(s),
+        DeclarationSpecifierKind::Type(t) => v.visit_type_specifier_qualifier(t),
+        DeclarationSpecifierKind::Function(f) => v.visit_function_specifier(f),
+    }
+    v.leave(Node::DeclarationSpecifier(specifier));
+}
+pub fn walk_init_declarator<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declarator: &InitDeclarator<'a>,
+) {
+    v.enter(Node::InitDeclarator(declarator));
+    v.visit_declarator(&declarator.declarator);
+    if let Some((_, initializer)) = &declarator.initializer {
+        v.visit_initializer(initializer);
+    }
+    v.leave(Node::InitDeclarator(declarator));
+}
+pub fn walk_attribute_declaration<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declaration: &AttributeDeclaration<'a>,
+) {
+    v.enter(Node::AttributeDeclaration(declaration));
+    walk_attributes(v, Some(&declaration.attributes));
+    v.leave(Node::AttributeDeclaration(declaration));
+}
+pub fn walk_storage_class_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &StorageClassSpecifier,
+) {
+    v.enter(Node::StorageClassSpecifier(specifier));
+    v.leave(Node::StorageClassSpecifier(specifier));
+}
+pub fn walk_type_specifier_qualifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &TypeSpecifierQualifier<'a>,
+) {
+    v.enter(Node::TypeSpecifierQualifier(specifier));
+    match &specifier.kind {
+        TypeSpecifierQualifierKind::TypeSpecifier(s) => v.visit_type_specifier(s),
+        TypeSpecifierQualifierKind::TypeQualifier(q) => v.visit_type_qualifier(q),
+        TypeSpecifierQualifierKind::Alignment(a) => v.visit_alignment_specifier(a),
+    }
+    v.leave(Node::TypeSpecifierQualifier(specifier));
+}
+pub fn walk_specifier_qualifier_list<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    list: &SpecifierQualifierList<'a>,
+) {
+    for specifier in list.iter() {
+        v.visit_type_specifier_qualifier(specifier);
+    }
+    walk_attributes(v, list.attributes());
+}
+pub fn walk_type_specifier<'a, V: Visitor<'a> + ?Sized>(v: &mut V, specifier: &TypeSpecifier<'a>) {
+    v.enter(Node::TypeSpecifier(specifier));
+    match &specifier.kind {
+        TypeSpecifierKind::BitInt { width, .. } => v.visit_expression(width),
+        TypeSpecifierKind::Atomic(atomic) => v.visit_atomic_type_specifier(atomic),
+        TypeSpecifierKind::StructOrUnion(s) => v.visit_struct_or_union_specifier(s),
+        TypeSpecifierKind::Enum(e) => v.visit_enum_specifier(e),
+        TypeSpecifierKind::Typeof(t) => v.visit_typeof_specifier(t),
+        TypeSpecifierKind::Void
+        | TypeSpecifierKind::Char
+        | TypeSpecifierKind::Short
+        | TypeSpecifierKind::Int
+        | TypeSpecifierKind::Long
+        | TypeSpecifierKind::Float
+        | TypeSpecifierKind::Double
+        | TypeSpecifierKind::Signed
+        | TypeSpecifierKind::Unsigned
+        | TypeSpecifierKind::Bool
+        | TypeSpecifierKind::Complex
+        | TypeSpecifierKind::Decimal32
+        | TypeSpecifierKind::Decimal64
+        | TypeSpecifierKind::Decimal128
+        | TypeSpecifierKind::Float16
+        | TypeSpecifierKind::Float32
+        | TypeSpecifierKind::Float64
+        | TypeSpecifierKind::Float128
+        | TypeSpecifierKind::Float32x
+        | TypeSpecifierKind::Float64x
+        | TypeSpecifierKind::Float128x
+        | TypeSpecifierKind::Int128
+        | TypeSpecifierKind::TypedefName(_) => (),
+    }
+    v.leave(Node::TypeSpecifier(specifier));
+}
+pub fn walk_type_qualifier<'a, V: Visitor<'a> + ?Sized>(v: &mut V, qualifier: &TypeQualifier) {
+    v.enter(Node::TypeQualifier(qualifier));
+    v.leave(Node::TypeQualifier(qualifier));
+}
+pub fn walk_type_qualifier_list<'a, V: Visitor<'a> + ?Sized>(v: &mut V, list: &TypeQualifierList) {
+    for qualifier in list.iter() {
+        v.visit_type_qualifier(qualifier);
+    }
+}
+pub fn walk_function_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &FunctionSpecifier,
+) {
+    v.enter(Node::FunctionSpecifier(specifier));
+    v.leave(Node::FunctionSpecifier(specifier));
+}
+pub fn walk_alignment_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &AlignmentSpecifier<'a>,
+) {
+    v.enter(Node::AlignmentSpecifier(specifier));
+    match &specifier.kind {
+        AlignmentSpecifierKind::Type(type_name) => v.visit_type_name(type_name),
+        AlignmentSpecifierKind::Expression(expression) => v.visit_expression(expression),
+    }
+    v.leave(Node::AlignmentSpecifier(specifier));
+}
+pub fn walk_struct_or_union_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &StructOrUnionSpecifier<'a>,
+) {
+    v.enter(Node::StructOrUnionSpecifier(specifier));
+    walk_attributes(v, specifier.attributes.as_ref());
+    if let Some((_, members, _)) = &specifier.members {
+        for member in members.iter() {
+            v.visit_member_declaration(member);
+        }
+    }
+    v.leave(Node::StructOrUnionSpecifier(specifier));
+}
+pub fn walk_member_declaration<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    member: &MemberDeclaration<'a>,
+) {
+    v.enter(Node::MemberDeclaration(member));
+    match &member.kind {
+        MemberDeclarationKind::Member {
+            attributes,
+            specifier_qualifiers,
+            member_declarators,
+            ..
+        } => {
+            walk_attributes(v, attributes.as_ref());
+            walk_specifier_qualifier_list(v, specifier_qualifiers);
+            for declarator in member_declarators.iter().flat_map(|d| d.iter()) {
+                v.visit_member_declarator(declarator);
+            }
+        }
+        MemberDeclarationKind::Assert(assert) => v.visit_static_assert_declaration(assert),
+    }
+    v.leave(Node::MemberDeclaration(member));
+}
+pub fn walk_member_declarator<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declarator: &MemberDeclarator<'a>,
+) {
+    v.enter(Node::MemberDeclarator(declarator));
+    if let Some(d) = &declarator.declarator {
+        v.visit_declarator(d);
+    }
+    if let Some((_, width)) = &declarator.width {
+        v.visit_expression(width);
+    }
+    v.leave(Node::MemberDeclarator(declarator));
+}
+pub fn walk_enum_specifier<'a, V: Visitor<'a> + ?Sized>(v: &mut V, specifier: &EnumSpecifier<'a>) {
+    v.enter(Node::EnumSpecifier(specifier));
+    walk_attributes(v, specifier.attributes.as_ref());
+    if let Some(enum_type) = &specifier.enum_type {
+        walk_specifier_qualifier_list(v, &enum_type.specifier_qualifiers);
+    }
+    if let Some((_, enumerators, _, _)) = &specifier.enumerators {
+        for enumerator in enumerators.iter() {
+            v.visit_enumerator(enumerator);
+        }
+    }
+    v.leave(Node::EnumSpecifier(specifier));
+}
+pub fn walk_enumerator<'a, V: Visitor<'a> + ?Sized>(v: &mut V, enumerator: &Enumerator<'a>) {
+    v.enter(Node::Enumerator(enumerator));
+    walk_attributes(v, enumerator.attributes.as_ref());
+    if let Some((_, value)) = &enumerator.value {
+        v.visit_expression(value);
+    }
+    v.leave(Node::Enumerator(enumerator));
+}
+pub fn walk_atomic_type_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &AtomicTypeSpecifier<'a>,
+) {
+    v.enter(Node::AtomicTypeSpecifier(specifier));
+    v.visit_type_name(&specifier.type_name);
+    v.leave(Node::AtomicTypeSpecifier(specifier));
+}
+pub fn walk_typeof_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &TypeofSpecifier<'a>,
+) {
+    v.enter(Node::TypeofSpecifier(specifier));
+    match &specifier.argument.kind {
+        TypeofSpecifierArgumentKind::Expression(expression) => v.visit_expression(expression),
+        TypeofSpecifierArgumentKind::Type(type_name) => v.visit_type_name(type_name),
+    }
+    v.leave(Node::TypeofSpecifier(specifier));
+}
+pub fn walk_declarator<'a, V: Visitor<'a> + ?Sized>(v: &mut V, declarator: &Declarator<'a>) {
+    v.enter(Node::Declarator(declarator));
+    if let Some(pointer) = &declarator.pointer {
+        v.visit_pointer(pointer);
+    }
+    v.visit_direct_declarator(&declarator.direct);
+    v.leave(Node::Declarator(declarator));
+}
+pub fn walk_direct_declarator<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declarator: &DirectDeclarator<'a>,
+) {
+    v.enter(Node::DirectDeclarator(declarator));
+    match &declarator.kind {
+        DirectDeclaratorKind::Name(_, attributes) => walk_attributes(v, attributes.as_ref()),
+        DirectDeclaratorKind::Parenthesized { inner, .. } => v.visit_declarator(inner),
+        DirectDeclaratorKind::Array(array, attributes) => {
+            v.visit_direct_declarator(&array.left);
+            if let Some(qualifiers) = &array.qualifiers {
+                walk_type_qualifier_list(v, qualifiers);
+            }
+            if let ArrayDeclaratorKind::Normal {
+                size: Some(size), ..
+            } = &array.kind
+            {
+                v.visit_expression(size);
+            }
+            walk_attributes(v, attributes.as_ref());
+        }
+        DirectDeclaratorKind::Function(function, attributes) => {
+            v.visit_direct_declarator(&function.left);
+            if let Some(parameters) = &function.parameters {
+                v.visit_parameter_type_list(parameters);
+            }
+            walk_attributes(v, attributes.as_ref());
+        }
+    }
+    v.leave(Node::DirectDeclarator(declarator));
+}
+pub fn walk_pointer<'a, V: Visitor<'a> + ?Sized>(v: &mut V, pointer: &Pointer<'a>) {
+    v.enter(Node::Pointer(pointer));
+    walk_attributes(v, pointer.attributes.as_ref());
+    if let Some(qualifiers) = &pointer.qualifiers {
+        walk_type_qualifier_list(v, qualifiers);
+    }
+    if let Some(right) = &pointer.right {
+        v.visit_pointer(right);
+    }
+    v.leave(Node::Pointer(pointer));
+}
+pub fn walk_parameter_type_list<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    parameters: &ParameterTypeList<'a>,
+) {
+    v.enter(Node::ParameterTypeList(parameters));
+    if let Some((list, _)) = &parameters.parameters {
+        for parameter in list.iter() {
+            v.visit_parameter_declaration(parameter);
+        }
+    }
+    v.leave(Node::ParameterTypeList(parameters));
+}
+pub fn walk_parameter_declaration<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    parameter: &ParameterDeclaration<'a>,
+) {
+    v.enter(Node::ParameterDeclaration(parameter));
+    walk_attributes(v, parameter.attributes.as_ref());
+    walk_declaration_specifiers(v, &parameter.specifiers);
+    match &parameter.kind {
+        ParameterDeclarationKind::Concrete(declarator) => v.visit_declarator(declarator),
+        ParameterDeclarationKind::Abstract(Some(declarator)) => {
+            v.visit_abstract_declarator(declarator)
+        }
+        ParameterDeclarationKind::Abstract(None) => (),
+    }
+    v.leave(Node::ParameterDeclaration(parameter));
+}
+pub fn walk_type_name<'a, V: Visitor<'a> + ?Sized>(v: &mut V, type_name: &TypeName<'a>) {
+    v.enter(Node::TypeName(type_name));
+    walk_specifier_qualifier_list(v, &type_name.specifier_qualifiers);
+    if let Some(declarator) = &type_name.declarator {
+        v.visit_abstract_declarator(declarator);
+    }
+    v.leave(Node::TypeName(type_name));
+}
+pub fn walk_abstract_declarator<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declarator: &AbstractDeclarator<'a>,
+) {
+    v.enter(Node::AbstractDeclarator(declarator));
+    if let Some(pointer) = &declarator.pointer {
+        v.visit_pointer(pointer);
+    }
+    if let Some(direct) = &declarator.direct {
+        v.visit_direct_abstract_declarator(direct);
+    }
+    v.leave(Node::AbstractDeclarator(declarator));
+}
+pub fn walk_direct_abstract_declarator<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    declarator: &DirectAbstractDeclarator<'a>,
+) {
+    v.enter(Node::DirectAbstractDeclarator(declarator));
+    match &declarator.kind {
+        DirectAbstractDeclaratorKind::Parenthesized { inner, .. } => {
+            v.visit_abstract_declarator(inner)
+        }
+        DirectAbstractDeclaratorKind::Array(array, attributes) => {
+            if let Some(left) = &array.left {
+                v.visit_direct_abstract_declarator(left);
+            }
+            if let ArrayAbstractDeclaratorKind::Normal {
+                qualifiers, size, ..
+            } = &array.kind
+            {
+                if let Some(qualifiers) = qualifiers {
+                    walk_type_qualifier_list(v, qualifiers);
+                }
+                if let Some(size) = size {
+                    v.visit_expression(size);
+                }
+            }
+            walk_attributes(v, attributes.as_ref());
+        }
+        DirectAbstractDeclaratorKind::Function(function, attributes) => {
+            if let Some(left) = &function.left {
+                v.visit_direct_abstract_declarator(left);
+            }
+            if let Some(parameters) = &function.parameters {
+                v.visit_parameter_type_list(parameters);
+            }
+            walk_attributes(v, attributes.as_ref());
+        }
+    }
+    v.leave(Node::DirectAbstractDeclarator(declarator));
+}
+pub fn walk_initializer<'a, V: Visitor<'a> + ?Sized>(v: &mut V, initializer: &Initializer<'a>) {
+    v.enter(Node::Initializer(initializer));
+    match &initializer.kind {
+        InitializerKind::Expression(expression) => v.visit_expression(expression),
+        InitializerKind::Braced(braced) => v.visit_braced_initializer(braced),
+    }
+    v.leave(Node::Initializer(initializer));
+}
+pub fn walk_braced_initializer<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    initializer: &BracedInitializer<'a>,
+) {
+    v.enter(Node::BracedInitializer(initializer));
+    if let Some((list, _)) = &initializer.initializers {
+        for (designation, initializer) in list.iter() {
+            if let Some(designation) = designation {
+                for designator in designation.designators.iter() {
+                    v.visit_designator(designator);
+                }
+            }
+            v.visit_initializer(initializer);
+        }
+    }
+    v.leave(Node::BracedInitializer(initializer));
+}
+pub fn walk_designator<'a, V: Visitor<'a> + ?Sized>(v: &mut V, designator: &Designator<'a>) {
+    v.enter(Node::Designator(designator));
+    if let DesignatorKind::InBrackets { value, .. } = &designator.kind {
+        v.visit_expression(value);
+    }
+    v.leave(Node::Designator(designator));
+}
+pub fn walk_static_assert_declaration<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    assert: &StaticAssertDeclaration<'a>,
+) {
+    v.enter(Node::StaticAssertDeclaration(assert));
+    v.visit_expression(&assert.condition);
+    v.leave(Node::StaticAssertDeclaration(assert));
+}
+pub fn walk_pragma_operator<'a, V: Visitor<'a> + ?Sized>(v: &mut V, pragma: &PragmaOperator<'a>) {
+    v.enter(Node::PragmaOperator(pragma));
+    v.leave(Node::PragmaOperator(pragma));
+}
+pub fn walk_attributes<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    attributes: Option<&AttributeSpecifierSequence<'a>>,
+) {
+    for specifier in attributes.iter().flat_map(|a| a.iter()) {
+        v.visit_attribute_specifier(specifier);
+    }
+}
+pub fn walk_attribute_specifier<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    specifier: &AttributeSpecifier<'a>,
+) {
+    v.enter(Node::AttributeSpecifier(specifier));
+    for attribute in specifier.attributes.iter().flatten() {
+        v.visit_attribute(attribute);
+    }
+    v.leave(Node::AttributeSpecifier(specifier));
+}
+pub fn walk_attribute<'a, V: Visitor<'a> + ?Sized>(v: &mut V, attribute: &Attribute<'a>) {
+    v.enter(Node::Attribute(attribute));
+    v.leave(Node::Attribute(attribute));
+}
+pub fn walk_statement<'a, V: Visitor<'a> + ?Sized>(v: &mut V, statement: &Statement<'a>) {
+    v.enter(Node::Statement(statement));
+    match &statement.kind {
+        StatementKind::Labeled(labeled) => {
+            v.visit_label(&labeled.label);
+            v.visit_statement(&labeled.statement);
+        }
+        StatementKind::Unlabeled(unlabeled) => v.visit_unlabeled_statement(unlabeled),
+    }
+    v.leave(Node::Statement(statement));
+}
+pub fn walk_label<'a, V: Visitor<'a> + ?Sized>(v: &mut V, label: &Label<'a>) {
+    v.enter(Node::Label(label));
+    walk_attributes(v, label.attributes.as_ref());
+    match &label.kind {
+        LabelKind::Case { value, .. } => v.visit_expression(value),
+        LabelKind::CaseRange { low, high, .. } => {
+            v.visit_expression(low);
+            v.visit_expression(high);
+        }
+        LabelKind::Name(_) | LabelKind::Default { .. } => (),
+    }
+    v.leave(Node::Label(label));
+}
+pub fn walk_unlabeled_statement<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    statement: &UnlabeledStatement<'a>,
+) {
+    v.enter(Node::UnlabeledStatement(statement));
+    match &statement.kind {
+        UnlabeledStatementKind::Expression(expression) => v.visit_expression_statement(expression),
+        UnlabeledStatementKind::Primary(attributes, primary) => {
+            walk_attributes(v, attributes.as_ref());
+            match &primary.kind {
+                PrimaryBlockKind::Compound(compound) => v.visit_compound_statement(compound),
+                PrimaryBlockKind::Selection(selection) => v.visit_selection_statement(selection),
+                PrimaryBlockKind::Iteration(iteration) => v.visit_iteration_statement(iteration),
+            }
+        }
+        UnlabeledStatementKind::Jump(attributes, jump) => {
+            walk_attributes(v, attributes.as_ref());
+            v.visit_jump_statement(jump);
+        }
+    }
+    v.leave(Node::UnlabeledStatement(statement));
+}
+pub fn walk_compound_statement<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    statement: &CompoundStatement<'a>,
+) {
+    v.enter(Node::CompoundStatement(statement));
+    for item in statement.items.iter().flat_map(|i| i.iter()) {
+        v.visit_block_item(item);
+    }
+    v.leave(Node::CompoundStatement(statement));
+}
+pub fn walk_block_item<'a, V: Visitor<'a> + ?Sized>(v: &mut V, item: &BlockItem<'a>) {
+    v.enter(Node::BlockItem(item));
+    match &item.kind {
+        BlockItemKind::Declaration(declaration) => v.visit_declaration(declaration),
+        BlockItemKind::Unlabeled(statement) => v.visit_unlabeled_statement(statement),
+        BlockItemKind::Label(label) => v.visit_label(label),
+    }
+    v.leave(Node::BlockItem(item));
+}
+pub fn walk_expression_statement<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    statement: &ExpressionStatement<'a>,
+) {
+    v.enter(Node::ExpressionStatement(statement));
+    walk_attributes(v, statement.attributes.as_ref());
+    if let Some(expression) = &statement.expression {
+        v.visit_expression(expression);
+    }
+    v.leave(Node::ExpressionStatement(statement));
+}
+pub fn walk_selection_statement<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    statement: &SelectionStatement<'a>,
+) {
+    v.enter(Node::SelectionStatement(statement));
+    match &statement.kind {
+        SelectionStatementKind::If {
+            condition,
+            then_body,
+            else_body,
+            ..
+        } => {
+            v.visit_expression(condition);
+            v.visit_statement(&then_body.statement);
+            if let Some((_, else_body)) = else_body {
+                v.visit_statement(&else_body.statement);
+            }
+        }
+        SelectionStatementKind::Switch {
+            controlling_expression,
+            body,
+            ..
+        } => {
+            v.visit_expression(controlling_expression);
+            v.visit_statement(&body.statement);
+        }
+    }
+    v.leave(Node::SelectionStatement(statement));
+}
+pub fn walk_iteration_statement<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    statement: &IterationStatement<'a>,
+) {
+    v.enter(Node::IterationStatement(statement));
+    match &statement.kind {
+        IterationStatementKind::While {
+            condition, body, ..
+        } => {
+            v.visit_expression(condition);
+            v.visit_statement(&body.statement);
+        }
+        IterationStatementKind::DoWhile {
+            body, condition, ..
+        } => {
+            v.visit_statement(&body.statement);
+            v.visit_expression(condition);
+        }
+        IterationStatementKind::For {
+            initializer,
+            condition,
+            counter,
+            body,
+            ..
+        } => {
+            match initializer {
+                ForInitializer::Expression(Some(expression), _) => v.visit_expression(expression),
+                ForInitializer::Expression(None, _) => (),
+                ForInitializer::Declaration(declaration) => v.visit_declaration(declaration),
+            }
+            if let Some(condition) = condition {
+                v.visit_expression(condition);
+            }
+            if let Some(counter) = counter {
+                v.visit_expression(counter);
+            }
+            v.visit_statement(&body.statement);
+        }
+    }
+    v.leave(Node::IterationStatement(statement));
+}
+pub fn walk_jump_statement<'a, V: Visitor<'a> + ?Sized>(v: &mut V, statement: &JumpStatement<'a>) {
+    v.enter(Node::JumpStatement(statement));
+    match &statement.kind {
+        JumpStatementKind::Return {
+            value: Some(value), ..
+        }
+        | JumpStatementKind::ComputedGoto { target: value, .. } => v.visit_expression(value),
+        _ => (),
+    }
+    v.leave(Node::JumpStatement(statement));
+}
+pub fn walk_expression<'a, V: Visitor<'a> + ?Sized>(v: &mut V, expression: &Expression<'a>) {
+    v.enter(Node::Expression(expression));
+    match &expression.kind {
+        ExpressionKind::Identifier(_)
+        | ExpressionKind::Integer(_)
+        | ExpressionKind::Float(_)
+        | ExpressionKind::String(_)
+        | ExpressionKind::Character(_)
+        | ExpressionKind::Bool(_)
+        | ExpressionKind::Nullptr
+        | ExpressionKind::LabelAddress(_) => {}
+        ExpressionKind::Parenthesized { inner, .. } => v.visit_expression(inner),
+        ExpressionKind::StatementExpression { body, .. } => v.visit_compound_statement(body),
+        ExpressionKind::GenericSelection(generic) => {
+            v.visit_expression(&generic.controlling_expression);
+            for association in generic.generic_assocs.iter() {
+                v.visit_generic_association(association);
+            }
+        }
+        ExpressionKind::Index { left, index, .. } => {
+            v.visit_expression(left);
+            v.visit_expression(index);
+        }
+        ExpressionKind::Call {
+            left, arguments, ..
+        } => {
+            v.visit_expression(left);
+            for argument in arguments.iter().flat_map(|a| a.iter()) {
+                v.visit_expression(argument);
+            }
+        }
+        ExpressionKind::Member { left, .. }
+        | ExpressionKind::MemberIndirect { left, .. }
+        | ExpressionKind::PostIncrement { left, .. }
+        | ExpressionKind::PostDecrement { left, .. } => v.visit_expression(left),
+        ExpressionKind::CompoundLiteral(literal) => {
+            for specifier in literal.storage_class.iter().flat_map(|s| s.iter()) {
+                v.visit_storage_class_specifier(specifier);
+            }
+            v.visit_type_name(&literal.type_name);
+            v.visit_braced_initializer(&literal.initializer);
+        }
+        ExpressionKind::PreIncrement { right, .. }
+        | ExpressionKind::PreDecrement { right, .. }
+        | ExpressionKind::Unary(_, right) => v.visit_expression(right),
+        ExpressionKind::Sizeof { kind, .. } => match kind {
+            SizeofKind::Expression(expression) => v.visit_expression(expression),
+            SizeofKind::Type { type_name, .. } => v.visit_type_name(type_name),
+        },
+        ExpressionKind::Alignof { type_name, .. } => v.visit_type_name(type_name),
+        ExpressionKind::Offsetof {
+            type_name, member, ..
+        } => {
+            v.visit_type_name(type_name);
+            for designator in member.designators.iter().flat_map(|d| d.iter()) {
+                v.visit_designator(designator);
+            }
+        }
+        ExpressionKind::TypesCompatible { left, right, .. } => {
+            v.visit_type_name(left);
+            v.visit_type_name(right);
+        }
+        ExpressionKind::Cast {
+            type_name, right, ..
+        } => {
+            v.visit_type_name(type_name);
+            v.visit_expression(right);
+        }
+        ExpressionKind::Binary { left, right, .. }
+        | ExpressionKind::Assign { left, right, .. }
+        | ExpressionKind::Comma { left, right, .. } => {
+            v.visit_expression(left);
+            v.visit_expression(right);
+        }
+        ExpressionKind::Conditional {
+            condition,
+            then_value,
+            else_value,
+            ..
+        } => {
+            v.visit_expression(condition);
+            v.visit_expression(then_value);
+            v.visit_expression(else_value);
+        }
+    }
+    v.leave(Node::Expression(expression));
+}
+pub fn walk_generic_association<'a, V: Visitor<'a> + ?Sized>(
+    v: &mut V,
+    association: &GenericAssociation<'a>,
+) {
+    v.enter(Node::GenericAssociation(association));
+    if let GenericAssociationKind::ForType(type_name) = &association.kind {
+        v.visit_type_name(type_name);
+    }
+    v.visit_expression(&association.value);
+    v.leave(Node::GenericAssociation(association));
+}
+
+/// Returns the position of the first token of `node` and the position just past its last token.
+/// `tokens` must be the tokens the node was parsed from.
+pub fn source_range<'a>(node: Node<'_, 'a>, tokens: &[Token<'a>]) -> (At, At) {
+    let mut extent = Extent {
+        tokens,
+        first: usize::MAX,
+        last: 0,
+    };
+    extent.visit_node(node);
+
+    let start = tokens.get(extent.first).map_or(node.at(), |t| t.at);
+    let end = tokens.get(extent.last).map_or(start, |t| {
+        let mut end = t.at;
+        end.next_column(t.kind.to_string().chars().count() as u32);
+        end.offset = t.end;
+        end
+    });
+    (start, end)
+}
+
+/// Tracks the indices of the first and last token of the nodes it visits.
+struct Extent<'t, 'a> {
+    tokens: &'t [Token<'a>],
+    first: usize,
+    last: usize,
+}
+impl<'a> Extent<'_, 'a> {
+    fn visit_node(&mut self, node: Node<'_, 'a>) {
+        match node {
+            Node::TranslationUnit(n) => self.visit_translation_unit(n),
+            Node::ExternalDeclaration(n) => self.visit_external_declaration(n),
+            Node::FunctionDefinition(n) => self.visit_function_definition(n),
+            Node::Declaration(n) => self.visit_declaration(n),
+            Node::DeclarationSpecifier(n) => self.visit_declaration_specifier(n),
+            Node::InitDeclarator(n) => self.visit_init_declarator(n),
+            Node::AttributeDeclaration(n) => self.visit_attribute_declaration(n),
+            Node::StorageClassSpecifier(n) => self.visit_storage_class_specifier(n),
+            Node::TypeSpecifierQualifier(n) => self.visit_type_specifier_qualifier(n),
+            Node::TypeSpecifier(n) => self.visit_type_specifier(n),
+            Node::TypeQualifier(n) => self.visit_type_qualifier(n),
+            Node::FunctionSpecifier(n) => self.visit_function_specifier(n),
+            Node::AlignmentSpecifier(n) => self.visit_alignment_specifier(n),
+            Node::StructOrUnionSpecifier(n) => self.visit_struct_or_union_specifier(n),
+            Node::MemberDeclaration(n) => self.visit_member_declaration(n),
+            Node::MemberDeclarator(n) => self.visit_member_declarator(n),
+            Node::EnumSpecifier(n) => self.visit_enum_specifier(n),
+            Node::Enumerator(n) => self.visit_enumerator(n),
+            Node::AtomicTypeSpecifier(n) => self.visit_atomic_type_specifier(n),
+            Node::TypeofSpecifier(n) => self.visit_typeof_specifier(n),
+            Node::Declarator(n) => self.visit_declarator(n),
+            Node::DirectDeclarator(n) => self.visit_direct_declarator(n),
+            Node::Pointer(n) => self.visit_pointer(n),
+            Node::ParameterTypeList(n) => self.visit_parameter_type_list(n),
+            Node::ParameterDeclaration(n) => self.visit_parameter_declaration(n),
+            Node::TypeName(n) => self.visit_type_name(n),
+            Node::AbstractDeclarator(n) => self.visit_abstract_declarator(n),
+            Node::DirectAbstractDeclarator(n) => self.visit_direct_abstract_declarator(n),
+            Node::Initializer(n) => self.visit_initializer(n),
+            Node::BracedInitializer(n) => self.visit_braced_initializer(n),
+            Node::Designator(n) => self.visit_designator(n),
+            Node::StaticAssertDeclaration(n) => self.visit_static_assert_declaration(n),
+            Node::PragmaOperator(n) => self.visit_pragma_operator(n),
+            Node::AttributeSpecifier(n) => self.visit_attribute_specifier(n),
+            Node::Attribute(n) => self.visit_attribute(n),
+            Node::Statement(n) => self.visit_statement(n),
+            Node::Label(n) => self.visit_label(n),
+            Node::UnlabeledStatement(n) => self.visit_unlabeled_statement(n),
+            Node::CompoundStatement(n) => self.visit_compound_statement(n),
+            Node::BlockItem(n) => self.visit_block_item(n),
+            Node::ExpressionStatement(n) => self.visit_expression_statement(n),
+            Node::SelectionStatement(n) => self.visit_selection_statement(n),
+            Node::IterationStatement(n) => self.visit_iteration_statement(n),
+            Node::JumpStatement(n) => self.visit_jump_statement(n),
+            Node::Expression(n) => self.visit_expression(n),
+            Node::GenericAssociation(n) => self.visit_generic_association(n),
+        }
+    }
+    fn index(&self, at: At) -> usize {
+        self.tokens.partition_point(|t| t.at.offset < at.offset)
+    }
+    /// Returns the index of the first token after the one at `index` that is not a comment.
+    fn after(&self, index: usize) -> usize {
+        let is_comment = |t: &Token| {
+            matches!(
+                t.kind,
+                TokenKind::LineComment(_) | TokenKind::BlockComment(_)
+            )
+        };
+        self.tokens
+            .get(index + 1..)
+            .and_then(|rest| rest.iter().position(|t| !is_comment(t)))
+            .map_or(index, |i| index + 1 + i)
+    }
+    /// Returns the last token of `node` that does not belong to a child node.
+    /// Names are stored without positions, so they are found by stepping past
+    /// the token before them.
+    fn last_own_token(&self, node: Node<'_, 'a>) -> usize {
+        let after_children = |at: At| self.after(self.last.max(self.index(at)));
+        let at = match node {
+            Node::Expression(expression) => match &expression.kind {
+                ExpressionKind::Parenthesized {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::StatementExpression {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::Call {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::Offsetof {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::TypesCompatible {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::Alignof {
+                    close_parenthesis, ..
+                }
+                | ExpressionKind::Sizeof {
+                    kind:
+                        SizeofKind::Type {
+                            close_parenthesis, ..
+                        },
+                    ..
+                } => *close_parenthesis,
+                ExpressionKind::GenericSelection(generic) => generic.close_parenthesis,
+                ExpressionKind::Index { close_bracket, .. } => *close_bracket,
+                ExpressionKind::PostIncrement { double_plus, .. } => *double_plus,
+                ExpressionKind::PostDecrement { double_minus, .. } => *double_minus,
+                ExpressionKind::Member { period: at, .. }
+                | ExpressionKind::MemberIndirect { arrow: at, .. } => {
+                    return self.after(self.index(*at));
+                }
+                ExpressionKind::LabelAddress(_) => return self.after(self.index(expression.at)),
+                _ => expression.at,
+            },
+            Node::Declaration(Declaration {
+                kind: DeclarationKind::Normal { semicolon, .. },
+                ..
+            })
+            | Node::MemberDeclaration(MemberDeclaration {
+                kind: MemberDeclarationKind::Member { semicolon, .. },
+                ..
+            })
+            | Node::AttributeDeclaration(AttributeDeclaration { semicolon, .. })
+            | Node::StaticAssertDeclaration(StaticAssertDeclaration { semicolon, .. })
+            | Node::ExpressionStatement(ExpressionStatement { semicolon, .. })
+            | Node::JumpStatement(JumpStatement { semicolon, .. })
+            | Node::IterationStatement(IterationStatement {
+                kind: IterationStatementKind::DoWhile { semicolon, .. },
+                ..
+            }) => *semicolon,
+            Node::TypeSpecifier(TypeSpecifier {
+                kind:
+                    TypeSpecifierKind::BitInt {
+                        close_parenthesis, ..
+                    },
+                ..
+            })
+            | Node::AtomicTypeSpecifier(AtomicTypeSpecifier {
+                close_parenthesis, ..
+            })
+            | Node::TypeofSpecifier(TypeofSpecifier {
+                close_parenthesis, ..
+            })
+            | Node::AlignmentSpecifier(AlignmentSpecifier {
+                close_parenthesis, ..
+            })
+            | Node::PragmaOperator(PragmaOperator {
+                close_parenthesis, ..
+            })
+            | Node::Declarator(Declarator {
+                asm_label:
+                    Some(AsmLabel {
+                        close_parenthesis, ..
+                    }),
+                ..
+            })
+            | Node::DirectDeclarator(DirectDeclarator {
+                kind:
+                    DirectDeclaratorKind::Parenthesized {
+                        close_parenthesis, ..
+                    },
+                ..
+            })
+            | Node::DirectDeclarator(DirectDeclarator {
+                kind:
+                    DirectDeclaratorKind::Function(
+                        FunctionDeclarator {
+                            close_parenthesis, ..
+                        },
+                        _,
+                    ),
+                ..
+            })
+            | Node::DirectAbstractDeclarator(DirectAbstractDeclarator {
+                kind:
+                    DirectAbstractDeclaratorKind::Parenthesized {
+                        close_parenthesis, ..
+                    },
+                ..
+            })
+            | Node::DirectAbstractDeclarator(DirectAbstractDeclarator {
+                kind:
+                    DirectAbstractDeclaratorKind::Function(
+                        FunctionAbstractDeclarator {
+                            close_parenthesis, ..
+                        },
+                        _,
+                    ),
+                ..
+            })
+            | Node::Attribute(Attribute {
+                argument_clause:
+                    Some(AttributeArgumentClause {
+                        close_parenthesis, ..
+                    }),
+                ..
+            }) => *close_parenthesis,
+            Node::DirectDeclarator(DirectDeclarator {
+                kind: DirectDeclaratorKind::Array(ArrayDeclarator { close_bracket, .. }, _),
+                ..
+            })
+            | Node::DirectAbstractDeclarator(DirectAbstractDeclarator {
+                kind:
+                    DirectAbstractDeclaratorKind::Array(
+                        ArrayAbstractDeclarator { close_bracket, .. },
+                        _,
+                    ),
+                ..
+            })
+            | Node::Designator(Designator {
+                kind: DesignatorKind::InBrackets { close_bracket, .. },
+                ..
+            })
+            | Node::AttributeSpecifier(AttributeSpecifier {
+                close_bracket_1: close_bracket,
+                ..
+            }) => *close_bracket,
+            Node::CompoundStatement(CompoundStatement { close_brace, .. })
+            | Node::BracedInitializer(BracedInitializer { close_brace, .. })
+            | Node::StructOrUnionSpecifier(StructOrUnionSpecifier {
+                members: Some((_, _, close_brace)),
+                ..
+            })
+            | Node::EnumSpecifier(EnumSpecifier {
+                enumerators: Some((_, _, _, close_brace)),
+                ..
+            }) => *close_brace,
+            Node::StructOrUnionSpecifier(StructOrUnionSpecifier {
+                struct_or_union: (keyword, _),
+                tag: Some(_),
+                ..
+            })
+            | Node::EnumSpecifier(EnumSpecifier {
+                enum_keyword: keyword,
+                tag: Some(_),
+                enum_type: None,
+                ..
+            }) => return after_children(*keyword),
+            Node::Designator(Designator {
+                kind: DesignatorKind::AfterPeriod { period, .. },
+                ..
+            }) => return self.after(self.index(*period)),
+            Node::Attribute(Attribute {
+                token:
+                    AttributeToken {
+                        prefix: Some((_, double_colon)),
+                        ..
+                    },
+                ..
+            }) => return self.after(self.index(*double_colon)),
+            Node::ParameterTypeList(ParameterTypeList {
+                ellipses: Some(ellipses),
+                ..
+            }) => *ellipses,
+            Node::Label(Label { colon, .. }) => *colon,
+            node => node.at(),
+        };
+        self.index(at)
+    }
+}
+impl<'a> Visitor<'a> for Extent<'_, 'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        self.first = self.first.min(self.index(node.at()));
+    }
+    fn leave(&mut self, node: Node<'_, 'a>) {
+        self.last = self.last.max(self.last_own_token(node));
+    }
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn member_alignment_is_a_power_of_two() {
+    assert_eq!(errors("struct S { alignas(16) char buf[16]; };"), []);
+    assert_eq!(errors("struct S { alignas(double) char c; };"), []);
+    assert_eq!(
+        errors("struct S { alignas(3) char buf[16]; };"),
+        [ValidationErrKind::InvalidAlignment]
+    );
+    assert_eq!(
+        errors("struct S { alignas(-8) int x; };"),
+        [ValidationErrKind::InvalidAlignment]
+    );
+}
+
+#[test]
+fn member_alignment_is_at_least_natural() {
+    assert_eq!(
+        errors("struct S { alignas(1) int x; };"),
+        [ValidationErrKind::AlignmentTooWeak]
+    );
+    assert_eq!(errors("struct S { alignas(0) int x; };"), []);
+}
+
+#[test]
+fn object_alignment_is_checked() {
+    assert_eq!(errors("alignas(16) int x;"), []);
+    assert_eq!(
+        errors("alignas(3) int x;"),
+        [ValidationErrKind::InvalidAlignment]
+    );
+}
+
+#[test]
+fn typedef_cannot_be_aligned() {
+    // Even a valid alignment is not permitted in a typedef.
+    assert_eq!(
+        errors("typedef alignas(16) int T;"),
+        [ValidationErrKind::AlignmentNotPermitted]
+    );
+    assert_eq!(
+        errors("typedef alignas(3) int T, U;"),
+        [ValidationErrKind::AlignmentNotPermitted]
+    );
+}
+
+#[test]
+fn bit_field_cannot_be_aligned() {
+    assert_eq!(
+        errors("struct S { alignas(8) int x : 3; };"),
+        [ValidationErrKind::AlignmentNotPermitted]
+    );
+    assert_eq!(
+        errors("struct S { alignas(8) int : 3; };"),
+        [ValidationErrKind::AlignmentNotPermitted]
+    );
+}
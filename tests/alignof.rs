@@ -0,0 +1,35 @@
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, Parser},
+    token::{Token, TokenKind},
+};
+
+fn kinds<'a>(tokens: &[Token<'a>]) -> Vec<TokenKind<'a>> {
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+#[test]
+fn both_spellings_parse_identically() {
+    let (c23, _) = Lexer::new("int a = alignof(double);").lex();
+    let (c11, _) = Lexer::new("int a = _Alignof(double);").lex();
+    assert_eq!(kinds(&c23), kinds(&c11));
+    assert!(Parser::new(&c23).parse().is_ok());
+    assert!(Parser::new(&c11).parse().is_ok());
+}
+
+#[test]
+fn expression_operand_is_rejected() {
+    for (src, at) in [
+        ("int a = alignof x;", TokenKind::Identifier("x")),
+        (
+            "void f(int x) { int a = _Alignof x; }",
+            TokenKind::Identifier("x"),
+        ),
+        ("int a = alignof(1);", TokenKind::OpenParenthesis),
+    ] {
+        let (tokens, _) = Lexer::new(src).lex();
+        let errors = Parser::new(&tokens).parse().errors;
+        let first = errors.first().map(|err| (err.expected, err.at.kind));
+        assert_eq!(first, Some((Expected::AlignofOperand, at)), "{src}");
+    }
+}
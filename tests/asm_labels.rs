@@ -0,0 +1,167 @@
+//! GNU `asm("name")` labels after a declarator, which name the register or
+//! assembler symbol of what it declares.
+
+use ecc::{
+    ast::{
+        DeclarationKind, Declarator, ExternalDeclaration, ExternalDeclarationKind,
+        StorageClassSpecifierKind,
+    },
+    lexer::Lexer,
+    parse_decl,
+    parser::{Dialect, Expected, Parser, ParserOptions},
+    symbols::SymbolTable,
+    types::{BaseType, TypeKind},
+    visit::{Node, Visitor, walk_external_declaration},
+};
+
+/// Returns the first declarator of a declaration and whether it is initialized.
+fn declarator<'d, 'a>(declaration: &'d ExternalDeclaration<'a>) -> (&'d Declarator<'a>, bool) {
+    let ExternalDeclarationKind::Declaration(declaration) = &declaration.kind else {
+        panic!("{declaration:?}");
+    };
+    let DeclarationKind::Normal {
+        init_declarators: Some(init_declarators),
+        ..
+    } = &declaration.kind
+    else {
+        panic!("{declaration:?}");
+    };
+    let init_declarator = init_declarators.iter().next().unwrap();
+    (
+        &init_declarator.declarator,
+        init_declarator.initializer.is_some(),
+    )
+}
+
+/// Returns the name an asm label gives the first declarator.
+fn label(declarator: &Declarator) -> String {
+    let units = declarator.asm_label.as_ref().unwrap().decode().unwrap();
+    units
+        .into_iter()
+        .map(|u| char::from_u32(u).unwrap())
+        .collect()
+}
+
+/// Records every declarator with an asm label, and the name it gives it.
+#[derive(Default)]
+struct Labels<'a>(Vec<(&'a str, String)>);
+impl<'a> Visitor<'a> for Labels<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Declarator(declarator) = node
+            && declarator.asm_label.is_some()
+        {
+            self.0.push((declarator.name().0, label(declarator)));
+        }
+    }
+}
+
+fn labels<'a>(declaration: &ExternalDeclaration<'a>) -> Vec<(&'a str, String)> {
+    let mut labels = Labels::default();
+    walk_external_declaration(&mut labels, declaration);
+    labels.0
+}
+
+fn errors(src: &str, dialect: Dialect) -> Vec<Expected<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect,
+        ..Default::default()
+    };
+    let result = Parser::with_options(&tokens, options).parse();
+    result.errors.iter().map(|err| err.expected).collect()
+}
+
+#[test]
+fn label_before_an_initializer() {
+    let declaration = parse_decl!("int x asm(\"r0\") = 1;");
+    let (declarator, initialized) = declarator(&declaration);
+    assert_eq!(declarator.name().0, "x");
+    assert_eq!(label(declarator), "r0");
+    assert!(initialized);
+}
+
+#[test]
+fn label_spellings() {
+    for src in [
+        "extern int y asm(\"sym\");",
+        "extern int y __asm(\"sym\");",
+        "extern int y __asm__(\"sym\");",
+    ] {
+        let declaration = parse_decl!(src);
+        assert_eq!(label(declarator(&declaration).0), "sym", "{src}");
+    }
+    let declaration = parse_decl!("void f(void) asm(\"myf\");");
+    assert_eq!(label(declarator(&declaration).0), "myf");
+}
+
+#[test]
+fn adjacent_strings_are_concatenated() {
+    let declaration = parse_decl!("extern int y __asm__(\"my\" \"_\" \"sym\");");
+    let declarator = declarator(&declaration).0;
+    assert_eq!(
+        declarator.asm_label.as_ref().unwrap().name.iter().count(),
+        3
+    );
+    assert_eq!(label(declarator), "my_sym");
+}
+
+#[test]
+fn label_needs_one_or_more_strings() {
+    for src in ["int x asm() = 1;", "int x asm(r0) = 1;", "int x asm(0);"] {
+        let errors = errors(src, Dialect::Gnu);
+        assert_eq!(errors.first(), Some(&Expected::AsmLabelName), "{src}");
+    }
+    assert_ne!(errors("int x asm(\"r0\";", Dialect::Gnu), []);
+}
+
+#[test]
+fn labels_need_the_gnu_dialect() {
+    assert_ne!(errors("extern int y asm(\"sym\");", Dialect::Standard), []);
+    assert_eq!(errors("extern int y asm(\"sym\");", Dialect::Gnu), []);
+}
+
+#[test]
+fn register_variable() {
+    let definition = parse_decl!("void f(void) { register int x asm(\"r0\") = 1; x; }");
+    assert_eq!(labels(&definition), [("x", "r0".to_string())]);
+}
+
+#[test]
+fn register_parameter() {
+    let src = "int f(register int x asm(\"r0\"), int y) { return x + y; }";
+    let definition = parse_decl!(src);
+    assert_eq!(labels(&definition), [("x", "r0".to_string())]);
+    assert_eq!(errors(src, Dialect::Gnu), []);
+    assert_ne!(errors(src, Dialect::Standard), []);
+}
+
+#[test]
+fn labels_do_not_change_the_declared_type() {
+    let src = "int f(register int x asm(\"r0\")) { register long y asm(\"r1\"); return x; }";
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let ast = Parser::with_options(&tokens, options).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let types: Vec<_> = symbols
+        .symbols()
+        .iter()
+        .filter(|symbol| symbol.name != "f")
+        .map(|symbol| {
+            let TypeKind::Base(base) = &symbol.ty.as_ref().unwrap().kind else {
+                panic!("{symbol:?}");
+            };
+            (symbol.name, base.clone(), symbol.storage_class)
+        })
+        .collect();
+    let register = Some(StorageClassSpecifierKind::Register);
+    assert_eq!(
+        types,
+        [
+            ("x", BaseType::Int, register),
+            ("y", BaseType::Long, register)
+        ]
+    );
+}
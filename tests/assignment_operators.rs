@@ -0,0 +1,47 @@
+use ecc::{
+    ast::{AssignmentOperator, ExpressionKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the operator of every assignment expression.
+struct Operators(Vec<AssignmentOperator>);
+impl<'a> Visitor<'a> for Operators {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Expression(expression) = node
+            && let ExpressionKind::Assign {
+                operator: (_, operator),
+                ..
+            } = expression.kind
+        {
+            self.0.push(operator);
+        }
+    }
+}
+
+#[test]
+fn every_assignment_operator_is_parsed() {
+    use AssignmentOperator::*;
+
+    for (spelling, expected) in [
+        ("=", Assign),
+        ("*=", Multiply),
+        ("/=", Divide),
+        ("%=", Modulo),
+        ("+=", Add),
+        ("-=", Subtract),
+        ("<<=", ShiftLeft),
+        (">>=", ShiftRight),
+        ("&=", And),
+        ("^=", Xor),
+        ("|=", Or),
+    ] {
+        let src = format!("void f(int a, int b) {{ a {spelling} b; }}");
+        let (tokens, _) = Lexer::new(&src).lex();
+        let ast = Parser::new(&tokens).parse().unwrap();
+        let mut operators = Operators(Vec::new());
+        walk_translation_unit(&mut operators, &ast);
+        assert_eq!(operators.0, [expected], "{src}");
+    }
+}
@@ -0,0 +1,65 @@
+use ecc::{
+    ast::{Expression, ExpressionKind},
+    lexer::Lexer,
+    parser::Parser,
+    validate::{ValidationErrKind, validate},
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Renders each expression statement with explicit parentheses.
+struct Shapes(Vec<String>);
+impl<'a> Visitor<'a> for Shapes {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::ExpressionStatement(statement) = node
+            && let Some(expression) = &statement.expression
+        {
+            self.0.push(shape(expression));
+        }
+    }
+}
+
+fn shape(expression: &Expression) -> String {
+    match &expression.kind {
+        ExpressionKind::Identifier(name) => name.to_string(),
+        ExpressionKind::Integer(int) => int.source.to_string(),
+        ExpressionKind::Assign { left, right, .. } => {
+            format!("({} = {})", shape(left), shape(right))
+        }
+        kind => panic!("unexpected expression {kind:?}"),
+    }
+}
+
+fn errors(body: &str) -> Vec<ValidationErrKind> {
+    let src = format!("int g(void); void f(int a, int b, int *p, int i) {{ {body} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    validate(&ast).into_iter().map(|err| err.kind).collect()
+}
+
+#[test]
+fn chained_assignment_nests_to_the_right() {
+    let src = "void f(int a, int b, int c) { a = b = c; a = b = c = 1; }";
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut shapes = Shapes(Vec::new());
+    walk_translation_unit(&mut shapes, &ast);
+    assert_eq!(shapes.0, ["(a = (b = c))", "(a = (b = (c = 1)))"]);
+}
+
+#[test]
+fn lvalue_targets_are_accepted() {
+    for body in ["a = b;", "*p = 1;", "p[i] = 1;", "(a) = 1;", "a = b = 2;"] {
+        assert_eq!(errors(body), [], "{body}");
+    }
+}
+
+#[test]
+fn rvalue_targets_are_reported() {
+    for body in ["(a + 1) = 2;", "1 = a;", "g() = 1;", "a = (b + 1) = 2;"] {
+        assert_eq!(
+            errors(body),
+            [ValidationErrKind::AssignmentToRvalue],
+            "{body}"
+        );
+    }
+}
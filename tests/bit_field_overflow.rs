@@ -0,0 +1,79 @@
+use ecc::{
+    lexer::Lexer,
+    lint::{LintKind, LintOptions, lint},
+    parser::Parser,
+};
+
+const STRUCT: &str =
+    "struct S { unsigned flags : 3; int level : 3; _Bool on : 1; unsigned whole; };";
+
+fn lints_with(body: &str, bit_field_overflow: bool) -> Vec<String> {
+    let src = format!("{STRUCT} void f(struct S s, struct S *p, int n) {{ {body} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let options = LintOptions {
+        bit_field_overflow,
+        ..Default::default()
+    };
+    lint(&ast, options)
+        .into_iter()
+        .map(|lint| match lint.kind {
+            LintKind::BitFieldOverflow { member, width } => format!("{member}:{width}"),
+            other => format!("{other:?}"),
+        })
+        .collect()
+}
+
+fn lints(body: &str) -> Vec<String> {
+    lints_with(body, true)
+}
+
+#[test]
+fn in_range_constants() {
+    for body in [
+        "s.flags = 0;",
+        "s.flags = 7;",
+        "p->flags = 5;",
+        "s.flags = 1 + 2 * 3;",
+        "s.level = 3;",
+        "s.level = -4;",
+    ] {
+        assert_eq!(lints(body), Vec::<String>::new(), "{body}");
+    }
+}
+
+#[test]
+fn out_of_range_constants() {
+    for (body, lint) in [
+        ("s.flags = 9;", "flags:3"),
+        ("s.flags = 8;", "flags:3"),
+        ("p->flags = 9;", "flags:3"),
+        ("(s.flags) = 9;", "flags:3"),
+        ("s.flags = -1;", "flags:3"),
+        ("s.level = 4;", "level:3"),
+        ("s.level = -5;", "level:3"),
+    ] {
+        assert_eq!(lints(body), [lint], "{body}");
+    }
+}
+
+#[test]
+fn not_flagged() {
+    for body in [
+        // The value is not a constant.
+        "s.flags = n;",
+        // Every value converts to a `bool`.
+        "s.on = 5;",
+        // The member is not a bit-field.
+        "s.whole = 9;",
+        // Only plain assignment stores the constant itself.
+        "s.flags += 9;",
+    ] {
+        assert_eq!(lints(body), Vec::<String>::new(), "{body}");
+    }
+}
+
+#[test]
+fn lint_is_opt_in() {
+    assert_eq!(lints_with("s.flags = 9;", false), Vec::<String>::new());
+}
@@ -0,0 +1,71 @@
+//! Block items that start with an attribute sequence, which is part of a
+//! declaration, an attribute declaration or an attributed statement.
+
+use ecc::{
+    ast::{BlockItemKind, DeclarationKind, UnlabeledStatementKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the kind of each block item and whether it carries attributes.
+struct Items(Vec<(&'static str, bool)>);
+impl<'a> Visitor<'a> for Items {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        let Node::BlockItem(item) = node else {
+            return;
+        };
+        let item = match &item.kind {
+            BlockItemKind::Declaration(declaration) => match &declaration.kind {
+                DeclarationKind::Normal { attributes, .. } => ("declaration", attributes.is_some()),
+                DeclarationKind::Attribute(_) => ("attribute declaration", true),
+                _ => ("declaration", false),
+            },
+            BlockItemKind::Unlabeled(statement) => match &statement.kind {
+                UnlabeledStatementKind::Expression(e) => ("expression", e.attributes.is_some()),
+                UnlabeledStatementKind::Primary(attributes, _) => ("primary", attributes.is_some()),
+                UnlabeledStatementKind::Jump(attributes, _) => ("jump", attributes.is_some()),
+            },
+            BlockItemKind::Label(label) => ("label", label.attributes.is_some()),
+        };
+        self.0.push(item);
+    }
+}
+
+fn items(body: &str) -> Vec<(&'static str, bool)> {
+    let src = format!("typedef int T; int f(int x) {{ {body} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut items = Items(Vec::new());
+    walk_translation_unit(&mut items, &ast);
+    items.0
+}
+
+#[test]
+fn attributed_declarations() {
+    assert_eq!(items("[[maybe_unused]] int x;"), [("declaration", true)]);
+    assert_eq!(items("[[maybe_unused]] T y;"), [("declaration", true)]);
+    assert_eq!(
+        items("[[maybe_unused]];"),
+        [("attribute declaration", true)]
+    );
+}
+
+#[test]
+fn attributed_statements() {
+    assert_eq!(items("[[unlikely]] return 0;"), [("jump", true)]);
+    assert_eq!(items("[[unlikely]] if (x) return 1;"), [("primary", true)]);
+    assert_eq!(items("[[vendor::hint]] x++;"), [("expression", true)]);
+    assert_eq!(
+        items("[[maybe_unused]] done: return 0;"),
+        [("label", true), ("jump", false)]
+    );
+}
+
+#[test]
+fn declaration_followed_by_attributed_statement() {
+    assert_eq!(
+        items("[[maybe_unused]] int y = x; [[unlikely]] return y;"),
+        [("declaration", true), ("jump", true)]
+    );
+}
@@ -0,0 +1,69 @@
+//! `true` and `false` are integer constant expressions of type `bool`, wherever
+//! a constant is required.
+
+use ecc::{
+    ast::LabelKind,
+    eval::{EvalConfig, Evaluator, IntegerType, Value},
+    lexer::Lexer,
+    parser::Parser,
+    validate::validate,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Evaluates every static assertion, enumerator value and case label.
+struct Constants(Vec<Value>);
+impl<'a> Visitor<'a> for Constants {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        let expression = match node {
+            Node::StaticAssertDeclaration(assert) => &assert.condition,
+            Node::Enumerator(enumerator) => match &enumerator.value {
+                Some((_, value)) => value,
+                None => return,
+            },
+            Node::Label(label) => match &label.kind {
+                LabelKind::Case { value, .. } => value,
+                _ => return,
+            },
+            _ => return,
+        };
+        let evaluator = Evaluator::new(EvalConfig::default());
+        self.0.push(evaluator.evaluate(expression).unwrap());
+    }
+}
+
+fn constants(src: &str) -> Vec<Value> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    assert_eq!(validate(&ast), [], "{src:?}");
+    let mut constants = Constants(Vec::new());
+    walk_translation_unit(&mut constants, &ast);
+    constants.0
+}
+
+fn bool(value: i128) -> Value {
+    Value {
+        value,
+        ty: IntegerType::Bool,
+    }
+}
+
+#[test]
+fn static_assert_on_true() {
+    assert_eq!(constants("_Static_assert(true);"), [bool(1)]);
+    assert_eq!(constants("_Static_assert(true, \"ok\");"), [bool(1)]);
+    assert_eq!(constants("static_assert(!false);")[0].value, 1);
+}
+
+#[test]
+fn enumerator_set_to_true() {
+    assert_eq!(
+        constants("enum { X = true, Y = false };"),
+        [bool(1), bool(0)]
+    );
+}
+
+#[test]
+fn case_false() {
+    let src = "void f(int x) { switch (x) { case false: break; case true: break; } }";
+    assert_eq!(constants(src), [bool(0), bool(1)]);
+}
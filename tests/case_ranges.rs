@@ -0,0 +1,75 @@
+//! GNU `case low ... high:` labels, which cover an inclusive range of values.
+
+use ecc::{
+    ast::{ExpressionKind, LabelKind},
+    lexer::Lexer,
+    parse_decl,
+    parser::{Dialect, Parser, ParserOptions},
+    validate::{ValidationErrKind, validate},
+    visit::{Node, Visitor, walk_external_declaration},
+};
+
+/// Records the bounds of every case range.
+#[derive(Default)]
+struct Ranges<'a>(Vec<(ExpressionKind<'a>, ExpressionKind<'a>)>);
+impl<'a> Visitor<'a> for Ranges<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Label(label) = node
+            && let LabelKind::CaseRange { low, high, .. } = &label.kind
+        {
+            self.0.push((low.kind.clone(), high.kind.clone()));
+        }
+    }
+}
+
+/// Validates `cases` as the body of a switch in the GNU dialect.
+fn errors(cases: &str) -> Vec<ValidationErrKind> {
+    let src = format!("void f(int x) {{ switch (x) {{ {cases} }} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let ast = Parser::with_options(&tokens, options).parse().unwrap();
+    validate(&ast).into_iter().map(|err| err.kind).collect()
+}
+
+#[test]
+fn case_range_is_parsed() {
+    let definition = parse_decl!("void f(int x) { switch (x) { case 1 ... 5: break; case 7: ; } }");
+    let mut ranges = Ranges::default();
+    walk_external_declaration(&mut ranges, &definition);
+    let [(ExpressionKind::Integer(low), ExpressionKind::Integer(high))] = ranges.0.as_slice()
+    else {
+        panic!("{:?}", ranges.0);
+    };
+    assert_eq!((low.source, high.source), ("1", "5"));
+}
+
+#[test]
+fn empty_case_range_is_reported() {
+    assert_eq!(
+        errors("case 5 ... 1: break;"),
+        [ValidationErrKind::EmptyCaseRange]
+    );
+    assert_eq!(
+        errors("case 'z' ... 'a': break;"),
+        [ValidationErrKind::EmptyCaseRange]
+    );
+    for cases in [
+        "case 1 ... 5: break;",
+        "case 3 ... 3: break;",
+        "case -2 ... 2: break;",
+        "case 'a' ... 'z': break;",
+        "case 1 + 1 ... 2 * 4: break;",
+    ] {
+        assert_eq!(errors(cases), [], "{cases}");
+    }
+}
+
+#[test]
+fn case_ranges_need_the_gnu_dialect() {
+    let src = "void f(int x) { switch (x) { case 1 ... 5: break; } }";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert!(!Parser::new(&tokens).parse().is_ok());
+}
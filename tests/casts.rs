@@ -0,0 +1,67 @@
+//! Golden shapes of parenthesized expressions and casts, which are told apart
+//! by whether the parenthesized tokens name a type.
+
+use ecc::{
+    ast::{Expression, ExpressionKind},
+    lexer::Lexer,
+    parser::Parser,
+    token::Token,
+    visit::{Node, source_range},
+};
+
+fn shape(expression: &Expression, src: &str, tokens: &[Token]) -> String {
+    let shape = |e: &Expression| shape(e, src, tokens);
+    match &expression.kind {
+        ExpressionKind::Identifier(name) => name.to_string(),
+        ExpressionKind::Integer(int) => int.source.to_string(),
+        ExpressionKind::Parenthesized { inner, .. } => format!("paren({})", shape(inner)),
+        ExpressionKind::Cast {
+            type_name, right, ..
+        } => {
+            let (start, end) = source_range(Node::TypeName(type_name), tokens);
+            let type_name = &src[start.offset..end.offset];
+            format!("cast<{type_name}>({})", shape(right))
+        }
+        ExpressionKind::Unary(operator, operand) => format!("{operator}{}", shape(operand)),
+        ExpressionKind::Binary {
+            left,
+            operator: (_, operator),
+            right,
+        } => format!("({} {operator} {})", shape(left), shape(right)),
+        ExpressionKind::Call { left, .. } => format!("call({})", shape(left)),
+        kind => panic!("unexpected expression {kind:?}"),
+    }
+}
+
+/// Parses `expression` as if in a function body after the typedef `MyType`
+/// and the objects `x` and `y`.
+fn parse(expression: &str) -> String {
+    let src = format!("typedef long MyType; int x, y; void f(void) {{ {expression}; }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let start = tokens
+        .iter()
+        .position(|t| t.at.offset == src.find(expression).unwrap())
+        .unwrap();
+    let mut parser = Parser::new(&tokens)
+        .with_predeclared_typedefs(&["MyType"])
+        .starting_at(start);
+    let parsed = parser.parse_next_expression().unwrap();
+    shape(&parsed, &src, &tokens)
+}
+
+#[test]
+fn casts_of_keyword_and_typedef_types() {
+    assert_eq!(parse("(int)-1"), "cast<int>(-1)");
+    assert_eq!(parse("(unsigned char)x"), "cast<unsigned char>(x)");
+    assert_eq!(parse("(MyType)y"), "cast<MyType>(y)");
+    assert_eq!(parse("(const MyType *)&y"), "cast<const MyType *>(&y)");
+    assert_eq!(parse("(int)(long)x"), "cast<int>(cast<long>(x))");
+}
+
+#[test]
+fn parenthesized_expressions_are_not_casts() {
+    assert_eq!(parse("(x)"), "paren(x)");
+    assert_eq!(parse("(x)-1"), "(paren(x) - 1)");
+    assert_eq!(parse("(x)(y)"), "call(paren(x))");
+    assert_eq!(parse("(MyType)-(x)"), "cast<MyType>(-paren(x))");
+}
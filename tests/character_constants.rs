@@ -0,0 +1,143 @@
+use ecc::{
+    ast::{CharLiteral, CharValueErr, StringLiteral},
+    lexer::{Lexer, LexerOptions, Standard},
+    parser::Parser,
+    token::{StringEncoding, TokenKind},
+};
+
+/// Lexes a single character constant and returns its value.
+fn value(src: &str) -> Result<u32, CharValueErr> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let TokenKind::Character(literal, encoding) = tokens[0].kind else {
+        panic!("{src} is not a character constant: {:?}", tokens[0].kind);
+    };
+    let literal = CharLiteral {
+        at: tokens[0].at,
+        literal,
+        encoding,
+    };
+    literal.value()
+}
+
+/// Lexes a single string literal and returns its code units.
+fn decode(src: &str) -> Result<Vec<u32>, CharValueErr> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let TokenKind::String(literal, encoding) = tokens[0].kind else {
+        panic!("{src} is not a string literal: {:?}", tokens[0].kind);
+    };
+    let literal = StringLiteral {
+        at: tokens[0].at,
+        literal,
+        encoding,
+    };
+    literal.decode()
+}
+
+#[test]
+fn simple_and_escaped_characters() {
+    assert_eq!(value("'a'"), Ok(97));
+    assert_eq!(value(r"'\n'"), Ok(10));
+    assert_eq!(value(r"'\x41'"), Ok(65));
+    assert_eq!(value(r"'\0'"), Ok(0));
+    assert_eq!(value(r"'\101'"), Ok(65));
+    assert_eq!(value(r"'\''"), Ok(39));
+    assert_eq!(value(r"'é'"), Ok(0xC3A9));
+}
+
+#[test]
+fn multi_character_constants_pack_big_endian() {
+    assert_eq!(value("'AB'"), Ok(0x4142));
+    assert_eq!(value("'ABCD'"), Ok(0x41424344));
+    assert_eq!(value("'ABCDE'"), Ok(0x42434445));
+}
+
+#[test]
+fn prefixed_constants_hold_one_code_unit() {
+    assert_eq!(value("L'a'"), Ok(97));
+    assert_eq!(value("u'\\u00e9'"), Ok(0xE9));
+    assert_eq!(value("U'\\U0001F600'"), Ok(0x1F600));
+    assert_eq!(value("u8'a'"), Ok(97));
+    assert_eq!(value("L'ab'"), Err(CharValueErr::MultiCharacter));
+    assert_eq!(value("u8'\\u00e9'"), Err(CharValueErr::MultiCharacter));
+}
+
+#[test]
+fn invalid_constants_are_errors() {
+    assert_eq!(value("''"), Err(CharValueErr::Empty));
+    assert_eq!(value(r"'\q'"), Err(CharValueErr::InvalidEscape));
+    assert_eq!(value(r"'\x'"), Err(CharValueErr::InvalidEscape));
+}
+
+#[test]
+fn hexadecimal_escapes_take_every_digit() {
+    assert_eq!(value(r"'\x41'"), Ok(0x41));
+    assert_eq!(value(r"'\x041'"), Ok(0x41));
+    assert_eq!(decode(r#""\x41g""#), Ok(vec![0x41, 0x67]));
+    assert_eq!(decode(r#""\xABC""#), Err(CharValueErr::OutOfRange));
+    assert_eq!(decode(r#"u"\xABC""#), Ok(vec![0xABC]));
+    assert_eq!(decode(r#"L"\xABC\x1""#), Ok(vec![0xABC, 0x1]));
+}
+
+#[test]
+fn escapes_too_large_for_a_code_unit_are_out_of_range() {
+    assert_eq!(value(r"'\x1FF'"), Err(CharValueErr::OutOfRange));
+    assert_eq!(value(r"'\777'"), Err(CharValueErr::OutOfRange));
+    assert_eq!(value(r"u'\x1FFFF'"), Err(CharValueErr::OutOfRange));
+    assert_eq!(value(r"U'\x1FFFF'"), Ok(0x1FFFF));
+    assert_eq!(value(r"L'\x123456789'"), Err(CharValueErr::OutOfRange));
+}
+
+#[test]
+fn universal_character_names_have_fixed_length() {
+    assert_eq!(decode(r#"U"\u00411""#), Ok(vec![0x41, 0x31]));
+    assert_eq!(value(r"'\u12'"), Err(CharValueErr::InvalidEscape));
+}
+
+fn lex(src: &str, standard: Standard) -> Vec<TokenKind<'_>> {
+    let options = LexerOptions {
+        standard,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options(src, options).lex();
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+#[test]
+fn utf8_character_constant_is_c23() {
+    assert_eq!(
+        lex("u8'a'", Standard::C23),
+        [
+            TokenKind::Character("a", StringEncoding::UTF8),
+            TokenKind::Eof
+        ]
+    );
+    // The prefix of a string literal is older, and unaffected.
+    assert_eq!(
+        lex("u8\"a\"", Standard::C11),
+        [TokenKind::String("a", StringEncoding::UTF8), TokenKind::Eof]
+    );
+}
+
+#[test]
+fn utf8_character_constant_before_c23_is_diagnosed() {
+    for standard in [Standard::C11, Standard::C17] {
+        assert_eq!(
+            lex("u8'a'", standard),
+            [
+                TokenKind::Identifier("u8"),
+                TokenKind::Character("a", StringEncoding::None),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    // An identifier followed by a constant is not an expression.
+    let options = LexerOptions {
+        standard: Standard::C11,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options("char c = u8'a';", options).lex();
+    assert!(!Parser::new(&tokens).parse().is_ok());
+    let (tokens, _) = Lexer::new("char c = u8'a';").lex();
+    assert!(Parser::new(&tokens).parse().is_ok());
+}
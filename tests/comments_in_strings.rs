@@ -0,0 +1,80 @@
+//! Comment delimiters inside string literals and character constants are
+//! ordinary characters, whether or not comments are kept as tokens.
+
+use ecc::{
+    lexer::{Lexer, LexerOptions},
+    parser::Parser,
+    token::{StringEncoding, TokenKind},
+};
+
+fn kinds(src: &str, keep_comments: bool) -> Vec<TokenKind<'_>> {
+    let options = LexerOptions {
+        keep_comments,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options(src, options).lex();
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+#[test]
+fn comment_delimiters_stay_in_the_string() {
+    for keep_comments in [false, true] {
+        assert_eq!(
+            kinds(r#""use /* x */ instead // of y""#, keep_comments),
+            [
+                TokenKind::String("use /* x */ instead // of y", StringEncoding::None),
+                TokenKind::Eof,
+            ],
+            "keep_comments: {keep_comments}"
+        );
+        assert_eq!(
+            kinds(r#""/*" x "*/""#, keep_comments),
+            [
+                TokenKind::String("/*", StringEncoding::None),
+                TokenKind::Identifier("x"),
+                TokenKind::String("*/", StringEncoding::None),
+                TokenKind::Eof,
+            ],
+            "keep_comments: {keep_comments}"
+        );
+    }
+}
+
+#[test]
+fn comment_after_the_string_is_still_a_comment() {
+    assert_eq!(
+        kinds("\"//\" // real\n'/'", true),
+        [
+            TokenKind::String("//", StringEncoding::None),
+            TokenKind::LineComment(" real"),
+            TokenKind::Character("/", StringEncoding::None),
+            TokenKind::Eof,
+        ]
+    );
+    assert_eq!(
+        kinds("\"//\" // real\n'/'", false),
+        [
+            TokenKind::String("//", StringEncoding::None),
+            TokenKind::Character("/", StringEncoding::None),
+            TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn attribute_argument_keeps_its_comment_delimiters() {
+    for keep_comments in [false, true] {
+        let options = LexerOptions {
+            keep_comments,
+            ..Default::default()
+        };
+        let src = r#"[[deprecated("use /* x */ instead")]] void f(void);"#;
+        let (tokens, _) = Lexer::with_options(src, options).lex();
+        assert!(
+            tokens
+                .iter()
+                .any(|t| t.kind == TokenKind::String("use /* x */ instead", StringEncoding::None))
+        );
+        assert!(Parser::new(&tokens).parse().is_ok());
+    }
+}
@@ -0,0 +1,15 @@
+//! Fixtures shared by the integration tests.
+
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    validate::{ValidationErrKind, validate},
+};
+
+/// Parses `src`, which must parse without errors, and returns the kinds of the
+/// validation errors it has.
+pub fn errors(src: &str) -> Vec<ValidationErrKind> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    validate(&ast).into_iter().map(|err| err.kind).collect()
+}
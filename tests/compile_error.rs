@@ -0,0 +1,63 @@
+use ecc::{
+    ast::TranslationUnit,
+    compile::CompileError,
+    lexer::{LexError, Lexer, lex_errors},
+    parser::{Expected, ParseErr, Parser},
+    token::{Token, TokenKind},
+};
+
+fn lex(src: &str) -> Result<Vec<Token<'_>>, LexError> {
+    let (tokens, _) = Lexer::new(src).lex();
+    if let Some(err) = lex_errors(&tokens).next() {
+        return Err(err);
+    }
+    Ok(tokens)
+}
+
+fn parse<'a>(tokens: &[Token<'a>]) -> Result<TranslationUnit<'a>, ParseErr<'a>> {
+    let (ast, errors) = Parser::new(tokens).parse().into_parts();
+    match (ast, errors.first()) {
+        (Ok(ast), None) => Ok(ast),
+        (_, Some(&err)) => Err(err),
+        (Err(()), None) => unreachable!("parsing failed without an error"),
+    }
+}
+
+/// Lexes and parses `src`, stopping at the first error of either stage.
+fn compile(src: &str) -> Result<TranslationUnit<'_>, CompileError<'_>> {
+    let tokens = lex(src)?;
+    Ok(parse(&tokens)?)
+}
+
+#[test]
+fn lex_errors_convert_and_display() {
+    let err = compile("int a;\nint b = 1 @ 2;").unwrap_err();
+    let CompileError::Lex(lex) = err else {
+        panic!("expected a lex error, got {err:?}");
+    };
+    assert_eq!((lex.at.offset, lex.end), (17, 18));
+    assert_eq!(err.to_string(), "2:11: invalid token");
+}
+
+#[test]
+fn parse_errors_convert_and_display() {
+    let err = compile("int a;\nint b = alignof b;").unwrap_err();
+    let CompileError::Parse(parse) = err else {
+        panic!("expected a parse error, got {err:?}");
+    };
+    assert_eq!(parse.expected, Expected::AlignofOperand);
+    assert_eq!(parse.at.kind, TokenKind::Identifier("b"));
+    assert_eq!(
+        err.to_string(),
+        "2:17: expected a parenthesized type name after `alignof`, found `b`"
+    );
+}
+
+#[test]
+fn preprocessor_errors_display() {
+    let err = CompileError::Preprocess("main.c: No such file".to_string());
+    assert_eq!(
+        err.to_string(),
+        "preprocessing failed: main.c: No such file"
+    );
+}
@@ -0,0 +1,65 @@
+use ecc::{
+    ast::{ExpressionKind, StorageClassSpecifierKind},
+    lexer::Lexer,
+    parser::Parser,
+    validate::{ValidationErrKind, validate},
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the storage-class specifiers of every compound literal.
+struct StorageClasses(Vec<Vec<StorageClassSpecifierKind>>);
+impl<'a> Visitor<'a> for StorageClasses {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Expression(expression) = node
+            && let ExpressionKind::CompoundLiteral(literal) = &expression.kind
+        {
+            let kinds = literal.storage_class.iter().flat_map(|list| list.iter());
+            self.0.push(kinds.map(|s| s.kind).collect());
+        }
+    }
+}
+
+fn check(src: &str) -> (Vec<Vec<StorageClassSpecifierKind>>, Vec<ValidationErrKind>) {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut storage_classes = StorageClasses(Vec::new());
+    walk_translation_unit(&mut storage_classes, &ast);
+    let errors = validate(&ast).into_iter().map(|err| err.kind).collect();
+    (storage_classes.0, errors)
+}
+
+#[test]
+fn storage_classes_are_captured() {
+    use StorageClassSpecifierKind::*;
+
+    let src = "void f(void) {
+        int *a = (static int){0};
+        int b = (constexpr int){1};
+        int c = (int){2};
+        const int *d = (static const int){5};
+        int *e = (register int[]){3, 4};
+        int *g = (static thread_local int){6};
+    }";
+    let (storage_classes, errors) = check(src);
+    assert_eq!(
+        storage_classes,
+        [
+            vec![Static],
+            vec![Constexpr],
+            vec![],
+            vec![Static],
+            vec![Register],
+            vec![Static, ThreadLocal],
+        ]
+    );
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn other_storage_classes_are_invalid() {
+    for storage_class in ["extern", "typedef", "auto"] {
+        let src = format!("void f(void) {{ int x = ({storage_class} int){{0}}; }}");
+        let (_, errors) = check(&src);
+        assert_eq!(errors, [ValidationErrKind::InvalidStorageClass], "{src}");
+    }
+}
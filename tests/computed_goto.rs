@@ -0,0 +1,77 @@
+//! GNU label addresses `&&label` and computed gotos `goto *expression;`.
+
+use ecc::{
+    ast::{ExpressionKind, JumpStatementKind},
+    lexer::Lexer,
+    parse_decl,
+    parser::{Dialect, Parser, ParserOptions},
+    visit::{Node, Visitor, walk_external_declaration},
+};
+
+/// Records the label addresses taken and the targets of computed gotos.
+#[derive(Default)]
+struct Jumps<'a> {
+    addresses: Vec<&'a str>,
+    computed_targets: Vec<ExpressionKind<'a>>,
+}
+impl<'a> Visitor<'a> for Jumps<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        match node {
+            Node::Expression(expression) => {
+                if let ExpressionKind::LabelAddress(label) = expression.kind {
+                    self.addresses.push(label);
+                }
+            }
+            Node::JumpStatement(jump) => {
+                if let JumpStatementKind::ComputedGoto { target, .. } = &jump.kind {
+                    self.computed_targets.push(target.kind.clone());
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn parses(src: &str, dialect: Dialect) -> bool {
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect,
+        ..Default::default()
+    };
+    Parser::with_options(&tokens, options).parse().is_ok()
+}
+
+#[test]
+fn label_address_and_computed_goto() {
+    let definition = parse_decl!("void f(void){ void *p = &&done; goto *p; done:; }");
+    let mut jumps = Jumps::default();
+    walk_external_declaration(&mut jumps, &definition);
+    assert_eq!(jumps.addresses, ["done"]);
+    assert_eq!(jumps.computed_targets, [ExpressionKind::Identifier("p")]);
+}
+
+#[test]
+fn computed_goto_takes_any_expression() {
+    let src = "void f(int i) {
+        static void *table[] = { &&a, &&b };
+        goto *table[i];
+        a: return;
+        b: goto *(i ? &&a : &&b);
+    }";
+    let definition = parse_decl!(src);
+    let mut jumps = Jumps::default();
+    walk_external_declaration(&mut jumps, &definition);
+    assert_eq!(jumps.addresses, ["a", "b", "a", "b"]);
+    assert_eq!(jumps.computed_targets.len(), 2);
+}
+
+#[test]
+fn rejected_outside_the_gnu_dialect() {
+    for src in [
+        "void f(void){ void *p = &&done; done:; }",
+        "void f(void *p){ goto *p; }",
+    ] {
+        assert!(parses(src, Dialect::Gnu), "{src}");
+        assert!(!parses(src, Dialect::Standard), "{src}");
+    }
+}
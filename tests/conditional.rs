@@ -0,0 +1,63 @@
+use ecc::{
+    ast::{Expression, ExpressionKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the structure of the first expression visited, fully parenthesized.
+struct Shape(Option<String>);
+impl<'a> Visitor<'a> for Shape {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Expression(expression) = node
+            && self.0.is_none()
+        {
+            self.0 = Some(shape(expression));
+        }
+    }
+}
+
+fn shape(expression: &Expression) -> String {
+    match &expression.kind {
+        ExpressionKind::Identifier(name) => name.to_string(),
+        ExpressionKind::Conditional {
+            condition,
+            then_value,
+            else_value,
+            ..
+        } => format!(
+            "({} ? {} : {})",
+            shape(condition),
+            shape(then_value),
+            shape(else_value)
+        ),
+        ExpressionKind::Comma { left, right, .. } => {
+            format!("({}, {})", shape(left), shape(right))
+        }
+        kind => panic!("unexpected {kind:?}"),
+    }
+}
+
+/// Parses `src` as an expression statement and returns its shape.
+fn parse(src: &str) -> String {
+    let src = format!("void f(void) {{ {src}; }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut shape = Shape(None);
+    walk_translation_unit(&mut shape, &ast);
+    shape.0.unwrap()
+}
+
+#[test]
+fn middle_operand_may_contain_a_comma() {
+    assert_eq!(parse("a ? b, c : d"), "(a ? (b, c) : d)");
+    assert_eq!(parse("a ? b, c, d : e"), "(a ? ((b, c), d) : e)");
+    assert_eq!(parse("a ? b ? c, d : e : f"), "(a ? (b ? (c, d) : e) : f)");
+}
+
+#[test]
+fn else_operand_ends_before_a_comma() {
+    assert_eq!(parse("a ? b : c, d"), "((a ? b : c), d)");
+    assert_eq!(parse("a ? b : c ? d : e, f"), "((a ? b : (c ? d : e)), f)");
+    assert_eq!(parse("a ? b, c : d, e"), "((a ? (b, c) : d), e)");
+}
@@ -0,0 +1,83 @@
+use ecc::{
+    lexer::Lexer,
+    lint::{LintKind, LintOptions, lint},
+    parser::Parser,
+};
+
+fn lints(src: &str) -> Vec<LintKind<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let options = LintOptions {
+        missing_return: true,
+        enum_switch: true,
+        ..Default::default()
+    };
+    lint(&ast, options).into_iter().map(|l| l.kind).collect()
+}
+
+#[test]
+fn missing_return_is_flagged() {
+    let missing = || vec![LintKind::MissingReturn { function: "f" }];
+    assert_eq!(lints("int f(int x) { if (x) return 1; }"), missing());
+    assert_eq!(lints("int f(void) { }"), missing());
+    assert_eq!(lints("int f(int x) { while (x) { return 1; } }"), missing());
+}
+
+#[test]
+fn paths_that_cannot_fall_off_the_end_are_not_flagged() {
+    for src in [
+        "int f(int x) { if (x) return 1; else return 0; }",
+        "int f(void) { for (;;) { } }",
+        "int f(void) { while (1) ; }",
+        "int f(int x) { switch (x) { case 1: return 1; default: return 0; } }",
+        "int f(void) { exit(1); }",
+        "int f(void) { abort(); }",
+        "void f(void) { }",
+        "int main(void) { }",
+    ] {
+        assert_eq!(lints(src), [], "{src}");
+    }
+}
+
+#[test]
+fn loop_conditions_are_evaluated_as_constants() {
+    for condition in [
+        "0xA", "0xF", "0x10", "010", "0b10", "1u", "true", "2 - 1", "(1 == 1)",
+    ] {
+        let src = format!("int f(void) {{ while ({condition}) ; }}");
+        assert_eq!(lints(&src), [], "{condition}");
+        let src = format!("int f(void) {{ do ; while ({condition}); }}");
+        assert_eq!(lints(&src), [], "{condition}");
+    }
+
+    let missing = || vec![LintKind::MissingReturn { function: "f" }];
+    for condition in ["0", "0x0", "00", "0b0", "false", "1 - 1"] {
+        let src = format!("int f(void) {{ while ({condition}) ; }}");
+        assert_eq!(lints(&src), missing(), "{condition}");
+    }
+}
+
+#[test]
+fn switch_missing_an_enumerator_is_flagged() {
+    let src = "enum color { RED, GREEN, BLUE };
+        void f(enum color c) { switch (c) { case RED: break; case BLUE: break; } }";
+    assert_eq!(
+        lints(src),
+        [LintKind::MissingEnumCases {
+            missing: vec!["GREEN"]
+        }]
+    );
+}
+
+#[test]
+fn exhaustive_or_defaulted_switches_are_not_flagged() {
+    let decl = "enum color { RED, GREEN, BLUE };";
+    for body in [
+        "switch (c) { case RED: case GREEN: case BLUE: break; }",
+        "switch (c) { case RED: break; default: break; }",
+        "switch (i) { case 1: break; }",
+    ] {
+        let src = format!("{decl} void f(enum color c, int i) {{ {body} }}");
+        assert_eq!(lints(&src), [], "{body}");
+    }
+}
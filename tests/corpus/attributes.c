@@ -0,0 +1,30 @@
+[[deprecated("use other")]] int old_function(void);
+[[nodiscard]] int must_use(void);
+[[maybe_unused]] static int spare;
+int annotated [[maybe_unused]] = 1;
+[[gnu::always_inline, gnu::hot]] inline int fast(int x) { return x; }
+struct [[deprecated]] legacy { int field [[maybe_unused]]; };
+
+void falls(int value) {
+    switch (value) {
+    case 0:
+        value++;
+        [[fallthrough]];
+    case 1:
+        break;
+    }
+    [[maybe_unused]] int local = value;
+}
+
+int blocks(int value) {
+    [[maybe_unused]] {
+        int scratch = value;
+    }
+    [[vendor::likely]] if (value > 0)
+        value--;
+    else
+        value++;
+    return value;
+}
+
+[[vendor::custom(1, "two", { three })]];
@@ -0,0 +1,51 @@
+int classify(int value) {
+    if (value < 0)
+        return -1;
+    else if (value == 0)
+        return 0;
+
+    int total = 0;
+    for (int i = 0; i < value; i++) {
+        if (i % 2)
+            continue;
+        total += i;
+    }
+    while (total > 100)
+        total /= 2;
+    do {
+        total--;
+    } while (total > 50);
+
+    switch (total) {
+    case 1:
+    case 2:
+        total = 3;
+        break;
+    default:
+        break;
+    }
+
+    if (total == 7)
+        goto done;
+    total <<= 1;
+done:
+    return total;
+}
+
+typedef int count;
+
+int dispatch(int op) {
+    switch (op) {
+        int unused;
+        count scratch = 0;
+    case 0:
+        return scratch;
+    case 1: {
+        int local = op * 2;
+        return local;
+    }
+    default:
+        unused = op;
+        return unused;
+    }
+}
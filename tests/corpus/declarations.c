@@ -0,0 +1,26 @@
+typedef unsigned long size_t;
+typedef struct node {
+    int value;
+    struct node *next;
+    unsigned flags : 3;
+    union {
+        float f;
+        double d;
+    };
+} node;
+
+enum color { RED, GREEN = 4, BLUE };
+enum small : unsigned char { TINY = 1 };
+
+static const int table[3][2];
+extern int (*handlers[4])(int, char **);
+_Atomic(int) counter;
+alignas(16) char buffer[64];
+thread_local int per_thread;
+int variadic(const char *format, ...);
+int no_prototype();
+void takes_array(int n, int values[static n]);
+char *restrict * const pointers;
+static_assert(sizeof(node) > 0, "node has a size");
+typeof(counter) copy;
+_BitInt(24) wide;
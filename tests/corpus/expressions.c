@@ -0,0 +1,30 @@
+int expressions(int a, int b, int *p, struct { int m; } s) {
+    int r = a + b * 2 - (a - b) / 3 % 4;
+    r = a << 2 | b >> 1 & ~a ^ !b;
+    r = a < b && b <= a || a > b != (a >= b) == 0;
+    r = a ? b : a ? 1 : 2;
+    r = (a, b);
+    r += *p++ + --p[1] + s.m + (&s)->m;
+    r = (int)sizeof(int) + sizeof r + alignof(long);
+    r = _Generic(a, int: 1, const char *: 2, default: 3);
+    r = -a + +b - -1;
+    p = &r;
+    p = nullptr;
+    r = true ? false : (_Bool)1;
+    r = "string"[0] + 'c' + u'x' + U'y';
+    return r;
+}
+
+struct point {
+    int x, y;
+    struct {
+        char tag[4];
+    } inner[2];
+};
+
+unsigned long builtins(void) {
+    unsigned long offset = __builtin_offsetof(struct point, y);
+    offset += __builtin_offsetof(struct point, inner[1].tag[2]);
+    return offset + __builtin_types_compatible_p(int, long)
+        + __builtin_types_compatible_p(struct point *, typeof(struct point *));
+}
@@ -0,0 +1,19 @@
+struct point {
+    int x, y;
+};
+struct line {
+    struct point from, to;
+};
+
+int primes[] = { 2, 3, 5, 7, 11, };
+struct point origin = { 0 };
+struct point corner = { .y = 4, .x = 3 };
+struct line diagonal = { .from = { 0, 0 }, .to.x = 5, .to.y = 5 };
+int matrix[2][3] = { [1][2] = 6, [0] = { 1, 2 } };
+struct point pairs[2] = { [0].x = 1, [1] = { 2, 3 } };
+char greeting[] = "hello";
+char narrow[] = u8"utf-8";
+struct point *shared = &(struct point){ 1, 2 };
+int empty[4] = {};
+double ratios[] = { 1.5, 2e10, 0x1p-3, 1.0f, 3.0L };
+unsigned long masks[] = { 0xFFu, 0b1010, 017, 'a', L'b', 1'000'000ull };
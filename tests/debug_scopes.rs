@@ -0,0 +1,34 @@
+//! Checks that every scope opened while parsing is closed again, leaving only the
+//! file scope with the top-level names in it.
+#![cfg(feature = "debug-scopes")]
+
+use std::collections::BTreeMap;
+
+use ecc::{lexer::Lexer, parser::Parser};
+
+const SRC: &str = "
+typedef int T;
+void f(int x) {
+    typedef long U;
+    { typedef char T; T t; }
+    for (typedef short V; 0;) {}
+}
+typedef T W;
+int g(void);
+";
+
+#[test]
+fn only_the_file_scope_is_left_after_each_declaration() {
+    let (tokens, _) = Lexer::new(SRC).lex();
+    let mut parser = Parser::new(&tokens);
+    let mut count = 0;
+    while let Ok(declaration) = parser.parse_next_declaration() {
+        let scopes = parser.scopes();
+        assert_eq!(scopes.len(), 1, "after {:?}", declaration.at);
+        count += 1;
+    }
+    assert_eq!(count, 4);
+
+    let file_scope = BTreeMap::from([("T", true), ("W", true), ("f", false), ("g", false)]);
+    assert_eq!(parser.scopes(), [file_scope]);
+}
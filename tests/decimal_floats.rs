@@ -0,0 +1,41 @@
+use ecc::{
+    lexer::Lexer,
+    token::{FloatFormat, FloatSuffix, FloatToken, TokenKind},
+};
+
+fn kinds(src: &str) -> Vec<TokenKind<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+fn decimal(source: &str, suffix: FloatSuffix) -> [TokenKind<'_>; 2] {
+    let float = FloatToken {
+        source,
+        format: FloatFormat::Decimal,
+        suffix: Some(suffix),
+    };
+    [TokenKind::Floating(float), TokenKind::Eof]
+}
+
+#[test]
+fn decimal_float_suffixes() {
+    assert_eq!(kinds("1.5df"), decimal("1.5", FloatSuffix::Decimal32));
+    assert_eq!(kinds("2.0dd"), decimal("2.0", FloatSuffix::Decimal64));
+    assert_eq!(kinds("3.0dl"), decimal("3.0", FloatSuffix::Decimal128));
+    assert_eq!(kinds("3.0DL"), decimal("3.0", FloatSuffix::Decimal128));
+    assert_eq!(kinds("1e3dd"), decimal("1e3", FloatSuffix::Decimal64));
+    assert!(FloatSuffix::Decimal32.is_decimal());
+    assert!(!FloatSuffix::LongDouble.is_decimal());
+}
+
+#[test]
+fn unknown_decimal_suffix_is_an_error() {
+    for src in ["1.5dz", "1.5d", "1.5fd", "1.5dfl"] {
+        assert_eq!(kinds(src), [TokenKind::Error, TokenKind::Eof], "{src}");
+    }
+}
+
+#[test]
+fn hexadecimal_float_has_no_decimal_suffix() {
+    assert_eq!(kinds("0x1p3df"), [TokenKind::Error, TokenKind::Eof]);
+}
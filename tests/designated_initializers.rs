@@ -0,0 +1,84 @@
+//! Golden shapes of nested braced initializers, with every designator and
+//! initializer printed back in the order the parser kept them.
+
+use ecc::{
+    ast::{
+        DesignatorKind, ExpressionKind, InitDeclarator, Initializer, InitializerKind,
+        TranslationUnit,
+    },
+    lexer::Lexer,
+    parser::Parser,
+    visit::Visitor,
+};
+
+/// Collects the shape of the initializer of every declarator.
+struct Shapes(Vec<String>);
+impl<'a> Visitor<'a> for Shapes {
+    fn visit_init_declarator(&mut self, declarator: &InitDeclarator<'a>) {
+        if let Some((_, initializer)) = &declarator.initializer {
+            self.0.push(shape(initializer));
+        }
+    }
+}
+
+fn shape(initializer: &Initializer) -> String {
+    match &initializer.kind {
+        InitializerKind::Expression(expr) => match &expr.kind {
+            ExpressionKind::Integer(int) => int.source.to_string(),
+            ExpressionKind::Identifier(name) => name.to_string(),
+            kind => panic!("unexpected initializer {kind:?}"),
+        },
+        InitializerKind::Braced(braced) => {
+            let items: Vec<_> = braced
+                .initializers
+                .iter()
+                .flat_map(|(list, _)| list.iter())
+                .map(|(designation, initializer)| {
+                    let mut item = String::new();
+                    for designator in designation.iter().flat_map(|d| d.designators.iter()) {
+                        match &designator.kind {
+                            DesignatorKind::InBrackets { value, .. } => {
+                                let ExpressionKind::Integer(int) = &value.kind else {
+                                    panic!("unexpected index {value:?}");
+                                };
+                                item += &format!("[{}]", int.source);
+                            }
+                            DesignatorKind::AfterPeriod { name, .. } => item += &format!(".{name}"),
+                        }
+                    }
+                    if designation.is_some() {
+                        item += " = ";
+                    }
+                    item + &shape(initializer)
+                })
+                .collect();
+            format!("{{ {} }}", items.join(", "))
+        }
+    }
+}
+
+fn shapes(src: &str) -> Vec<String> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast: TranslationUnit = Parser::new(&tokens).parse().unwrap();
+    let mut shapes = Shapes(Vec::new());
+    shapes.visit_translation_unit(&ast);
+    shapes.0
+}
+
+#[test]
+fn array_and_member_designators_mix() {
+    let src = "struct p { int x, y; } ps[2] = { [0].x = 1, [1] = {2, 3} };";
+    assert_eq!(shapes(src), ["{ [0].x = 1, [1] = { 2, 3 } }"]);
+
+    let src = "struct s { struct p { int x[2]; } a[2]; } v = { .a[1].x[0] = 4, .a[0] = { .x = { [1] = 5 } }, 6 };";
+    assert_eq!(
+        shapes(src),
+        ["{ .a[1].x[0] = 4, .a[0] = { .x = { [1] = 5 } }, 6 }"]
+    );
+}
+
+#[test]
+fn positional_initializers_nest() {
+    let src = "int m[2][2][2] = { { {1, 2}, {3, 4} }, { {5}, 6, 7 }, };";
+    assert_eq!(shapes(src), ["{ { { 1, 2 }, { 3, 4 } }, { { 5 }, 6, 7 } }"]);
+}
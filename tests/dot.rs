@@ -0,0 +1,43 @@
+use ecc::{
+    ast::TranslationUnit,
+    dot::to_dot,
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Counts the nodes the visitor enters.
+struct Count(usize);
+impl<'a> Visitor<'a> for Count {
+    fn enter(&mut self, _node: Node<'_, 'a>) {
+        self.0 += 1;
+    }
+}
+
+fn dot(src: &str) -> (String, usize) {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast: TranslationUnit = Parser::new(&tokens).parse().unwrap();
+    let mut count = Count(0);
+    walk_translation_unit(&mut count, &ast);
+    (to_dot(&ast), count.0)
+}
+
+#[test]
+fn every_node_has_one_vertex_and_one_parent_edge() {
+    let (dot, count) = dot("int x = 1 + 2;");
+    assert!(dot.starts_with("digraph ast {\n"));
+    assert!(dot.ends_with("}\n"));
+
+    let vertices = dot.lines().filter(|l| l.contains("[label=")).count();
+    let edges = dot.lines().filter(|l| l.contains(" -> ")).count();
+    assert_eq!(vertices, count);
+    assert_eq!(edges, count - 1);
+    assert_eq!(vertices, 13);
+}
+
+#[test]
+fn labels_show_kind_and_position() {
+    let (dot, _) = dot("int x =\n  1 + 2;");
+    assert!(dot.contains("\"TranslationUnit\\n1:1\""), "{dot}");
+    assert!(dot.contains("\\n2:3\""), "{dot}");
+}
@@ -0,0 +1,55 @@
+//! An ellipsis may only end a parameter list; anywhere else it is reported as such.
+
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, Parser},
+    symbols::SymbolTable,
+};
+
+/// Returns whether the function `f` declared by `src` is variadic.
+fn is_variadic(src: &str) -> bool {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let f = symbols.symbols().iter().find(|s| s.name == "f").unwrap();
+    f.function.as_ref().unwrap().variadic
+}
+
+/// Returns the first error parsing `src` reports, with the column it is at.
+fn first_error(src: &str) -> (Expected<'_>, u32) {
+    let (tokens, _) = Lexer::new(src).lex();
+    let result = Parser::new(&tokens).parse();
+    let err = &result.errors()[0];
+    (err.expected, err.at.at.column)
+}
+
+#[test]
+fn ellipsis_after_the_parameters() {
+    assert!(is_variadic("int f(int, ...);"));
+    assert!(is_variadic("int f(int a, char *b, ...) { return a; }"));
+    assert!(!is_variadic("int f(int a, char *b);"));
+}
+
+#[test]
+fn ellipsis_alone() {
+    assert!(is_variadic("int f(...);"));
+}
+
+#[test]
+fn ellipsis_inside_a_parameter_list() {
+    let misplaced = Expected::EllipsisAfterParameters;
+    assert_eq!(first_error("int f(..., int);"), (misplaced, 7));
+    assert_eq!(first_error("int f(int, ..., int);"), (misplaced, 12));
+    assert_eq!(first_error("int f(int, ...,);"), (misplaced, 12));
+}
+
+#[test]
+fn ellipsis_in_an_expression() {
+    let misplaced = Expected::EllipsisAfterParameters;
+    assert_eq!(first_error("int x = ...;"), (misplaced, 9));
+    assert_eq!(
+        first_error("void g(void) { int y = 1 + ...; }"),
+        (misplaced, 28)
+    );
+    assert_eq!(first_error("void g(void) { f(1, ...); }"), (misplaced, 21));
+}
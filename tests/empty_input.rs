@@ -0,0 +1,50 @@
+//! Input without any declarations is reported with a single, dedicated error.
+
+use ecc::{
+    lexer::{Lexer, LexerOptions},
+    parser::{Expected, Parser},
+};
+
+fn expected(src: &str, keep_comments: bool) -> Vec<Expected<'_>> {
+    let options = LexerOptions {
+        keep_comments,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options(src, options).lex();
+    let result = Parser::new(&tokens).parse();
+    assert!(result.ast.is_none(), "{src:?}");
+    result.errors.iter().map(|err| err.expected).collect()
+}
+
+#[test]
+fn empty_string() {
+    assert_eq!(expected("", false), [Expected::NonEmptyTranslationUnit]);
+}
+
+#[test]
+fn whitespace_only() {
+    assert_eq!(
+        expected(" \t\n\r\n  ", false),
+        [Expected::NonEmptyTranslationUnit]
+    );
+}
+
+#[test]
+fn comment_only() {
+    let src = "// nothing here\n/* or\n   here */\n";
+    for keep_comments in [false, true] {
+        assert_eq!(
+            expected(src, keep_comments),
+            [Expected::NonEmptyTranslationUnit],
+            "keep_comments: {keep_comments}"
+        );
+    }
+}
+
+#[test]
+fn line_markers_only() {
+    assert_eq!(
+        expected("# 1 \"a.c\"\n# 20 \"b.h\" 1\n", false),
+        [Expected::NonEmptyTranslationUnit]
+    );
+}
@@ -0,0 +1,61 @@
+//! C23 enumerations with a fixed underlying type `enum E : T`.
+
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn integer_underlying_types() {
+    for src in [
+        "enum E : long { A };",
+        "enum E : int { A, B };",
+        "enum E : unsigned char { A = 255 };",
+        "enum E : const short { A };",
+        "typedef unsigned long U; enum E : U { A };",
+        "enum { A };",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn non_integer_underlying_types() {
+    for src in [
+        "enum E : float { A };",
+        "enum E : double { A };",
+        "enum F : int { X }; enum E : enum F { A };",
+        "enum E : _BitInt(8) { A };",
+    ] {
+        assert_eq!(errors(src), [ValidationErrKind::InvalidEnumType], "{src}");
+    }
+}
+
+#[test]
+fn forward_declaration_and_later_uses() {
+    for src in [
+        "enum E : int;",
+        "enum E : unsigned char;",
+        "enum E : int; enum E x;",
+        "enum E : int; enum E : int { A }; enum E x = A;",
+        "enum E : int; void f(enum E e);",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn underlying_type_without_enumerators_elsewhere() {
+    for src in [
+        "enum E : int x;",
+        "enum E : int; enum E : int y;",
+        "typedef enum E : int T;",
+        "void f(enum E : int e);",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::EnumTypeWithoutEnumerators],
+            "{src}"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, Parser},
+    token::TokenKind,
+};
+
+#[test]
+fn tokens_render_with_their_spelling() {
+    for (expected, rendered) in [
+        (Expected::Token(TokenKind::CloseBrace), "expected `}`"),
+        (Expected::Token(TokenKind::Semicolon), "expected `;`"),
+        (
+            Expected::Token(TokenKind::DoubleLessEqual),
+            "expected `<<=`",
+        ),
+        (Expected::Token(TokenKind::While), "expected `while`"),
+        (Expected::Token(TokenKind::Eof), "expected end of file"),
+    ] {
+        assert_eq!(expected.to_string(), rendered);
+    }
+}
+
+#[test]
+fn categories_render_as_phrases() {
+    for (expected, rendered) in [
+        (Expected::PrimaryExpression, "expected a primary expression"),
+        (Expected::Identifier, "expected an identifier"),
+        (Expected::StructOrUnion, "expected `struct` or `union`"),
+        (
+            Expected::ExternalDeclaration,
+            "expected a declaration or function definition",
+        ),
+    ] {
+        assert_eq!(expected.to_string(), rendered);
+    }
+}
+
+#[test]
+fn parse_errors_render_position_and_found_token() {
+    let (tokens, _) = Lexer::new("void f(void) {\n  int x = alignof x;\n}").lex();
+    let result = Parser::new(&tokens).parse();
+    assert_eq!(
+        result.errors[0].to_string(),
+        "2:19: expected a parenthesized type name after `alignof`, found `x`"
+    );
+}
@@ -0,0 +1,36 @@
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    symbols::SymbolTable,
+    types::{BaseType, TypeKind},
+};
+
+/// Returns the base type of each symbol declared in `src`.
+fn base_types(src: &str) -> Vec<BaseType<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    SymbolTable::collect(&ast)
+        .symbols()
+        .iter()
+        .map(|symbol| match &symbol.ty.as_ref().unwrap().kind {
+            TypeKind::Base(base) => base.clone(),
+            kind => panic!("unexpected type {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn interchange_and_extended_floats_are_types() {
+    assert_eq!(base_types("_Float16 h;"), [BaseType::Float16]);
+    assert_eq!(base_types("_Float128 q;"), [BaseType::Float128]);
+    assert_eq!(
+        base_types("_Float32 a; _Float64 b; _Float32x c; _Float64x d; _Float128x e;"),
+        [
+            BaseType::Float32,
+            BaseType::Float64,
+            BaseType::Float32x,
+            BaseType::Float64x,
+            BaseType::Float128x,
+        ]
+    );
+}
@@ -0,0 +1,43 @@
+use ecc::{
+    lexer::Lexer,
+    token::{Files, TokenKind},
+};
+
+#[test]
+fn file_ids_are_interned() {
+    let mut files = Files::new();
+    let a = files.get_file_id("a.h");
+    let b = files.get_file_id("b.h");
+    assert_ne!(a, b);
+    assert_eq!(files.get_file_id("a.h"), a);
+    assert_eq!(files.get_file_id("b.h"), b);
+    assert_eq!(&files[a], "a.h");
+    assert_eq!(&files[b], "b.h");
+}
+
+#[test]
+fn many_line_markers_map_to_their_files() {
+    // Enters and leaves a thousand headers from `main.c`, as an include-heavy
+    // translation unit does.
+    let mut src = String::from("# 1 \"main.c\"\n");
+    for i in 0..1000 {
+        src += &format!(
+            "# 1 \"include/h{i}.h\" 1\nint v{i};\n# {} \"main.c\" 2\nint m{i};\n",
+            i + 2
+        );
+    }
+    let (tokens, files) = Lexer::new(&src).lex();
+
+    let names: Vec<_> = tokens
+        .iter()
+        .filter_map(|t| match t.kind {
+            TokenKind::Identifier(name) => Some((name, &files[t.at.file], t.at.line)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names.len(), 2000);
+    for (i, pair) in names.chunks(2).enumerate() {
+        assert_eq!(pair[0], (&*format!("v{i}"), &*format!("include/h{i}.h"), 1));
+        assert_eq!(pair[1], (&*format!("m{i}"), "main.c", i as u32 + 2));
+    }
+}
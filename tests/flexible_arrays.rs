@@ -0,0 +1,50 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn trailing_flexible_array_is_valid() {
+    for src in [
+        "struct S { int n; int data[]; };",
+        "struct S { int n, data[]; };",
+        "struct S { int a; double b; char data[]; };",
+        "struct S { struct { int a; }; int fam[]; };",
+        "struct S { union { int a; float b; }; int fam[]; };",
+        "struct S { int n; int fixed[4]; };",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn flexible_array_must_be_last() {
+    assert_eq!(
+        errors("struct S { int data[]; int n; };"),
+        [ValidationErrKind::FlexibleArrayNotLast]
+    );
+    assert_eq!(
+        errors("struct S { int n; int data[]; struct { int a; }; };"),
+        [ValidationErrKind::FlexibleArrayNotLast]
+    );
+}
+
+#[test]
+fn flexible_array_needs_another_named_member() {
+    assert_eq!(
+        errors("struct S { int data[]; };"),
+        [ValidationErrKind::FlexibleArrayOnlyMember]
+    );
+    assert_eq!(
+        errors("struct S { int : 3; int data[]; };"),
+        [ValidationErrKind::FlexibleArrayOnlyMember]
+    );
+}
+
+#[test]
+fn flexible_array_in_union_is_invalid() {
+    assert_eq!(
+        errors("union U { int n; int data[]; };"),
+        [ValidationErrKind::FlexibleArrayInUnion]
+    );
+}
@@ -0,0 +1,70 @@
+use ecc::{
+    ast::ExpressionKind,
+    lexer::Lexer,
+    parse_expr,
+    token::{FloatFormat, FloatSuffix, FloatToken, TokenKind},
+};
+
+fn kinds(src: &str) -> Vec<TokenKind<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+fn float(source: &str, format: FloatFormat, suffix: Option<FloatSuffix>) -> FloatToken<'_> {
+    FloatToken {
+        source,
+        format,
+        suffix,
+    }
+}
+
+#[test]
+fn suffixes_and_forms() {
+    let cases = [
+        (
+            "1.0e10L",
+            float(
+                "1.0e10",
+                FloatFormat::Decimal,
+                Some(FloatSuffix::LongDouble),
+            ),
+        ),
+        (
+            ".5f",
+            float(".5", FloatFormat::Decimal, Some(FloatSuffix::Float)),
+        ),
+        ("2.", float("2.", FloatFormat::Decimal, None)),
+        (
+            "0x1p-3f",
+            float("1p-3", FloatFormat::Hexadecimal, Some(FloatSuffix::Float)),
+        ),
+    ];
+    for (src, token) in cases {
+        assert_eq!(
+            kinds(src),
+            [TokenKind::Floating(token), TokenKind::Eof],
+            "{src}"
+        );
+        assert_eq!(token.to_string(), src.to_ascii_lowercase(), "{src}");
+    }
+}
+
+#[test]
+fn only_one_suffix_is_allowed() {
+    for src in ["1.0fl", "1.0lf", "1.0ff", "0x1p-3fl"] {
+        assert_eq!(kinds(src), [TokenKind::Error, TokenKind::Eof], "{src}");
+    }
+}
+
+#[test]
+fn constants_are_expressions() {
+    let expression = parse_expr!("1.0e10L");
+    assert_eq!(
+        expression.kind,
+        ExpressionKind::Float(float(
+            "1.0e10",
+            FloatFormat::Decimal,
+            Some(FloatSuffix::LongDouble)
+        ))
+    );
+}
@@ -0,0 +1,78 @@
+use ecc::{
+    ast::{BinaryOperator, Expression, ExpressionKind, ForInitializer, IterationStatementKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// The shape of each clause of a `for` statement, `None` if it is omitted.
+#[derive(Debug, PartialEq)]
+enum Clause {
+    Comma,
+    Less,
+    Other,
+}
+
+struct Clauses(Vec<[Option<Clause>; 3]>);
+impl<'a> Visitor<'a> for Clauses {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::IterationStatement(statement) = node
+            && let IterationStatementKind::For {
+                initializer,
+                condition,
+                counter,
+                ..
+            } = &statement.kind
+        {
+            let initializer = match initializer {
+                ForInitializer::Expression(expression, _) => expression.as_ref(),
+                ForInitializer::Declaration(_) => panic!("unexpected declaration"),
+            };
+            let shape = |expression: Option<&Expression>| {
+                expression.map(|e| match &e.kind {
+                    ExpressionKind::Comma { .. } => Clause::Comma,
+                    ExpressionKind::Binary {
+                        operator: (_, BinaryOperator::Less),
+                        ..
+                    } => Clause::Less,
+                    _ => Clause::Other,
+                })
+            };
+            self.0.push([
+                shape(initializer),
+                shape(condition.as_ref()),
+                shape(counter.as_ref()),
+            ]);
+        }
+    }
+}
+
+fn clauses(body: &str) -> Vec<[Option<Clause>; 3]> {
+    let src = format!("void f(int i, int j, int n) {{ {body} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut clauses = Clauses(Vec::new());
+    walk_translation_unit(&mut clauses, &ast);
+    clauses.0
+}
+
+#[test]
+fn comma_operator_in_initializer_and_counter() {
+    assert_eq!(
+        clauses("for (i=0,j=0; i<n; i++,j++) ;"),
+        [[Some(Clause::Comma), Some(Clause::Less), Some(Clause::Comma)]]
+    );
+}
+
+#[test]
+fn condition_is_a_full_expression() {
+    assert_eq!(
+        clauses("for (; i<n, j<n; ) ;"),
+        [[None, Some(Clause::Comma), None]]
+    );
+    assert_eq!(clauses("for (;;) ;"), [[None, None, None]]);
+    assert_eq!(
+        clauses("for (i = 0; i < n; i++) ;"),
+        [[Some(Clause::Other), Some(Clause::Less), Some(Clause::Other)]]
+    );
+}
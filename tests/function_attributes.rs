@@ -0,0 +1,115 @@
+//! Attributes in each position around a function definition, and the AST slot
+//! each of them appertains to.
+
+use ecc::{
+    ast::{
+        AttributeSpecifierSequence, DirectDeclaratorKind, ExternalDeclarationKind,
+        FunctionDefinition, TranslationUnit,
+    },
+    lexer::Lexer,
+    parser::Parser,
+};
+
+fn names<'a>(attributes: Option<&AttributeSpecifierSequence<'a>>) -> Vec<&'a str> {
+    attributes
+        .into_iter()
+        .flat_map(|sequence| sequence.iter())
+        .flat_map(|specifier| specifier.attributes.iter().flatten())
+        .map(|attribute| attribute.token.token)
+        .collect()
+}
+
+/// The attributes of a function definition, by the slot they are kept in.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Slots<'a> {
+    definition: Vec<&'a str>,
+    specifiers: Vec<&'a str>,
+    name: Vec<&'a str>,
+    function_type: Vec<&'a str>,
+}
+
+fn slots(src: &str) -> Slots<'_> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast: TranslationUnit = Parser::new(&tokens).parse().unwrap();
+    let [declaration] = ast.iter().collect::<Vec<_>>()[..] else {
+        panic!("expected a single declaration in {src}");
+    };
+    let ExternalDeclarationKind::Function(FunctionDefinition {
+        attributes,
+        specifiers,
+        declarator,
+        ..
+    }) = &declaration.kind
+    else {
+        panic!("expected a function definition in {src}");
+    };
+    let DirectDeclaratorKind::Function(function, function_type) = &declarator.direct.kind else {
+        panic!("expected a function declarator in {src}");
+    };
+    let DirectDeclaratorKind::Name(_, name) = &function.left.kind else {
+        panic!("expected a named function in {src}");
+    };
+    Slots {
+        definition: names(attributes.as_ref()),
+        specifiers: names(specifiers.attributes()),
+        name: names(name.as_ref()),
+        function_type: names(function_type.as_ref()),
+    }
+}
+
+#[test]
+fn leading_attributes_appertain_to_the_definition() {
+    assert_eq!(
+        slots("[[noreturn]] void f(void) {}"),
+        Slots {
+            definition: vec!["noreturn"],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn attributes_after_the_declarator_appertain_to_the_function_type() {
+    assert_eq!(
+        slots("void f(void) [[noreturn]] {}"),
+        Slots {
+            function_type: vec!["noreturn"],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn attributes_after_the_specifiers_appertain_to_the_type() {
+    assert_eq!(
+        slots("void [[gnu::aligned]] f(void) {}"),
+        Slots {
+            specifiers: vec!["aligned"],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn attributes_after_the_name_appertain_to_the_function() {
+    assert_eq!(
+        slots("void f [[maybe_unused]] (void) {}"),
+        Slots {
+            name: vec!["maybe_unused"],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn attributes_in_every_position() {
+    assert_eq!(
+        slots("[[a]] [[b]] static int [[c]] f [[d]] (int x) [[e, f]] { return x; }"),
+        Slots {
+            definition: vec!["a", "b"],
+            specifiers: vec!["c"],
+            name: vec!["d"],
+            function_type: vec!["e", "f"],
+        }
+    );
+}
@@ -0,0 +1,69 @@
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    symbols::{FunctionInfo, SymbolTable},
+    types::{BaseType, Type, TypeKind},
+};
+
+/// Returns the function information of the symbol `name` declared in `src`.
+fn function<'a>(src: &'a str, name: &str) -> Option<FunctionInfo<'a>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let symbol = symbols.symbols().iter().find(|s| s.name == name).unwrap();
+    symbol.function.clone()
+}
+
+fn base(base: BaseType<'static>) -> Type<'static> {
+    Type {
+        kind: TypeKind::Base(base),
+        qualifiers: Default::default(),
+    }
+}
+
+#[test]
+fn void_parameter_list_is_a_prototype_without_parameters() {
+    let f = function("int f(void);", "f").unwrap();
+    assert!(f.prototyped);
+    assert_eq!(f.params, []);
+    assert!(!f.variadic);
+}
+
+#[test]
+fn empty_parameter_list_is_not_a_prototype() {
+    for src in ["int f();", "int f() { return 0; }"] {
+        let f = function(src, "f").unwrap();
+        assert!(!f.prototyped, "{src}");
+        assert_eq!(f.params, [], "{src}");
+        assert!(!f.variadic, "{src}");
+    }
+}
+
+#[test]
+fn variadic_prototype_keeps_its_parameters() {
+    let f = function("int f(int, ...);", "f").unwrap();
+    assert!(f.prototyped);
+    assert_eq!(f.params, [base(BaseType::Int)]);
+    assert!(f.variadic);
+
+    let g = function("void g(char c, double d[]) {}", "g").unwrap();
+    assert!(g.prototyped && !g.variadic);
+    assert_eq!(
+        g.params,
+        [
+            base(BaseType::Char),
+            Type {
+                kind: TypeKind::Pointer(Box::new(base(BaseType::Double))),
+                qualifiers: Default::default(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn typedef_function_types_are_looked_through() {
+    let src = "typedef int handler(int); handler h; int x; int (*p)(void);";
+    assert_eq!(function(src, "h").unwrap().params, [base(BaseType::Int)]);
+    assert_eq!(function(src, "x"), None);
+    assert_eq!(function(src, "p"), None);
+}
@@ -0,0 +1,67 @@
+//! The parameters of a function definition and the outermost block of its body
+//! share one scope.
+
+mod common;
+
+use common::errors;
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    symbols::{SymbolKind, SymbolTable},
+    validate::ValidationErrKind,
+};
+
+#[test]
+fn local_variable_shadows_a_typedef() {
+    let src = "typedef int T; void f() { int T; T++; }";
+    assert_eq!(errors(src), []);
+    let src = "typedef int T; void f(void) { int T = 1; { T++; } T = 2; }";
+    assert_eq!(errors(src), []);
+}
+
+#[test]
+fn parameter_shadows_a_typedef() {
+    let src = "typedef int T; void f(int T) { T++; }";
+    assert_eq!(errors(src), []);
+}
+
+#[test]
+fn body_cannot_redeclare_a_parameter_as_a_typedef() {
+    assert_eq!(
+        errors("void f(int T) { typedef int T; }"),
+        [ValidationErrKind::ConflictingKinds]
+    );
+    // A nested block is a new scope.
+    assert_eq!(errors("void f(int T) { { typedef int T; } }"), []);
+}
+
+#[test]
+fn body_cannot_redeclare_a_parameter_with_another_type() {
+    assert_eq!(
+        errors("void f(int x) { long x; }"),
+        [ValidationErrKind::ConflictingTypes]
+    );
+    assert_eq!(errors("void f(int x) { { long x; } }"), []);
+}
+
+#[test]
+fn typedef_and_object_conflict_in_one_scope() {
+    assert_eq!(
+        errors("int x; typedef int x;"),
+        [ValidationErrKind::ConflictingKinds]
+    );
+    assert_eq!(
+        errors("typedef int x; int x;"),
+        [ValidationErrKind::ConflictingKinds]
+    );
+}
+
+#[test]
+fn body_refers_to_the_parameter() {
+    let src = "typedef int T; void f(int T) { T = 1; }";
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let table = SymbolTable::collect(&ast);
+    let at = tokens.iter().rev().find(|t| t.text(src) == "T").unwrap().at;
+    assert_eq!(table.resolve(at).unwrap().kind, SymbolKind::Parameter);
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn function_specifiers_on_objects_are_reported() {
+    for src in [
+        "inline int x;",
+        "_Noreturn int *p;",
+        "inline void (*fp)(void);",
+        "inline int f(void), x;",
+        "void f(void) { inline int x; }",
+        "void f(inline int x);",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::FunctionSpecifierOnObject],
+            "{src}"
+        );
+    }
+}
+
+#[test]
+fn function_declarations_take_function_specifiers() {
+    for src in [
+        "inline int f(void);",
+        "_Noreturn void exit(int);",
+        "static inline int f(void), g(int);",
+        "inline int f(void) { return 0; }",
+        "_Noreturn void f(void) { for (;;); }",
+        "void f(void) { _Noreturn void g(void); }",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
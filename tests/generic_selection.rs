@@ -0,0 +1,104 @@
+//! `_Generic` associations name any type, and no two of them may name compatible
+//! types.
+
+mod common;
+
+use common::errors;
+use ecc::{
+    ast::{ExpressionKind, GenericAssociationKind},
+    lexer::Lexer,
+    parser::Parser,
+    types::{BaseType, Qualifiers, Tag, Type, TypeKind, type_name_type},
+    validate::ValidationErrKind,
+};
+
+fn base(base: BaseType<'static>) -> Type<'static> {
+    Type {
+        kind: TypeKind::Base(base),
+        qualifiers: Qualifiers::default(),
+    }
+}
+
+#[test]
+fn association_types() {
+    let src = "_Generic(x, const int: 1, struct S *: 2, MyType: 3, default: 0)";
+    let (tokens, _) = Lexer::new(src).lex();
+    let expression = Parser::new(&tokens)
+        .with_predeclared_typedefs(&["MyType"])
+        .parse_next_expression()
+        .unwrap();
+    let ExpressionKind::GenericSelection(generic) = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    let types: Vec<_> = generic
+        .generic_assocs
+        .iter()
+        .map(|association| match &association.kind {
+            GenericAssociationKind::ForType(type_name) => Some(type_name_type(type_name).unwrap()),
+            GenericAssociationKind::Default { .. } => None,
+        })
+        .collect();
+
+    let const_int = Type {
+        qualifiers: Qualifiers {
+            is_const: true,
+            ..Default::default()
+        },
+        ..base(BaseType::Int)
+    };
+    let struct_pointer = Type {
+        kind: TypeKind::Pointer(Box::new(base(BaseType::Struct(Tag::Named("S"))))),
+        qualifiers: Qualifiers::default(),
+    };
+    assert_eq!(
+        types,
+        [
+            Some(const_int),
+            Some(struct_pointer),
+            Some(base(BaseType::Typedef("MyType"))),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn distinct_associations() {
+    let src = "typedef long MyType; struct S;
+        int r = _Generic(0, const int: 1, struct S *: 2, MyType: 3, default: 0);";
+    assert_eq!(errors(src), []);
+    // Differently qualified types are not compatible.
+    assert_eq!(errors("int r = _Generic(0, int: 1, const int: 2);"), []);
+    assert_eq!(
+        errors("int r = _Generic(0, int *: 1, const int *: 2, int *const: 3);"),
+        []
+    );
+}
+
+#[test]
+fn duplicate_associations() {
+    use ValidationErrKind::DuplicateGenericAssociation as Duplicate;
+
+    for src in [
+        "int r = _Generic(0, int: 1, signed int: 2);",
+        "int r = _Generic(0, const int: 1, int const: 2);",
+        "struct S; int r = _Generic(0, struct S *: 1, struct S*: 2);",
+        "typedef long MyType; int r = _Generic(0, MyType: 1, long: 2);",
+        "int r = _Generic(0, int[]: 1, int[4]: 2);",
+        "int r = _Generic(0, default: 1, int: 2, default: 3);",
+    ] {
+        assert_eq!(errors(src), [Duplicate], "{src}");
+    }
+}
+
+#[test]
+fn typedefs_resolve_in_the_scope_of_the_selection() {
+    let distinct =
+        "typedef long T; void f(void) { typedef int T; int r = _Generic(0, T: 1, long: 2); }";
+    assert_eq!(errors(distinct), []);
+    let duplicate =
+        "typedef long T; void f(void) { typedef int T; int r = _Generic(0, T: 1, int: 2); }";
+    assert_eq!(
+        errors(duplicate),
+        [ValidationErrKind::DuplicateGenericAssociation]
+    );
+}
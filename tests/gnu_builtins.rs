@@ -0,0 +1,101 @@
+//! The GNU builtins that take type names get their own expressions in the GNU
+//! dialect, and are ordinary identifiers otherwise.
+
+use ecc::{
+    ast::{DesignatorKind, Expression, ExpressionKind},
+    lexer::Lexer,
+    parser::{Dialect, Parser, ParserOptions},
+    token::{Token, TokenKind},
+    types::{BaseType, Tag, Type, TypeKind, type_name_type},
+};
+
+/// Parses all of `tokens` as one expression.
+fn parse<'a>(tokens: &[Token<'a>], dialect: Dialect) -> Result<Expression<'a>, ()> {
+    let options = ParserOptions {
+        dialect,
+        ..Default::default()
+    };
+    let mut parser = Parser::with_options(tokens, options);
+    let expression = parser.parse_next_expression().map_err(|_| ())?;
+    match parser.remaining()[0].kind {
+        TokenKind::Eof => Ok(expression),
+        _ => Err(()),
+    }
+}
+
+fn base(base: BaseType<'static>) -> Type<'static> {
+    Type {
+        kind: TypeKind::Base(base),
+        qualifiers: Default::default(),
+    }
+}
+
+#[test]
+fn offsetof() {
+    let (tokens, _) = Lexer::new("__builtin_offsetof(struct S, x)").lex();
+    let expression = parse(&tokens, Dialect::Gnu).unwrap();
+    let ExpressionKind::Offsetof {
+        type_name, member, ..
+    } = &expression.kind
+    else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(
+        type_name_type(type_name),
+        Ok(base(BaseType::Struct(Tag::Named("S"))))
+    );
+    assert_eq!(member.name, "x");
+    assert!(member.designators.is_none());
+}
+
+#[test]
+fn offsetof_nested_member() {
+    let (tokens, _) = Lexer::new("__builtin_offsetof(struct S, a.b[2].c)").lex();
+    let expression = parse(&tokens, Dialect::Gnu).unwrap();
+    let ExpressionKind::Offsetof { member, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(member.name, "a");
+    let designators: Vec<_> = member
+        .designators
+        .iter()
+        .flat_map(|list| list.iter())
+        .map(|designator| match &designator.kind {
+            DesignatorKind::AfterPeriod { name, .. } => name.to_string(),
+            DesignatorKind::InBrackets { .. } => "[]".to_string(),
+        })
+        .collect();
+    assert_eq!(designators, ["b", "[]", "c"]);
+}
+
+#[test]
+fn types_compatible() {
+    let (tokens, _) = Lexer::new("__builtin_types_compatible_p(int, long)").lex();
+    let expression = parse(&tokens, Dialect::Gnu).unwrap();
+    let ExpressionKind::TypesCompatible { left, right, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(type_name_type(left), Ok(base(BaseType::Int)));
+    assert_eq!(type_name_type(right), Ok(base(BaseType::Long)));
+}
+
+#[test]
+fn type_name_operands_need_the_gnu_dialect() {
+    for src in [
+        "__builtin_offsetof(struct S, x)",
+        "__builtin_types_compatible_p(int, long)",
+    ] {
+        let (tokens, _) = Lexer::new(src).lex();
+        assert!(parse(&tokens, Dialect::Standard).is_err(), "{src}");
+    }
+}
+
+#[test]
+fn builtins_are_calls_outside_the_gnu_dialect() {
+    let (tokens, _) = Lexer::new("__builtin_offsetof(s, x)").lex();
+    let expression = parse(&tokens, Dialect::Standard).unwrap();
+    let ExpressionKind::Call { left, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(left.kind, ExpressionKind::Identifier("__builtin_offsetof"));
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use ecc::validate::ValidationErrKind;
+
+/// Validates `body` as the body of a function with some variables in scope.
+fn errors(body: &str) -> Vec<ValidationErrKind> {
+    let src = format!(
+        "struct S {{ int x; }}; int f(void);
+        void g(int a, int b, int *p, struct S *s, struct S t) {{ {body} }}"
+    );
+    common::errors(&src)
+}
+
+#[test]
+fn rvalue_operands_are_reported() {
+    for body in ["5++;", "--(a+b);", "f()++;", "++5;", "(a + 1)--;", "a++++;"] {
+        assert_eq!(
+            errors(body),
+            [ValidationErrKind::IncrementOfRvalue],
+            "{body}"
+        );
+    }
+}
+
+#[test]
+fn lvalue_operands_are_accepted() {
+    for body in [
+        "a++;", "--b;", "s->x++;", "(*p)++;", "++p[1];", "t.x--;", "((a))++;",
+    ] {
+        assert_eq!(errors(body), [], "{body}");
+    }
+}
@@ -0,0 +1,115 @@
+//! Braced initializers checked against the shape of the object they initialize.
+
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn nested_array_initializer_fits() {
+    assert_eq!(errors("int a[2][2] = {{1, 2}, {3, 4}};"), []);
+    assert_eq!(errors("int a[2][2] = {1, 2, 3, 4};"), []);
+    assert_eq!(errors("int a[2][2] = {{1}, {3}};"), []);
+}
+
+#[test]
+fn over_long_initializer_is_reported() {
+    assert_eq!(
+        errors("int a[2][2] = {{1, 2}, {3, 4}, {5, 6}};"),
+        [ValidationErrKind::ExcessInitializer]
+    );
+    assert_eq!(
+        errors("int a[2][2] = {{1, 2, 3}, {4}};"),
+        [ValidationErrKind::ExcessInitializer]
+    );
+    assert_eq!(
+        errors("int a[2][2] = {1, 2, 3, 4, 5};"),
+        [ValidationErrKind::ExcessInitializer]
+    );
+}
+
+#[test]
+fn nested_struct_initializer_fits() {
+    let src = "struct P { int x, y; }; struct L { struct P a, b; } l = {{1, 2}, {3, 4}};";
+    assert_eq!(errors(src), []);
+    let src = "struct P { int x, y; }; struct L { struct P a, b; } l = {{1, 2}, {3, 4}, {5}};";
+    assert_eq!(errors(src), [ValidationErrKind::ExcessInitializer]);
+    let src = "struct P { int x, y; } p = {.z = 1};";
+    assert_eq!(errors(src), [ValidationErrKind::UnknownMember]);
+}
+
+#[test]
+fn block_scope_struct_does_not_replace_the_outer_one() {
+    let src = "struct S { int a, b; };
+               void f(void) { struct S { int x; } s = {1}; }
+               struct S t = {1, 2};";
+    assert_eq!(errors(src), []);
+
+    let src = "struct S { int a, b; };
+               void f(void) { struct S { int x; } s = {1, 2}; }";
+    assert_eq!(errors(src), [ValidationErrKind::ExcessInitializer]);
+}
+
+#[test]
+fn block_scope_struct_is_used_inside_its_block() {
+    let src = "struct S { int a; };
+               void f(void) { struct S { int x, y; }; { struct S s = {1, 2}; } }";
+    assert_eq!(errors(src), []);
+}
+
+#[test]
+fn designator_within_bounds() {
+    assert_eq!(errors("int a[3] = {[2] = 1};"), []);
+    assert_eq!(errors("int a[3] = {[0] = 1, [1 + 1] = 2};"), []);
+}
+
+#[test]
+fn designator_out_of_bounds() {
+    assert_eq!(
+        errors("int a[3] = {[5] = 1};"),
+        [ValidationErrKind::DesignatorOutOfBounds]
+    );
+    assert_eq!(
+        errors("int a[3] = {[3] = 1};"),
+        [ValidationErrKind::DesignatorOutOfBounds]
+    );
+    assert_eq!(
+        errors("int a[3] = {[-1] = 1};"),
+        [ValidationErrKind::DesignatorOutOfBounds]
+    );
+}
+
+#[test]
+fn unsized_array_length_is_inferred_from_designators() {
+    assert_eq!(errors("int a[] = {[4] = 1}; extern int a[5];"), []);
+    assert_eq!(
+        errors("int a[] = {[4] = 1}; extern int a[4];"),
+        [ValidationErrKind::ConflictingTypes]
+    );
+    assert_eq!(errors("int a[] = {[5] = 1}; extern int a[6];"), []);
+    assert_eq!(
+        errors("int a[] = {[1] = 1, 2, [0] = 3}; extern int a[3];"),
+        []
+    );
+}
+
+#[test]
+fn unsized_array_length_is_inferred_from_a_string() {
+    for src in [
+        "char s[] = \"abc\"; extern char s[4];",
+        "char s[] = {\"abc\"}; extern char s[4];",
+        "char s[] = (\"abc\"); extern char s[4];",
+        "unsigned short s[] = {u\"\\U0001F600\"}; extern unsigned short s[3];",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+    assert_eq!(
+        errors("char s[] = {\"abc\"}; extern char s[1];"),
+        [ValidationErrKind::ConflictingTypes]
+    );
+    // Strings in an array of strings are elements of their own.
+    assert_eq!(
+        errors("char s[][4] = {\"abc\", \"de\"}; extern char s[2][4];"),
+        []
+    );
+}
@@ -0,0 +1,46 @@
+use ecc::{
+    ast::{DeclarationKind, ExternalDeclarationKind, InlineLinkage},
+    parse_decl,
+};
+
+/// Classifies the function declared or defined by `src`.
+fn linkage(src: &str) -> InlineLinkage {
+    let declaration = parse_decl!(src);
+    match &declaration.kind {
+        ExternalDeclarationKind::Function(function) => function.inline_linkage(),
+        ExternalDeclarationKind::Declaration(declaration) => match &declaration.kind {
+            DeclarationKind::Normal { specifiers, .. } => specifiers.inline_linkage(),
+            _ => panic!("{declaration:?}"),
+        },
+    }
+}
+
+#[test]
+fn definitions_and_declarations_are_classified_alike() {
+    let table = [
+        ("int", InlineLinkage::External),
+        ("extern int", InlineLinkage::External),
+        ("static int", InlineLinkage::Static),
+        ("inline int", InlineLinkage::Inline),
+        ("int inline", InlineLinkage::Inline),
+        ("extern inline int", InlineLinkage::ExternInline),
+        ("inline extern int", InlineLinkage::ExternInline),
+        ("static inline int", InlineLinkage::StaticInline),
+        ("inline static int", InlineLinkage::StaticInline),
+    ];
+    for (specifiers, expected) in table {
+        let definition = format!("{specifiers} f(void) {{ return 0; }}");
+        let declaration = format!("{specifiers} f(void);");
+        assert_eq!(linkage(&definition), expected, "{definition}");
+        assert_eq!(linkage(&declaration), expected, "{declaration}");
+    }
+}
+
+#[test]
+fn other_specifiers_do_not_matter() {
+    assert_eq!(
+        linkage("_Noreturn static inline void f(void) { for (;;); }"),
+        InlineLinkage::StaticInline
+    );
+    assert_eq!(linkage("const inline long f(void);"), InlineLinkage::Inline);
+}
@@ -0,0 +1,81 @@
+//! `__int128` is a type specifier in the GNU dialect and an ordinary identifier
+//! in standard C.
+
+use ecc::{
+    lexer::Lexer,
+    parser::{Dialect, Parser, ParserOptions},
+    symbols::SymbolTable,
+    types::{BaseType, TypeKind},
+};
+
+fn options(dialect: Dialect) -> ParserOptions {
+    ParserOptions {
+        dialect,
+        ..Default::default()
+    }
+}
+
+/// Returns the name and base type of each symbol declared in `src`.
+fn symbols(src: &str, dialect: Dialect) -> Option<Vec<(&str, BaseType<'_>)>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let result = Parser::with_options(&tokens, options(dialect)).parse();
+    if !result.is_ok() {
+        return None;
+    }
+    let ast = result.unwrap();
+    let table = SymbolTable::collect(&ast);
+    let symbols = table
+        .symbols()
+        .iter()
+        .map(|symbol| match &symbol.ty.as_ref().unwrap().kind {
+            TypeKind::Base(base) => (symbol.name, base.clone()),
+            kind => panic!("unexpected type {kind:?}"),
+        })
+        .collect();
+    Some(symbols)
+}
+
+#[test]
+fn int128_is_a_type_in_gnu() {
+    assert_eq!(
+        symbols("__int128 x;", Dialect::Gnu),
+        Some(vec![("x", BaseType::Int128)])
+    );
+    assert_eq!(
+        symbols("unsigned __int128 y;", Dialect::Gnu),
+        Some(vec![("y", BaseType::UnsignedInt128)])
+    );
+    assert_eq!(
+        symbols("signed __int128 z; __int128 unsigned w;", Dialect::Gnu),
+        Some(vec![
+            ("z", BaseType::Int128),
+            ("w", BaseType::UnsignedInt128)
+        ])
+    );
+}
+
+#[test]
+fn int128_is_an_identifier_in_standard_c() {
+    assert_eq!(symbols("__int128 x;", Dialect::Standard), None);
+    assert_eq!(symbols("unsigned __int128 y;", Dialect::Standard), None);
+    assert_eq!(
+        symbols("int __int128 = 1; long y = __int128;", Dialect::Standard),
+        Some(vec![("__int128", BaseType::Int), ("y", BaseType::Long)])
+    );
+}
+
+#[test]
+fn int128_cannot_be_cast_to_in_standard_c() {
+    let src = "int x = (__int128)1;";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert!(
+        Parser::with_options(&tokens, options(Dialect::Gnu))
+            .parse()
+            .is_ok()
+    );
+    assert!(
+        !Parser::with_options(&tokens, options(Dialect::Standard))
+            .parse()
+            .is_ok()
+    );
+}
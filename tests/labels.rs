@@ -0,0 +1,48 @@
+//! Labels live in their own name space, so they may share a name with a typedef.
+
+use ecc::{
+    ast::{JumpStatementKind, LabelKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records every label name and goto target, in source order.
+struct Labels<'a>(Vec<(&'static str, &'a str)>);
+impl<'a> Visitor<'a> for Labels<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        match node {
+            Node::Label(label) => {
+                if let LabelKind::Name(name) = label.kind {
+                    self.0.push(("label", name));
+                }
+            }
+            Node::JumpStatement(jump) => {
+                if let JumpStatementKind::Goto { target, .. } = jump.kind {
+                    self.0.push(("goto", target));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn labels(src: &str) -> Vec<(&'static str, &str)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut labels = Labels(Vec::new());
+    walk_translation_unit(&mut labels, &ast);
+    labels.0
+}
+
+#[test]
+fn label_named_like_a_typedef() {
+    let src = "typedef int T; void f(void) { T: goto T; }";
+    assert_eq!(labels(src), [("label", "T"), ("goto", "T")]);
+}
+
+#[test]
+fn goto_before_a_label_named_like_a_typedef() {
+    let src = "typedef int T; void f(void) { T x; goto T; x = 1; T: return; }";
+    assert_eq!(labels(src), [("goto", "T"), ("label", "T")]);
+}
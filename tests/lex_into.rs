@@ -0,0 +1,40 @@
+use ecc::{
+    lexer::Lexer,
+    token::{Files, TokenKind},
+};
+
+#[test]
+fn snippets_share_one_files_interner() {
+    let first = "# 1 \"a.h\"\nint x;\n# 1 \"b.h\"\nint y;\n";
+    let second = "# 1 \"b.h\"\nint z;\n# 1 \"c.h\"\nint w;\n";
+    let mut tokens = Vec::new();
+    let mut files = Files::new();
+    Lexer::new(first).lex_into(&mut tokens, &mut files);
+    let split = tokens.len();
+    Lexer::new(second).lex_into(&mut tokens, &mut files);
+
+    let names: Vec<_> = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Int)
+        .map(|t| (t.at.file, &files[t.at.file]))
+        .collect();
+    assert_eq!(names[0].1, "a.h");
+    assert_eq!(names[1].1, "b.h");
+    assert_eq!(names[2], names[1]);
+    assert_eq!(names[3].1, "c.h");
+    assert_ne!(names[3].0, names[0].0);
+
+    // Both snippets end in their own `Eof`, and the first one's tokens are kept.
+    assert_eq!(tokens[split - 1].kind, TokenKind::Eof);
+    assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+    assert_eq!(tokens[split - 1].at.file, tokens.last().unwrap().at.file);
+}
+
+#[test]
+fn lex_into_matches_lex() {
+    let src = "# 3 \"a.h\"\nint x = 1;\n";
+    let (expected, _) = Lexer::new(src).lex();
+    let mut tokens = Vec::new();
+    Lexer::new(src).lex_into(&mut tokens, &mut Files::new());
+    assert_eq!(tokens, expected);
+}
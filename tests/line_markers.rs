@@ -0,0 +1,75 @@
+use ecc::{lexer::Lexer, token::TokenKind};
+
+/// Lexes `src` and returns each token's text, line and file name.
+fn lines(src: &str) -> Vec<(&str, u32, String)> {
+    let (tokens, files) = Lexer::new(src).lex();
+    tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Eof)
+        .map(|t| (t.text(src), t.at.line, files[t.at.file].to_string()))
+        .collect()
+}
+
+#[test]
+fn non_numeric_line_is_an_error_token() {
+    let src = "# notanumber \"f\"\nint x;";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+    assert_eq!(tokens[0].text(src), "# notanumber \"f\"");
+
+    // The marker is skipped, so the next line keeps its own position.
+    let file = lines(src)[0].2.clone();
+    assert_eq!(
+        lines(src)[1..],
+        [
+            ("int", 2, file.clone()),
+            ("x", 2, file.clone()),
+            (";", 2, file)
+        ]
+    );
+}
+
+#[test]
+fn overflowing_line_is_an_error_token() {
+    let src = "# 99999999999 \"f\"\nint x;";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+    assert_eq!(tokens[0].text(src), "# 99999999999 \"f\"");
+    assert_eq!(lines(src)[1].1, 2);
+    assert_ne!(lines(src)[1].2, "f");
+}
+
+#[test]
+fn largest_line_is_accepted() {
+    let src = "# 4294967295 \"f\"\nint x;";
+    assert_eq!(lines(src)[0], ("int", u32::MAX, "f".to_string()));
+}
+
+#[test]
+fn marker_after_tokens_on_the_same_line() {
+    let src = "int a = 1 + # 40 \"m.h\"\n2;";
+    let lexed = lines(src);
+    let file = lexed[0].2.clone();
+    let before: Vec<_> = lexed[..5]
+        .iter()
+        .map(|(text, line, _)| (*text, *line))
+        .collect();
+    assert_eq!(before, [("int", 1), ("a", 1), ("=", 1), ("1", 1), ("+", 1)]);
+    assert!(lexed[..5].iter().all(|(_, _, name)| *name == file));
+    assert_eq!(
+        lexed[5..],
+        [("2", 40, "m.h".to_string()), (";", 40, "m.h".to_string())]
+    );
+
+    let (tokens, _) = Lexer::new(src).lex();
+    assert_eq!(tokens[5].at.column, 1);
+}
+
+#[test]
+fn marker_without_a_file_mid_line_keeps_the_file() {
+    let src = "# 3 \"f.c\"\nx # 7\ny";
+    assert_eq!(
+        lines(src),
+        [("x", 3, "f.c".to_string()), ("y", 7, "f.c".to_string())]
+    );
+}
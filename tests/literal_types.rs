@@ -0,0 +1,58 @@
+//! An unsuffixed decimal literal may only be signed, while a hexadecimal or octal
+//! one may also take the unsigned types, so the largest value differs by base.
+
+use ecc::{
+    eval::{DataModel, IntegerType, literal_type},
+    lexer::Lexer,
+    parser::Parser,
+    token::{Token, TokenKind},
+    validate::{ValidationErrKind, validate},
+};
+
+fn literal(src: &str) -> Token<'_> {
+    Lexer::new(src).lex().0[0]
+}
+
+fn ty(src: &str) -> Option<IntegerType> {
+    let TokenKind::Integer(int) = literal(src).kind else {
+        panic!("{src} is not an integer literal");
+    };
+    literal_type(&int, DataModel::LP64)
+}
+
+fn errors(src: &str) -> Vec<(u32, ValidationErrKind)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    validate(&ast)
+        .into_iter()
+        .map(|err| (err.at.column, err.kind))
+        .collect()
+}
+
+#[test]
+fn huge_decimal_has_no_type() {
+    // 2^64 - 1 does not fit `long long`, and a decimal literal may not be unsigned.
+    assert_eq!(ty("18446744073709551615"), None);
+    assert_eq!(ty("9223372036854775807"), Some(IntegerType::Long));
+    assert_eq!(ty("18446744073709551615u"), Some(IntegerType::UnsignedLong));
+}
+
+#[test]
+fn same_value_in_hex_is_unsigned() {
+    assert_eq!(ty("0xFFFFFFFFFFFFFFFF"), Some(IntegerType::UnsignedLong));
+    assert_eq!(
+        ty("01777777777777777777777"),
+        Some(IntegerType::UnsignedLong)
+    );
+    assert_eq!(ty("0x7FFFFFFFFFFFFFFF"), Some(IntegerType::Long));
+    assert_eq!(ty("0x1'0000'0000'0000'0000"), None);
+}
+
+#[test]
+fn huge_decimal_is_reported_at_the_literal() {
+    assert_eq!(
+        errors("unsigned long long x = 18446744073709551615;"),
+        [(24, ValidationErrKind::IntegerLiteralTooLarge)]
+    );
+    assert_eq!(errors("unsigned long long x = 0xFFFFFFFFFFFFFFFF;"), []);
+}
@@ -0,0 +1,62 @@
+use ecc::{
+    ast::{IterationStatementKind, SecondaryBlock, StatementKind, UnlabeledStatementKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records whether the body of each loop is a null statement.
+struct NullBodies(Vec<bool>);
+impl<'a> Visitor<'a> for NullBodies {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::IterationStatement(statement) = node {
+            let body = match &statement.kind {
+                IterationStatementKind::While { body, .. }
+                | IterationStatementKind::DoWhile { body, .. }
+                | IterationStatementKind::For { body, .. } => body,
+            };
+            self.0.push(is_null(body));
+        }
+    }
+}
+
+fn is_null(body: &SecondaryBlock) -> bool {
+    matches!(
+        &body.statement.kind,
+        StatementKind::Unlabeled(statement)
+            if matches!(
+                &statement.kind,
+                UnlabeledStatementKind::Expression(expression)
+                    if expression.expression.is_none() && expression.attributes.is_none()
+            )
+    )
+}
+
+fn null_bodies(body: &str) -> Vec<bool> {
+    let src = format!("void f(int x) {{ {body} }}");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut bodies = NullBodies(Vec::new());
+    walk_translation_unit(&mut bodies, &ast);
+    bodies.0
+}
+
+#[test]
+fn null_statement_bodies() {
+    assert_eq!(null_bodies("while (x);"), [true]);
+    assert_eq!(null_bodies("for (;;);"), [true]);
+    assert_eq!(null_bodies("for (int i = 0; i < x; i++) ;"), [true]);
+    assert_eq!(null_bodies("do ; while (0);"), [true]);
+    assert_eq!(null_bodies("while (x) { }"), [false]);
+    assert_eq!(null_bodies("while (x) x--;"), [false]);
+    assert_eq!(null_bodies("while (x) while (x);"), [false, true]);
+}
+
+#[test]
+fn missing_bodies_are_errors() {
+    for body in ["while (x)", "for (;;)", "do while (0);"] {
+        let src = format!("void f(int x) {{ {body} }}");
+        let (tokens, _) = Lexer::new(&src).lex();
+        assert_ne!(Parser::new(&tokens).parse().errors, [], "{src}");
+    }
+}
@@ -0,0 +1,134 @@
+use ecc::{
+    ast::{ExpressionKind, TranslationUnit},
+    eval::{EvalConfig, EvalErrKind, Evaluator},
+    lexer::Lexer,
+    parser::{Dialect, Parser, ParserOptions},
+    symbols::SymbolTable,
+    types::{TypeKind, type_name_type},
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+const TYPES: &str = "
+struct inner { char tag[3]; int value; };
+struct outer {
+    char flag;
+    struct inner inner[4];
+    union { short s; double d; };
+    unsigned bits : 3;
+    long tail;
+};
+";
+
+/// Evaluates every `__builtin_offsetof` in a translation unit, in order.
+struct Offsets<'s, 'a> {
+    symbols: &'s SymbolTable<'a>,
+    evaluator: Evaluator,
+    results: Vec<Result<u64, EvalErrKind>>,
+}
+impl<'a> Visitor<'a> for Offsets<'_, 'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Expression(expr) = node
+            && let ExpressionKind::Offsetof {
+                type_name, member, ..
+            } = &expr.kind
+        {
+            let object = type_name_type(type_name).unwrap();
+            let offset = self.evaluator.offsetof(&object, member, self.symbols);
+            self.results.push(offset.map_err(|err| err.kind));
+        }
+    }
+}
+
+/// Evaluates `__builtin_offsetof(struct outer, member)` for each of `members`.
+fn offsets(members: &[&str]) -> Vec<Result<u64, EvalErrKind>> {
+    let mut src = TYPES.to_owned();
+    for (i, member) in members.iter().enumerate() {
+        src += &format!("unsigned long o{i} = __builtin_offsetof(struct outer, {member});\n");
+    }
+    offsets_in(&src)
+}
+
+/// Evaluates every `__builtin_offsetof` in `src`, in order.
+fn offsets_in(src: &str) -> Vec<Result<u64, EvalErrKind>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let ast: TranslationUnit = Parser::with_options(&tokens, options).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let mut offsets = Offsets {
+        symbols: &symbols,
+        evaluator: Evaluator::new(EvalConfig::default()),
+        results: Vec::new(),
+    };
+    walk_translation_unit(&mut offsets, &ast);
+    offsets.results
+}
+
+#[test]
+fn nested_members_and_subscripts() {
+    assert_eq!(
+        offsets(&[
+            "flag",
+            "inner",
+            "inner[1]",
+            "inner[1].tag[2]",
+            "inner[2].value",
+            "inner[1 + 2].tag",
+            "d",
+            "tail",
+        ]),
+        [Ok(0), Ok(4), Ok(12), Ok(14), Ok(24), Ok(28), Ok(40), Ok(56)]
+    );
+}
+
+#[test]
+fn invalid_designators_are_errors() {
+    let invalid = Err(EvalErrKind::InvalidMemberDesignator);
+    assert_eq!(
+        offsets(&[
+            "missing",
+            "inner[0].missing",
+            "flag[1]",
+            "inner.tag",
+            "bits"
+        ]),
+        [invalid; 5]
+    );
+}
+
+#[test]
+fn block_scope_tags_keep_their_own_members() {
+    let src = "
+        struct S { char c; int x; };
+        void f(void) {
+            struct S { int x; } local;
+            struct S *p = &local;
+        }
+        unsigned long o = __builtin_offsetof(struct S, x);
+    ";
+    assert_eq!(offsets_in(src), [Ok(4)]);
+
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let ast = Parser::with_options(&tokens, options).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let ty = |name| {
+        let symbol = symbols.symbols().iter().find(|s| s.name == name).unwrap();
+        symbol.ty.clone().unwrap()
+    };
+    let data_model = EvalConfig::default().data_model;
+    let (offset, _) = symbols
+        .member_offset(&ty("local"), "x", data_model)
+        .unwrap();
+    assert_eq!(offset, 0);
+    assert_eq!(symbols.layout(&ty("local"), data_model).unwrap().size, 4);
+    let TypeKind::Pointer(pointee) = ty("p").kind else {
+        panic!("expected a pointer");
+    };
+    assert_eq!(*pointee, ty("local"));
+}
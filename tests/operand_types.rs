@@ -0,0 +1,72 @@
+use ecc::{
+    ast::BinaryOperator,
+    lexer::Lexer,
+    lint::{LintKind, LintOptions, lint},
+    parser::Parser,
+};
+
+/// Returns the operators whose operands `lint` rejects, with their columns.
+fn invalid(src: &str) -> Vec<(u32, BinaryOperator)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let options = LintOptions {
+        operand_types: true,
+        ..Default::default()
+    };
+    lint(&ast, options)
+        .into_iter()
+        .map(|l| match l.kind {
+            LintKind::InvalidOperands { operator } => (l.at.column, operator),
+            kind => panic!("unexpected lint {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn arithmetic_operands_are_valid() {
+    for src in [
+        "int f(int a, int b) { return a + b; }",
+        "int *f(int *p, int n) { return p + n; }",
+        "int *f(int *p, int n) { return n + p; }",
+        "long f(int *p, int *q) { return p - q; }",
+        "int f(int a, long b) { return a % b; }",
+        "double f(float a, int b) { return a * b; }",
+        "int f(char c, _Bool b) { return c << b; }",
+    ] {
+        assert_eq!(invalid(src), [], "{src}");
+    }
+}
+
+#[test]
+fn structure_operand_is_invalid() {
+    let src = "struct S { int x; }; int f(struct S s, int i) { return s + i; }";
+    assert_eq!(invalid(src), [(58, BinaryOperator::Add)]);
+}
+
+#[test]
+fn floating_remainder_is_invalid() {
+    assert_eq!(
+        invalid("int f(float a, int b) { return a % b; }"),
+        [(34, BinaryOperator::Modulo)]
+    );
+    assert_eq!(
+        invalid("int f(int a, double b) { return a << b; }"),
+        [(35, BinaryOperator::ShiftLeft)]
+    );
+}
+
+#[test]
+fn pointer_arithmetic_needs_an_integer() {
+    assert_eq!(
+        invalid("int *f(int *p, int *q) { return p + q; }"),
+        [(35, BinaryOperator::Add)]
+    );
+    assert_eq!(
+        invalid("int *f(int *p, double d) { return p - d; }"),
+        [(37, BinaryOperator::Subtract)]
+    );
+    assert_eq!(
+        invalid("int f(int *p, int n) { return n - p; }"),
+        [(33, BinaryOperator::Subtract)]
+    );
+}
@@ -0,0 +1,74 @@
+use ecc::{
+    eval::{DataModel, EvalConfig, EvalErrKind, Evaluator, IntegerType, Overflow, Value},
+    lexer::Lexer,
+    parser::Parser,
+};
+
+fn evaluate(src: &str, config: EvalConfig) -> Result<Value, EvalErrKind> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let expression = Parser::new(&tokens).parse_next_expression().unwrap();
+    Evaluator::new(config)
+        .evaluate(&expression)
+        .map_err(|err| err.kind)
+}
+
+fn with(overflow: Overflow) -> EvalConfig {
+    EvalConfig {
+        overflow,
+        ..Default::default()
+    }
+}
+
+fn int(value: i128) -> Result<Value, EvalErrKind> {
+    Ok(Value {
+        value,
+        ty: IntegerType::Int,
+    })
+}
+
+#[test]
+fn int_max_plus_one() {
+    let src = "2147483647 + 1";
+    assert_eq!(
+        evaluate(src, EvalConfig::default()),
+        Err(EvalErrKind::Overflow)
+    );
+    assert_eq!(
+        evaluate(src, with(Overflow::Error)),
+        Err(EvalErrKind::Overflow)
+    );
+    assert_eq!(evaluate(src, with(Overflow::Wrap)), int(-2147483648));
+    assert_eq!(evaluate(src, with(Overflow::Saturate)), int(2147483647));
+}
+
+#[test]
+fn unsigned_arithmetic_always_wraps() {
+    let value = evaluate("4294967295u + 1", with(Overflow::Error));
+    assert_eq!(
+        value,
+        Ok(Value {
+            value: 0,
+            ty: IntegerType::UnsignedInt
+        })
+    );
+}
+
+#[test]
+fn shift_counts_depend_on_the_data_model() {
+    assert_eq!(
+        evaluate("1 << 40", EvalConfig::default()),
+        Err(EvalErrKind::InvalidShift)
+    );
+    let long = |data_model| EvalConfig {
+        data_model,
+        ..Default::default()
+    };
+    assert_eq!(
+        evaluate("1L << 40", long(DataModel::LP64)).map(|v| v.value),
+        Ok(1 << 40)
+    );
+    assert_eq!(
+        evaluate("1L << 40", long(DataModel::LLP64)),
+        Err(EvalErrKind::InvalidShift)
+    );
+}
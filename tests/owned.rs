@@ -0,0 +1,49 @@
+use ecc::{
+    lexer::Lexer,
+    owned::{
+        DeclarationKind, DirectDeclaratorKind, ExpressionKind, ExternalDeclarationKind,
+        InitializerKind, ToOwnedAst, TranslationUnit,
+    },
+    parser::Parser,
+};
+
+/// Parses a source buffer that is dropped before the tree is returned.
+fn parse_owned() -> TranslationUnit {
+    let src = String::from("int counter = total; long limit;");
+    let (tokens, _) = Lexer::new(&src).lex();
+    let unit = Parser::new(&tokens).parse().unwrap();
+    let owned = unit.to_owned_ast();
+    drop(tokens);
+    drop(src);
+    owned
+}
+
+#[test]
+fn names_outlive_the_source() {
+    let unit = parse_owned();
+    let mut names = Vec::new();
+    for declaration in unit.iter() {
+        let ExternalDeclarationKind::Declaration(declaration) = &declaration.kind else {
+            panic!("expected a declaration");
+        };
+        let DeclarationKind::Normal {
+            init_declarators: Some(init_declarators),
+            ..
+        } = &declaration.kind
+        else {
+            panic!("expected declarators");
+        };
+        for init_declarator in init_declarators.iter() {
+            if let DirectDeclaratorKind::Name(name, _) = &init_declarator.declarator.direct.kind {
+                names.push(name.to_string());
+            }
+            if let Some((_, initializer)) = &init_declarator.initializer
+                && let InitializerKind::Expression(expression) = &initializer.kind
+                && let ExpressionKind::Identifier(name) = &expression.kind
+            {
+                names.push(name.to_string());
+            }
+        }
+    }
+    assert_eq!(names, ["counter", "total", "limit"]);
+}
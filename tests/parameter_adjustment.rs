@@ -0,0 +1,101 @@
+//! Array and function parameters are adjusted to pointers, and the qualifiers
+//! inside an array parameter's brackets apply to that pointer.
+
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    types::{BaseType, Qualifiers, Type, TypeKind, parameter_adjusted_type},
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Collects the adjusted type of every parameter.
+struct Parameters<'a>(Vec<Type<'a>>);
+impl<'a> Visitor<'a> for Parameters<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::ParameterDeclaration(parameter) = node {
+            self.0.push(parameter_adjusted_type(parameter).unwrap());
+        }
+    }
+}
+
+/// Returns the adjusted parameter types of the functions declared in `src`.
+fn adjusted(src: &str) -> Vec<Type<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut parameters = Parameters(Vec::new());
+    walk_translation_unit(&mut parameters, &ast);
+    parameters.0
+}
+
+fn int() -> Type<'static> {
+    Type {
+        kind: TypeKind::Base(BaseType::Int),
+        qualifiers: Qualifiers::default(),
+    }
+}
+
+fn pointer(pointee: Type<'static>, qualifiers: Qualifiers) -> Type<'static> {
+    Type {
+        kind: TypeKind::Pointer(Box::new(pointee)),
+        qualifiers,
+    }
+}
+
+#[test]
+fn restrict_array_becomes_restrict_pointer() {
+    let restrict = Qualifiers {
+        is_restrict: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        adjusted("void f(int a[restrict 10]);"),
+        [pointer(int(), restrict)]
+    );
+    assert_eq!(
+        adjusted("void f(int [restrict]);"),
+        [pointer(int(), restrict)]
+    );
+}
+
+#[test]
+fn const_array_becomes_const_pointer() {
+    let constant = Qualifiers {
+        is_const: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        adjusted("void f(int a[const]);"),
+        [pointer(int(), constant)]
+    );
+    assert_eq!(
+        adjusted("void f(int a[static const volatile 4]);"),
+        [pointer(
+            int(),
+            Qualifiers {
+                is_volatile: true,
+                ..constant
+            }
+        )]
+    );
+}
+
+#[test]
+fn only_the_outermost_array_is_adjusted() {
+    let Type { kind, qualifiers } = adjusted("void f(int a[const 2][3]);").remove(0);
+    assert!(qualifiers.is_const);
+    let TypeKind::Pointer(pointee) = kind else {
+        panic!("{kind:?}");
+    };
+    assert!(
+        matches!(pointee.kind, TypeKind::Array { .. }),
+        "{pointee:?}"
+    );
+}
+
+#[test]
+fn unqualified_array_becomes_plain_pointer() {
+    assert_eq!(
+        adjusted("void f(int a[10]);"),
+        [pointer(int(), Qualifiers::default())]
+    );
+}
@@ -0,0 +1,54 @@
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, Parser},
+    token::TokenKind,
+};
+
+#[test]
+fn success_has_an_ast_and_no_errors() {
+    let (tokens, _) = Lexer::new("int a; int b = 1;").lex();
+    let result = Parser::new(&tokens).parse();
+    assert!(result.is_ok());
+    assert!(result.errors().is_empty());
+
+    let (ast, errors) = result.clone().into_parts();
+    assert_eq!(ast.unwrap().iter().count(), 2);
+    assert!(errors.is_empty());
+    assert_eq!(result.unwrap().iter().count(), 2);
+}
+
+#[test]
+fn failure_has_errors_and_no_ast() {
+    let (tokens, _) = Lexer::new("int x = ;").lex();
+    let result = Parser::new(&tokens).parse();
+    assert!(!result.is_ok());
+    assert!(result.ast.is_none());
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].expected, Expected::ExternalDeclaration);
+    assert_eq!(result.errors()[0].at.kind, TokenKind::Int);
+
+    let (ast, errors) = result.into_parts();
+    assert!(ast.is_err());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn recovery_keeps_the_declarations_before_an_error() {
+    let (tokens, _) = Lexer::new("int a; int b = ;").lex();
+    let result = Parser::new(&tokens).parse();
+    assert!(!result.is_ok());
+    assert_eq!(result.ast.as_ref().unwrap().iter().count(), 1);
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].at.at.column, 8);
+
+    let (ast, errors) = result.into_parts();
+    assert_eq!(ast.unwrap().iter().count(), 1);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "called `ParseResult::unwrap` with errors")]
+fn unwrap_panics_on_a_partial_ast() {
+    let (tokens, _) = Lexer::new("int a; int b = ;").lex();
+    Parser::new(&tokens).parse().unwrap();
+}
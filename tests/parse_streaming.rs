@@ -0,0 +1,50 @@
+use ecc::{
+    ast::ExternalDeclaration,
+    lexer::Lexer,
+    parser::{Expected, Parser},
+};
+
+/// Parses `src` in streaming mode, returning the line and column of each
+/// declaration passed to the callback, in the order it was called.
+fn stream(src: &str) -> (Vec<(u32, u32)>, Vec<Expected<'_>>) {
+    let (tokens, _) = Lexer::new(src).lex();
+    let mut positions = Vec::new();
+    let errors = Parser::new(&tokens).parse_streaming(|declaration: ExternalDeclaration| {
+        positions.push((declaration.at.line, declaration.at.column));
+    });
+    let errors = errors.into_iter().map(|err| err.expected).collect();
+    (positions, errors)
+}
+
+#[test]
+fn callback_fires_once_per_declaration_in_order() {
+    let src = "typedef int T;\nT f(T x) { return x; }\nint a, b;\nstruct S { T t; };\nT g(void);";
+    let (positions, errors) = stream(src);
+    assert_eq!(positions, [(1, 1), (2, 1), (3, 1), (4, 1), (5, 1)]);
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn callback_matches_parse() {
+    let src = "int a; void f(void) {} _Static_assert(1, \"\"); extern int b[];";
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut streamed = Vec::new();
+    let errors = Parser::new(&tokens).parse_streaming(|declaration| streamed.push(declaration));
+    assert!(errors.is_empty());
+    assert_eq!(streamed, ast.iter().cloned().collect::<Vec<_>>());
+}
+
+#[test]
+fn declarations_before_an_error_are_still_streamed() {
+    let (positions, errors) = stream("int a; int b; int c = ; int d;");
+    assert_eq!(positions, [(1, 1), (1, 8)]);
+    assert_eq!(errors, [Expected::ExternalDeclaration]);
+}
+
+#[test]
+fn empty_input_never_calls_back() {
+    let (positions, errors) = stream("");
+    assert_eq!(positions, []);
+    assert_eq!(errors, [Expected::NonEmptyTranslationUnit]);
+}
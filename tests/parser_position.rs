@@ -0,0 +1,78 @@
+use ecc::{
+    ast::ExternalDeclarationKind,
+    lexer::{Lexer, LexerOptions},
+    parser::Parser,
+    token::TokenKind,
+};
+
+#[test]
+fn parsing_a_declaration_moves_to_the_next_token() {
+    let src = "int a; long b = 2; void f(void) {}";
+    let (tokens, _) = Lexer::new(src).lex();
+    let mut parser = Parser::new(&tokens);
+    assert_eq!(parser.position(), 0);
+    assert_eq!(parser.remaining(), &tokens[..]);
+
+    parser.parse_next_declaration().unwrap();
+    assert_eq!(parser.position(), 3);
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Long);
+
+    parser.parse_next_declaration().unwrap();
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Void);
+
+    let function = parser.parse_next_declaration().unwrap();
+    assert!(matches!(
+        function.kind,
+        ExternalDeclarationKind::Function(_)
+    ));
+    assert_eq!(parser.remaining().len(), 1);
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Eof);
+}
+
+#[test]
+fn failed_declaration_leaves_the_position_unchanged() {
+    let (tokens, _) = Lexer::new("int a; int = ; int c;").lex();
+    let mut parser = Parser::new(&tokens);
+    parser.parse_next_declaration().unwrap();
+    let position = parser.position();
+    assert!(parser.parse_next_declaration().is_err());
+    assert_eq!(parser.position(), position);
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Int);
+}
+
+#[test]
+fn resuming_with_a_new_parser() {
+    let src = "typedef int T; T x; T y;";
+    let (tokens, _) = Lexer::new(src).lex();
+    let mut first = Parser::new(&tokens);
+    first.parse_next_declaration().unwrap();
+    first.parse_next_declaration().unwrap();
+
+    let mut second = Parser::new(&tokens)
+        .with_predeclared_typedefs(&["T"])
+        .starting_at(first.position());
+    assert_eq!(second.remaining(), first.remaining());
+    second.parse_next_declaration().unwrap();
+    assert_eq!(second.remaining()[0].kind, TokenKind::Eof);
+}
+
+#[test]
+fn position_skips_comments_after_a_declaration() {
+    let options = LexerOptions {
+        keep_comments: true,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options("int a; /* between */ int b;", options).lex();
+    let mut parser = Parser::new(&tokens);
+    parser.parse_next_declaration().unwrap();
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Int);
+    assert_eq!(parser.position(), 4);
+}
+
+#[test]
+fn expression_stops_before_the_semicolon() {
+    let (tokens, _) = Lexer::new("a + b, c; d").lex();
+    let mut parser = Parser::new(&tokens);
+    parser.parse_next_expression().unwrap();
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Semicolon);
+}
@@ -0,0 +1,133 @@
+//! The qualifiers of each pointer level in a declarator stay with that level.
+
+use ecc::ast::TypeQualifierKind::{Const, Restrict, Volatile};
+use ecc::{
+    ast::{DeclarationKind, ExternalDeclarationKind, TypeQualifierKind},
+    lexer::Lexer,
+    parser::Parser,
+    symbols::SymbolTable,
+    types::{BaseType, Qualifiers, Type, TypeKind},
+};
+
+/// Returns the qualifiers of each pointer level of the first declarator in `src`.
+fn levels(src: &str) -> Vec<Vec<TypeQualifierKind>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let declaration = ast.iter().next().unwrap();
+    let ExternalDeclarationKind::Declaration(declaration) = &declaration.kind else {
+        panic!("expected a declaration");
+    };
+    let DeclarationKind::Normal {
+        init_declarators: Some(init_declarators),
+        ..
+    } = &declaration.kind
+    else {
+        panic!("expected a declarator");
+    };
+    let declarator = &init_declarators.iter().next().unwrap().declarator;
+    declarator
+        .pointer
+        .as_ref()
+        .unwrap()
+        .levels()
+        .iter()
+        .map(|level| {
+            level
+                .qualifiers
+                .iter()
+                .flat_map(|q| q.iter())
+                .map(|q| q.kind)
+                .collect()
+        })
+        .collect()
+}
+
+fn ty(src: &str) -> Type<'_> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    SymbolTable::collect(&ast).symbols()[0].ty.clone().unwrap()
+}
+
+fn qualifiers(qualifiers: &[TypeQualifierKind]) -> Qualifiers {
+    let mut result = Qualifiers::default();
+    for qualifier in qualifiers {
+        match qualifier {
+            TypeQualifierKind::Const => result.is_const = true,
+            TypeQualifierKind::Restrict => result.is_restrict = true,
+            TypeQualifierKind::Volatile => result.is_volatile = true,
+            TypeQualifierKind::Atomic => result.is_atomic = true,
+        }
+    }
+    result
+}
+
+fn base(base: BaseType<'static>, quals: &[TypeQualifierKind]) -> Type<'static> {
+    Type {
+        kind: TypeKind::Base(base),
+        qualifiers: qualifiers(quals),
+    }
+}
+
+fn pointer(pointee: Type<'static>, quals: &[TypeQualifierKind]) -> Type<'static> {
+    Type {
+        kind: TypeKind::Pointer(Box::new(pointee)),
+        qualifiers: qualifiers(quals),
+    }
+}
+
+#[test]
+fn const_pointer_to_pointer() {
+    assert_eq!(levels("int * const * p;"), [vec![Const], vec![]]);
+    assert_eq!(
+        ty("int * const * p;"),
+        pointer(pointer(base(BaseType::Int, &[]), &[Const]), &[])
+    );
+}
+
+#[test]
+fn restrict_pointer_to_const_pointer() {
+    assert_eq!(
+        levels("int * const * restrict p;"),
+        [vec![Const], vec![Restrict]]
+    );
+    assert_eq!(
+        ty("int * const * restrict p;"),
+        pointer(pointer(base(BaseType::Int, &[]), &[Const]), &[Restrict])
+    );
+}
+
+#[test]
+fn const_pointer_to_restrict_pointer() {
+    assert_eq!(
+        levels("char * restrict * const p;"),
+        [vec![Restrict], vec![Const]]
+    );
+    assert_eq!(
+        ty("char * restrict * const p;"),
+        pointer(pointer(base(BaseType::Char, &[]), &[Restrict]), &[Const])
+    );
+}
+
+#[test]
+fn const_pointer_to_const_char() {
+    assert_eq!(levels("const char * const p;"), [vec![Const]]);
+    assert_eq!(
+        ty("const char * const p;"),
+        pointer(base(BaseType::Char, &[Const]), &[Const])
+    );
+}
+
+#[test]
+fn several_qualifiers_on_one_level() {
+    assert_eq!(
+        levels("int * const volatile * restrict const p;"),
+        [vec![Const, Volatile], vec![Restrict, Const]]
+    );
+    assert_eq!(
+        ty("int * const volatile * restrict const p;"),
+        pointer(
+            pointer(base(BaseType::Int, &[]), &[Const, Volatile]),
+            &[Restrict, Const]
+        )
+    );
+}
@@ -0,0 +1,218 @@
+//! Token positions in sources indented with tabs, spaces and both, or starting
+//! with a byte order mark.
+
+use ecc::{
+    lexer::{Lexer, LexerOptions},
+    token::TokenKind,
+};
+
+/// Lexes `src` with tab stops every `tab_width` columns and returns the line,
+/// column and spelling of each token.
+fn positions(src: &str, tab_width: u32) -> Vec<(u32, u32, &str)> {
+    let options = LexerOptions {
+        tab_width,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options(src, options).lex();
+    tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Eof)
+        .map(|t| (t.at.line, t.at.column, t.text(src)))
+        .collect()
+}
+
+#[test]
+fn tab_indentation() {
+    let src = "int main(void) {\n\treturn 0;\n\t\t}\n";
+    assert_eq!(
+        positions(src, 4),
+        [
+            (1, 1, "int"),
+            (1, 5, "main"),
+            (1, 9, "("),
+            (1, 10, "void"),
+            (1, 14, ")"),
+            (1, 16, "{"),
+            (2, 5, "return"),
+            (2, 12, "0"),
+            (2, 13, ";"),
+            (3, 9, "}"),
+        ]
+    );
+    assert_eq!(
+        positions(src, 8)[6..],
+        [(2, 9, "return"), (2, 16, "0"), (2, 17, ";"), (3, 17, "}")]
+    );
+}
+
+#[test]
+fn tabs_after_spaces_stop_at_the_next_tab_stop() {
+    let src = "  \tint x;\n \t \ty = 1;\n\t  \tz;";
+    assert_eq!(
+        positions(src, 4),
+        [
+            (1, 5, "int"),
+            (1, 9, "x"),
+            (1, 10, ";"),
+            (2, 9, "y"),
+            (2, 11, "="),
+            (2, 13, "1"),
+            (2, 14, ";"),
+            (3, 9, "z"),
+            (3, 10, ";"),
+        ]
+    );
+}
+
+#[test]
+fn tabs_between_tokens() {
+    let src = "int\tx\t=\t1;";
+    assert_eq!(
+        positions(src, 4),
+        [
+            (1, 1, "int"),
+            (1, 5, "x"),
+            (1, 9, "="),
+            (1, 13, "1"),
+            (1, 14, ";")
+        ]
+    );
+    // With the default width of one, a tab is a single column like a space.
+    assert_eq!(
+        positions(src, 1),
+        [
+            (1, 1, "int"),
+            (1, 5, "x"),
+            (1, 7, "="),
+            (1, 9, "1"),
+            (1, 10, ";")
+        ]
+    );
+}
+
+#[test]
+fn tab_inside_a_string_literal() {
+    let src = "char *s = \"a\tb\"; int z;";
+    assert_eq!(
+        positions(src, 8),
+        [
+            (1, 1, "char"),
+            (1, 6, "*"),
+            (1, 7, "s"),
+            (1, 9, "="),
+            (1, 11, "\"a\tb\""),
+            (1, 19, ";"),
+            (1, 21, "int"),
+            (1, 25, "z"),
+            (1, 26, ";"),
+        ]
+    );
+}
+
+#[test]
+fn crlf_line_endings() {
+    let src = "int a;\r\n\tint b;\r\n  b;";
+    assert_eq!(
+        positions(src, 4),
+        [
+            (1, 1, "int"),
+            (1, 5, "a"),
+            (1, 6, ";"),
+            (2, 5, "int"),
+            (2, 9, "b"),
+            (2, 10, ";"),
+            (3, 3, "b"),
+            (3, 4, ";"),
+        ]
+    );
+}
+
+#[test]
+fn columns_after_multi_character_tokens() {
+    let src = "x<<=y>>=0x1Full...->z;";
+    assert_eq!(
+        positions(src, 1),
+        [
+            (1, 1, "x"),
+            (1, 2, "<<="),
+            (1, 5, "y"),
+            (1, 6, ">>="),
+            (1, 9, "0x1Full"),
+            (1, 16, "..."),
+            (1, 19, "->"),
+            (1, 21, "z"),
+            (1, 22, ";"),
+        ]
+    );
+}
+
+#[test]
+fn columns_count_characters_not_bytes() {
+    let src = "s = \"héllo\"; /* ü */ t; // ß\nu;";
+    assert_eq!(
+        positions(src, 1),
+        [
+            (1, 1, "s"),
+            (1, 3, "="),
+            (1, 5, "\"héllo\""),
+            (1, 12, ";"),
+            (1, 22, "t"),
+            (1, 23, ";"),
+            (2, 1, "u"),
+            (2, 2, ";"),
+        ]
+    );
+}
+
+#[test]
+fn columns_after_long_whitespace_and_comments() {
+    let src = format!(
+        "{}a /* one\n   two */  b\n{}c",
+        " ".repeat(100),
+        "\t".repeat(3)
+    );
+    assert_eq!(
+        positions(&src, 4),
+        [(1, 101, "a"), (2, 12, "b"), (3, 13, "c")]
+    );
+}
+
+#[test]
+fn byte_order_mark_takes_no_column() {
+    let plain = "int x;\n  long y;";
+    let src = format!("\u{FEFF}{plain}");
+    assert_eq!(positions(&src, 1), positions(plain, 1));
+    assert_eq!(
+        positions(&src, 1),
+        [
+            (1, 1, "int"),
+            (1, 5, "x"),
+            (1, 6, ";"),
+            (2, 3, "long"),
+            (2, 8, "y"),
+            (2, 9, ";"),
+        ]
+    );
+
+    // Offsets still count the mark's three bytes.
+    let (tokens, _) = Lexer::new(&src).lex();
+    assert_eq!(tokens[0].at.offset, 3);
+    assert_eq!(tokens[1].at.offset, 7);
+}
+
+#[test]
+fn byte_order_mark_is_only_skipped_at_the_start() {
+    let src = "int\u{FEFF}x;";
+    let (tokens, _) = Lexer::new(src).lex();
+    let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::Int,
+            TokenKind::Error,
+            TokenKind::Identifier("x"),
+            TokenKind::Semicolon,
+            TokenKind::Eof,
+        ]
+    );
+}
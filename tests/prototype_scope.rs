@@ -0,0 +1,50 @@
+//! Parameter names hide typedef names from their declaration up to the end of
+//! the parameter list, or of the body of a function definition.
+
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    symbols::SymbolTable,
+    types::{BaseType, TypeKind},
+};
+
+fn parses(src: &str) -> bool {
+    let (tokens, _) = Lexer::new(src).lex();
+    Parser::new(&tokens).parse().is_ok()
+}
+
+#[test]
+fn outer_typedef_as_parameter_type() {
+    let src = "typedef int MyType; void f(MyType x);";
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+    let function = symbols
+        .symbols()
+        .iter()
+        .find(|symbol| symbol.name == "f")
+        .unwrap();
+    let params = &function.function.as_ref().unwrap().params;
+    let [param] = params.as_slice() else {
+        panic!("{params:?}");
+    };
+    assert_eq!(param.kind, TypeKind::Base(BaseType::Int));
+}
+
+#[test]
+fn parameter_hides_the_typedef_for_the_rest_of_the_list() {
+    assert!(!parses("typedef int T; void f(int T, T *p);"));
+    assert!(parses("typedef int T; void f(int T, int p[sizeof(T)]);"));
+}
+
+#[test]
+fn typedef_is_visible_again_after_the_list() {
+    assert!(parses("typedef int T; void f(int T); T y;"));
+    assert!(parses("typedef int T; void f(int (*g)(int T), T x);"));
+}
+
+#[test]
+fn parameter_hides_the_typedef_in_the_body() {
+    assert!(parses("typedef int T; int f(int T) { T * 2; return T; }"));
+    assert!(!parses("typedef int T; int f(int T) { T x; return x; }"));
+}
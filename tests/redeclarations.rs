@@ -0,0 +1,71 @@
+//! Redeclarations must agree on the type, with typedef names resolved in the
+//! scope of each declaration.
+
+mod common;
+
+use common::errors;
+use ecc::{
+    lexer::Lexer,
+    parser::Parser,
+    symbols::SymbolTable,
+    types::{BaseType, TypeKind},
+    validate::ValidationErrKind,
+};
+
+#[test]
+fn character_types_are_distinct() {
+    let src = "char a; signed char b; unsigned char c;";
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let table = SymbolTable::collect(&ast);
+    let bases: Vec<_> = table
+        .symbols()
+        .iter()
+        .map(|symbol| symbol.ty.clone().unwrap().kind)
+        .collect();
+    assert_eq!(
+        bases,
+        [
+            TypeKind::Base(BaseType::Char),
+            TypeKind::Base(BaseType::SignedChar),
+            TypeKind::Base(BaseType::UnsignedChar),
+        ]
+    );
+}
+
+#[test]
+fn character_types_conflict() {
+    for src in [
+        "char x; signed char x;",
+        "char x; unsigned char x;",
+        "signed char x; unsigned char x;",
+    ] {
+        assert_eq!(errors(src), [ValidationErrKind::ConflictingTypes], "{src}");
+    }
+    assert_eq!(errors("char x; char x;"), []);
+    assert_eq!(errors("signed char x; char signed x;"), []);
+}
+
+#[test]
+fn block_scope_typedef_does_not_leak() {
+    let src = "typedef int T; T x; void f(void) { typedef long T; } T x;";
+    assert_eq!(errors(src), []);
+    let src = "typedef int T; T x; void f(void) { typedef long T; } long x;";
+    assert_eq!(errors(src), [ValidationErrKind::ConflictingTypes]);
+}
+
+#[test]
+fn block_scope_typedef_is_used_inside_its_block() {
+    let src = "typedef int T; void f(void) { typedef long T; extern T y; extern long y; }";
+    assert_eq!(errors(src), []);
+    let src = "typedef int T; void f(void) { typedef long T; extern T y; extern int y; }";
+    assert_eq!(errors(src), [ValidationErrKind::ConflictingTypes]);
+}
+
+#[test]
+fn for_clause_declarations_are_scoped_to_the_loop() {
+    let src = "void f(void) { for (int i = 0; i < 3; i++) {} for (long i = 0; i < 3; i++) {} }";
+    assert_eq!(errors(src), []);
+    let src = "void f(void) { for (int i = 0; i < 3; i++) {} char *i; }";
+    assert_eq!(errors(src), []);
+}
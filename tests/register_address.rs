@@ -0,0 +1,61 @@
+use ecc::{
+    lexer::Lexer,
+    lint::{LintKind, LintOptions, lint},
+    parser::Parser,
+};
+
+fn lints(src: &str) -> Vec<LintKind<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let options = LintOptions {
+        register_address: true,
+        ..Default::default()
+    };
+    lint(&ast, options).into_iter().map(|l| l.kind).collect()
+}
+
+#[test]
+fn address_of_register_object_is_flagged() {
+    let r = || vec![LintKind::AddressOfRegister { name: "r" }];
+    assert_eq!(lints("void f(void) { register int r; int *p = &r; }"), r());
+    assert_eq!(
+        lints("void f(void) { register int r; int *p = &(r); }"),
+        r()
+    );
+    assert_eq!(
+        lints("void f(void) { register struct { int m; } r; int *p = &r.m; }"),
+        r()
+    );
+    assert_eq!(lints("void f(register int r) { int *p = &r; }"), r());
+}
+
+#[test]
+fn register_array_decay_is_flagged() {
+    let a = || vec![LintKind::RegisterArrayDecay { name: "a" }];
+    assert_eq!(
+        lints("void f(void) { register int a[4]; int x = a[1]; }"),
+        a()
+    );
+    assert_eq!(
+        lints("void f(void) { register int a[4]; int *p = a; }"),
+        a()
+    );
+    assert_eq!(
+        lints("void f(void) { register int a[4]; int (*p)[4] = &a; }"),
+        [LintKind::AddressOfRegister { name: "a" }]
+    );
+}
+
+#[test]
+fn other_uses_are_not_flagged() {
+    for src in [
+        "void f(void) { int r; int *p = &r; }",
+        "int r; void f(void) { &r; }",
+        "void f(void) { register int r; int x = r + 1; }",
+        "void f(void) { register int a[4]; unsigned long n = sizeof a + sizeof(a); }",
+        "void f(void) { register int a[4]; typeof(a) b; }",
+        "void f(void) { register int *p; int x = p[0]; }",
+    ] {
+        assert_eq!(lints(src), [], "{src}");
+    }
+}
@@ -0,0 +1,106 @@
+//! Checks that re-lexing after an edit gives the same tokens as lexing the edited
+//! source from scratch, for many pseudo-random edits to the corpus files.
+
+use std::{fs, path::Path};
+
+use ecc::{
+    lexer::{Edit, Lexer},
+    token::{Files, Token},
+};
+
+/// Text spliced into the corpus, chosen to open and close the constructs that
+/// span several tokens or lines.
+const REPLACEMENTS: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "x",
+    "1",
+    "+",
+    "=",
+    ";",
+    "\"",
+    "'",
+    "/*",
+    "*/",
+    "//",
+    "\\\n",
+    "0x1p",
+    "1.5e+",
+    "u8\"a\"",
+    "\n# 40 \"other.h\"\n",
+    "\n#line 7\n",
+];
+
+/// A xorshift generator, so that every run tries the same edits.
+struct Rng(u64);
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+    /// A char boundary of `src`, at most `len` bytes in.
+    fn boundary(&mut self, src: &str, len: usize) -> usize {
+        let mut i = self.below(len + 1);
+        while !src.is_char_boundary(i) {
+            i -= 1;
+        }
+        i
+    }
+}
+
+fn assert_same(relexed: (&[Token], &Files), lexed: (&[Token], &Files), context: &str) {
+    let ((relexed, relexed_files), (lexed, lexed_files)) = (relexed, lexed);
+    assert_eq!(relexed.len(), lexed.len(), "{context}");
+    for (r, l) in relexed.iter().zip(lexed) {
+        assert_eq!((r.kind, r.end), (l.kind, l.end), "{context}");
+        assert_eq!(
+            (r.at.line, r.at.column, r.at.offset),
+            (l.at.line, l.at.column, l.at.offset),
+            "{context}"
+        );
+        // The ids may differ, as the re-lexed files also hold the old names.
+        assert_eq!(
+            &relexed_files[r.at.file], &lexed_files[l.at.file],
+            "{context}"
+        );
+    }
+}
+
+#[test]
+fn relexing_matches_lexing_from_scratch() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&corpus)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "c"))
+        .collect();
+    paths.sort();
+
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for path in paths {
+        let mut src = fs::read_to_string(&path).unwrap();
+        for _ in 0..200 {
+            let start = rng.boundary(&src, src.len());
+            let end = start + rng.boundary(&src[start..], 16.min(src.len() - start));
+            let edit = Edit {
+                range: start..end,
+                replacement: REPLACEMENTS[rng.below(REPLACEMENTS.len())],
+            };
+            let new_src = [&src[..start], edit.replacement, &src[end..]].concat();
+
+            let (old_tokens, mut files) = Lexer::new(&src).lex();
+            let relexed = Lexer::new(&new_src).relex(&src, &old_tokens, &edit, &mut files);
+            let (lexed, lexed_files) = Lexer::new(&new_src).lex();
+            let context = format!("{}: {edit:?}", path.display());
+            assert_same((&relexed, &files), (&lexed, &lexed_files), &context);
+
+            src = new_src;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use ecc::{
+    lexer::Lexer,
+    lint::{LintKind, LintOptions, lint},
+    parser::Parser,
+};
+
+fn reserved(src: &str) -> Vec<&str> {
+    let (tokens, files) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let options = LintOptions {
+        reserved_identifiers: true,
+        ..Default::default()
+    };
+    lint(&ast, options)
+        .into_iter()
+        .filter(|l| !files.is_system_header(l.at.file))
+        .map(|l| match l.kind {
+            LintKind::ReservedIdentifier { name } => name,
+            kind => panic!("unexpected lint {kind:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn reserved_names_are_flagged() {
+    assert_eq!(reserved("int __foo;"), ["__foo"]);
+    assert_eq!(reserved("int _Bar;"), ["_Bar"]);
+    assert_eq!(reserved("int _bar; void f(void) { int _baz; }"), ["_bar"]);
+    assert_eq!(reserved("int printf;"), ["printf"]);
+}
+
+#[test]
+fn ordinary_names_are_not_flagged() {
+    assert!(reserved("int foo_;").is_empty());
+    assert_eq!(
+        reserved("int foo__bar; void f(int printf) { }"),
+        [] as [&str; 0]
+    );
+}
+
+#[test]
+fn system_header_declarations_can_be_skipped() {
+    let src = "# 1 \"main.c\"\n\
+        # 1 \"/usr/include/stdio.h\" 1 3 4\n\
+        int printf(const char *__format, ...);\n\
+        typedef int __gnuc_va_list;\n\
+        # 2 \"main.c\" 2\n\
+        int __mine;\n";
+    assert_eq!(reserved(src), ["__mine"]);
+}
@@ -0,0 +1,130 @@
+//! Lexes and parses every file in `tests/corpus`, prints its tokens back out,
+//! and checks that parsing the printed source yields the same AST.
+
+use std::{
+    fmt::Debug,
+    fs,
+    io::{ErrorKind, Write},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use ecc::{
+    ast::TranslationUnit,
+    lexer::Lexer,
+    parser::{Dialect, Parser, ParserOptions},
+    token::{Token, TokenKind},
+};
+
+#[test]
+fn corpus_round_trips() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&corpus)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "c"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no C files in {}", corpus.display());
+
+    let failures: Vec<_> = paths
+        .iter()
+        .filter_map(|path| {
+            let src = fs::read_to_string(path).unwrap();
+            let err = round_trip(&src).err()?;
+            Some(format!("{}: {err}", path.display()))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Runs `src` through every stage, naming the stage that failed.
+fn round_trip(src: &str) -> Result<(), String> {
+    let src = preprocess(src).map_err(|err| format!("preprocessing failed: {err}"))?;
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = parse(&tokens).map_err(|err| format!("parsing failed: {err}"))?;
+
+    let printed = print_tokens(&tokens);
+    let (reprinted_tokens, _) = Lexer::new(&printed).lex();
+    let kinds = tokens.iter().map(|t| t.kind);
+    if !kinds.eq(reprinted_tokens.iter().map(|t| t.kind)) {
+        return Err(format!("lexing the printed tokens differs:\n{printed}"));
+    }
+    let reparsed = parse(&reprinted_tokens)
+        .map_err(|err| format!("parsing the printed tokens failed: {err}\n{printed}"))?;
+
+    if without_positions(&ast) != without_positions(&reparsed) {
+        return Err(format!("the reparsed AST differs:\n{printed}"));
+    }
+    Ok(())
+}
+
+/// Runs the C preprocessor over `src`, or returns it unchanged if none is installed.
+fn preprocess(src: &str) -> Result<String, String> {
+    let child = Command::new("cpp")
+        .args(["-P", "-xc", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(src.to_string()),
+        Err(err) => return Err(err.to_string()),
+    };
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(src.as_bytes())
+        .map_err(|err| err.to_string())?;
+    let output = child.wait_with_output().map_err(|err| err.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
+
+fn parse<'a>(tokens: &[Token<'a>]) -> Result<TranslationUnit<'a>, String> {
+    let options = ParserOptions {
+        dialect: Dialect::Gnu,
+        ..Default::default()
+    };
+    let (ast, errors) = Parser::with_options(tokens, options).parse().into_parts();
+    let describe = |err: &ecc::parser::ParseErr| {
+        let at = err.at.at;
+        format!(
+            "{}:{}: {}, found `{}`",
+            at.line, at.column, err.expected, err.at.kind
+        )
+    };
+    match ast {
+        Ok(ast) if errors.is_empty() => Ok(ast),
+        _ => Err(errors.iter().map(describe).collect::<Vec<_>>().join("; ")),
+    }
+}
+
+/// Prints each token on its own line, so that no two tokens can merge.
+fn print_tokens(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Eof)
+        .map(|t| format!("{}\n", t.kind))
+        .collect()
+}
+
+/// Returns the debug representation of `value` with every position removed.
+fn without_positions(value: &impl Debug) -> String {
+    const START: &str = "At { file: ";
+    let debug = format!("{value:?}");
+    let mut out = String::with_capacity(debug.len());
+    let mut rest = debug.as_str();
+    while let Some(start) = rest.find(START) {
+        out.push_str(&rest[..start]);
+        let end = start + rest[start..].find('}').unwrap() + 1;
+        out.push_str("At");
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,70 @@
+//! `sizeof` takes either a parenthesized type name or a unary expression, which may
+//! itself start with a parenthesized type name when it is a compound literal.
+
+use ecc::{
+    ast::{Expression, ExpressionKind, SizeofKind},
+    lexer::Lexer,
+    parser::Parser,
+    token::TokenKind,
+};
+
+/// Describes the operand of the `sizeof` at the root of `expression`.
+fn operand(expression: &Expression) -> &'static str {
+    let ExpressionKind::Sizeof { kind, .. } = &expression.kind else {
+        return "not sizeof";
+    };
+    let SizeofKind::Expression(operand) = kind else {
+        return "type";
+    };
+    match &operand.kind {
+        ExpressionKind::Identifier(_) => "identifier",
+        ExpressionKind::Parenthesized { .. } => "parenthesized",
+        ExpressionKind::CompoundLiteral(_) => "compound literal",
+        _ => "other expression",
+    }
+}
+
+fn sizeof(src: &str) -> &'static str {
+    let (tokens, _) = Lexer::new(src).lex();
+    let mut parser = Parser::new(&tokens).with_predeclared_typedefs(&["T"]);
+    let expression = parser.parse_next_expression().unwrap();
+    assert_eq!(parser.remaining()[0].kind, TokenKind::Eof, "{src:?}");
+    operand(&expression)
+}
+
+#[test]
+fn operand_forms() {
+    assert_eq!(sizeof("sizeof x"), "identifier");
+    assert_eq!(sizeof("sizeof(x)"), "parenthesized");
+    assert_eq!(sizeof("sizeof(int)"), "type");
+    assert_eq!(sizeof("sizeof (int){0}"), "compound literal");
+}
+
+#[test]
+fn compound_literal_operands() {
+    for src in [
+        "sizeof (int){1, 2, 3}",
+        "sizeof (int[]){1, 2, 3}",
+        "sizeof (struct S){ .a = 1 }",
+        "sizeof (T){0}",
+    ] {
+        assert_eq!(sizeof(src), "compound literal", "{src}");
+    }
+}
+
+#[test]
+fn typedef_names_are_types() {
+    assert_eq!(sizeof("sizeof(T)"), "type");
+    assert_eq!(sizeof("sizeof(T *)"), "type");
+    assert_eq!(sizeof("sizeof(int[4])"), "type");
+}
+
+#[test]
+fn type_form_ends_at_the_parenthesis() {
+    let (tokens, _) = Lexer::new("sizeof(int) + 1").lex();
+    let expression = Parser::new(&tokens).parse_next_expression().unwrap();
+    let ExpressionKind::Binary { left, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(operand(left), "type");
+}
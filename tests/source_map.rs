@@ -0,0 +1,68 @@
+use ecc::{
+    lexer::{Lexer, LexerOptions, SourceMap},
+    token::{At, Files, TokenKind},
+};
+
+const SRC: &str = "int a;\n  long b;\n# 10 \"other.h\"\nchar c;\n\tshort d;\n";
+
+fn map(files: &mut Files) -> SourceMap<'static> {
+    let options = LexerOptions {
+        tab_width: 4,
+        ..Default::default()
+    };
+    SourceMap::new(SRC, options, files)
+}
+
+/// Returns the file name, line and column of `offset`.
+fn position(offset: usize) -> (String, u32, u32) {
+    let mut files = Files::new();
+    let map = map(&mut files);
+    let at = map.offset_to_at(offset);
+    assert_eq!(at.offset, offset);
+    (files[at.file].to_string(), at.line, at.column)
+}
+
+#[test]
+fn offsets_at_line_starts() {
+    let main = position(0).0;
+    assert_eq!(position(0), (main.clone(), 1, 1));
+    assert_eq!(position(SRC.find("  long").unwrap()), (main, 2, 1));
+    assert_eq!(
+        position(SRC.find("char").unwrap()),
+        ("other.h".to_string(), 10, 1)
+    );
+}
+
+#[test]
+fn offsets_mid_line() {
+    let main = position(0).0;
+    assert_eq!(position(SRC.find('a').unwrap()), (main.clone(), 1, 5));
+    assert_eq!(position(SRC.find("long").unwrap()), (main.clone(), 2, 3));
+    assert_eq!(position(SRC.find('b').unwrap()), (main, 2, 8));
+}
+
+#[test]
+fn offsets_after_a_marker() {
+    let other = || "other.h".to_string();
+    assert_eq!(position(SRC.find("c;").unwrap() + 1), (other(), 10, 7));
+    // A tab advances to the next stop, four columns on.
+    assert_eq!(position(SRC.find("short").unwrap()), (other(), 11, 5));
+    assert_eq!(position(SRC.find('d').unwrap()), (other(), 11, 11));
+}
+
+#[test]
+fn offsets_match_the_lexer() {
+    let mut files = Files::new();
+    let map = map(&mut files);
+    let options = LexerOptions {
+        tab_width: 4,
+        ..Default::default()
+    };
+    let (tokens, lexed_files) = Lexer::with_options(SRC, options).lex();
+    // The final `Eof` is placed in a file of its own.
+    for token in tokens.iter().filter(|t| t.kind != TokenKind::Eof) {
+        let at: At = map.offset_to_at(token.at.offset);
+        assert_eq!((at.line, at.column), (token.at.line, token.at.column));
+        assert_eq!(&files[at.file], &lexed_files[token.at.file]);
+    }
+}
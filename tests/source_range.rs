@@ -0,0 +1,75 @@
+use ecc::{
+    ast::{ExpressionKind, TranslationUnit},
+    lexer::Lexer,
+    parser::Parser,
+    token::Token,
+    visit::{Node, Visitor, source_range, walk_translation_unit},
+};
+
+/// Collects the source text of every node `select` picks out.
+struct Ranges<'t, 'a> {
+    src: &'a str,
+    tokens: &'t [Token<'a>],
+    select: fn(Node) -> bool,
+    texts: Vec<&'a str>,
+}
+impl<'a> Visitor<'a> for Ranges<'_, 'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if (self.select)(node) {
+            let (start, end) = source_range(node, self.tokens);
+            self.texts.push(&self.src[start.offset..end.offset]);
+        }
+    }
+}
+
+fn texts(src: &str, select: fn(Node) -> bool) -> Vec<&str> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast: TranslationUnit = Parser::new(&tokens).parse().unwrap();
+    let mut ranges = Ranges {
+        src,
+        tokens: &tokens,
+        select,
+        texts: Vec::new(),
+    };
+    walk_translation_unit(&mut ranges, &ast);
+    ranges.texts
+}
+
+#[test]
+fn if_statement_covers_its_else_branch() {
+    let src =
+        "void f(int x) {\n    if (x) {\n        g();\n    } else\n        h(x) /* done */ ;\n}\n";
+    let ifs = texts(src, |node| matches!(node, Node::SelectionStatement(_)));
+    assert_eq!(
+        ifs,
+        ["if (x) {\n        g();\n    } else\n        h(x) /* done */ ;"]
+    );
+}
+
+#[test]
+fn nested_else_if_ends_with_the_innermost_branch() {
+    let src = "void f(int x) { if (x) g(); else if (x > 1) h(); else x = s->t; }";
+    let ifs = texts(src, |node| matches!(node, Node::SelectionStatement(_)));
+    assert_eq!(
+        ifs,
+        [
+            "if (x) g(); else if (x > 1) h(); else x = s->t;",
+            "if (x > 1) h(); else x = s->t;",
+        ]
+    );
+}
+
+#[test]
+fn names_without_positions_are_included() {
+    let src = "struct [[gnu::packed]] S; int x = a.b, y = { .c = 1 };";
+    let names = texts(src, |node| {
+        matches!(
+            node,
+            Node::StructOrUnionSpecifier(_) | Node::Attribute(_) | Node::Designator(_)
+        ) || matches!(node, Node::Expression(e) if matches!(e.kind, ExpressionKind::Member { .. }))
+    });
+    assert_eq!(
+        names,
+        ["struct [[gnu::packed]] S", "gnu::packed", "a.b", ".c"]
+    );
+}
@@ -0,0 +1,89 @@
+//! Type specifiers and qualifiers may come in any order, and name the same type
+//! whichever order they are written in.
+
+use ecc::{
+    ast::TypeName,
+    lexer::Lexer,
+    parser::Parser,
+    symbols::SymbolTable,
+    types::{BaseType, QualifiedType, Qualifiers, canonicalize},
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+const PERMUTATIONS: &[&str] = &[
+    "const unsigned int",
+    "const int unsigned",
+    "unsigned const int",
+    "unsigned int const",
+    "int const unsigned",
+    "int unsigned const",
+    "const unsigned",
+    "unsigned const",
+    "const const unsigned int",
+];
+
+fn const_unsigned_int() -> QualifiedType<'static> {
+    QualifiedType {
+        base: BaseType::UnsignedInt,
+        qualifiers: Qualifiers {
+            is_const: true,
+            ..Default::default()
+        },
+    }
+}
+
+/// Canonicalizes every type name in the source.
+struct TypeNames<'a>(Vec<QualifiedType<'a>>);
+impl<'a> Visitor<'a> for TypeNames<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::TypeName(TypeName {
+            specifier_qualifiers,
+            ..
+        }) = node
+        {
+            self.0
+                .push(canonicalize(specifier_qualifiers.iter()).unwrap());
+        }
+    }
+}
+
+#[test]
+fn type_names_in_any_order_are_the_same() {
+    for specifiers in PERMUTATIONS {
+        let src = format!("int x = sizeof({specifiers});");
+        let (tokens, _) = Lexer::new(&src).lex();
+        let ast = Parser::new(&tokens).parse().unwrap();
+        let mut names = TypeNames(Vec::new());
+        walk_translation_unit(&mut names, &ast);
+        assert_eq!(names.0, [const_unsigned_int()], "{specifiers}");
+    }
+}
+
+#[test]
+fn declarations_in_any_order_are_the_same() {
+    for specifiers in PERMUTATIONS {
+        let src = format!("{specifiers} x = 1;");
+        let (tokens, _) = Lexer::new(&src).lex();
+        let ast = Parser::new(&tokens).parse().unwrap();
+        let table = SymbolTable::collect(&ast);
+        let ty = table.symbols()[0].ty.clone().unwrap();
+        assert_eq!(ty, const_unsigned_int().into(), "{specifiers}");
+    }
+}
+
+#[test]
+fn long_is_folded_in_any_order() {
+    for specifiers in [
+        "unsigned long const",
+        "const unsigned long",
+        "long const unsigned int",
+    ] {
+        let src = format!("int x = sizeof({specifiers});");
+        let (tokens, _) = Lexer::new(&src).lex();
+        let ast = Parser::new(&tokens).parse().unwrap();
+        let mut names = TypeNames(Vec::new());
+        walk_translation_unit(&mut names, &ast);
+        assert_eq!(names.0[0].base, BaseType::UnsignedLong, "{specifiers}");
+        assert!(names.0[0].qualifiers.is_const, "{specifiers}");
+    }
+}
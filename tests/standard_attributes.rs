@@ -0,0 +1,119 @@
+//! Standard attributes accept only the argument clauses the standard gives them.
+
+mod common;
+
+use common::errors;
+use ecc::{
+    ast::{BalancedTokenKind, BalancedTokenSequence},
+    lexer::Lexer,
+    parser::Parser,
+    token::TokenKind,
+    validate::ValidationErrKind,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the name and argument tokens of every attribute.
+struct Attributes<'a>(Vec<(&'a str, Option<Vec<TokenKind<'a>>>)>);
+impl<'a> Visitor<'a> for Attributes<'a> {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Attribute(attribute) = node {
+            let arguments = attribute
+                .argument_clause
+                .as_ref()
+                .map(|clause| kinds(clause.tokens.as_ref()));
+            self.0.push((attribute.token.token, arguments));
+        }
+    }
+}
+
+fn kinds<'a>(tokens: Option<&BalancedTokenSequence<'a>>) -> Vec<TokenKind<'a>> {
+    let tokens = tokens.iter().flat_map(|tokens| tokens.iter());
+    tokens
+        .filter_map(|token| match token.kind {
+            BalancedTokenKind::Token(kind) => Some(kind),
+            _ => None,
+        })
+        .collect()
+}
+
+fn attributes(src: &str) -> Vec<(&str, Option<Vec<TokenKind<'_>>>)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut attributes = Attributes(Vec::new());
+    walk_translation_unit(&mut attributes, &ast);
+    attributes.0
+}
+
+#[test]
+fn deprecated_with_and_without_a_message() {
+    assert_eq!(
+        attributes("[[deprecated]] int f(void);"),
+        [("deprecated", None)]
+    );
+    let with_message = attributes("[[deprecated(\"use g\")]] int f(void);");
+    assert_eq!(with_message.len(), 1);
+    assert_eq!(with_message[0].0, "deprecated");
+    assert!(matches!(
+        with_message[0].1.as_deref(),
+        Some([TokenKind::String(..)])
+    ));
+}
+
+#[test]
+fn deprecated_takes_an_optional_message() {
+    for src in [
+        "[[deprecated]] int f(void);",
+        "[[deprecated(\"use g\")]] int f(void);",
+        "[[deprecated(\"use \" \"g\")]] int f(void);",
+        "[[__deprecated__(\"use g\")]] int f(void);",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn nodiscard_takes_an_optional_message() {
+    for src in [
+        "[[nodiscard]] int f(void);",
+        "[[nodiscard(\"check it\")]] int f(void);",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn maybe_unused_takes_no_arguments() {
+    assert_eq!(errors("[[maybe_unused]] int x;"), []);
+    assert_eq!(errors("int f([[maybe_unused]] int x);"), []);
+}
+
+#[test]
+fn wrong_argument_counts() {
+    for src in [
+        "[[deprecated()]] int f(void);",
+        "[[deprecated(\"a\", \"b\")]] int f(void);",
+        "[[deprecated(1)]] int f(void);",
+        "[[nodiscard()]] int f(void);",
+        "[[nodiscard(\"a\", \"b\")]] int f(void);",
+        "[[nodiscard(reason)]] int f(void);",
+        "[[maybe_unused()]] int x;",
+        "[[maybe_unused(\"a\")]] int x;",
+        "[[__maybe_unused__(x)]] int x;",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::InvalidAttributeArguments],
+            "{src}"
+        );
+    }
+}
+
+#[test]
+fn other_attributes_take_any_arguments() {
+    for src in [
+        "[[gnu::aligned(8, 16)]] int x;",
+        "[[vendor_thing(a, \"b\", 3)]] int x;",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
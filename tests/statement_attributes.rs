@@ -0,0 +1,143 @@
+use ecc::{
+    ast::{AttributeSpecifierSequence, LabelKind, PrimaryBlockKind, UnlabeledStatementKind},
+    lexer::Lexer,
+    parser::Parser,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Records the kind of every attributed primary block with its attribute names.
+struct Attributed(Vec<(&'static str, Vec<String>)>);
+impl<'a> Visitor<'a> for Attributed {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::UnlabeledStatement(statement) = node
+            && let UnlabeledStatementKind::Primary(Some(attributes), block) = &statement.kind
+        {
+            let kind = match block.kind {
+                PrimaryBlockKind::Compound(_) => "compound",
+                PrimaryBlockKind::Selection(_) => "selection",
+                PrimaryBlockKind::Iteration(_) => "iteration",
+            };
+            self.0.push((kind, names(attributes)));
+        }
+    }
+}
+
+/// Records every attributed label, expression statement and attribute declaration
+/// with its attribute names.
+struct Placed(Vec<(String, Vec<String>)>);
+impl<'a> Visitor<'a> for Placed {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        match node {
+            Node::Label(label) if label.attributes.is_some() => {
+                let kind = match label.kind {
+                    LabelKind::Name(name) => format!("label {name}"),
+                    _ => "case".to_string(),
+                };
+                self.0
+                    .push((kind, names(label.attributes.as_ref().unwrap())));
+            }
+            Node::ExpressionStatement(statement) if statement.attributes.is_some() => {
+                let kind = match statement.expression {
+                    Some(_) => "expression",
+                    None => "null statement",
+                };
+                let attributes = names(statement.attributes.as_ref().unwrap());
+                self.0.push((kind.to_string(), attributes));
+            }
+            Node::AttributeDeclaration(declaration) => {
+                let attributes = names(&declaration.attributes);
+                self.0
+                    .push(("attribute declaration".to_string(), attributes));
+            }
+            _ => (),
+        }
+    }
+}
+
+fn names(attributes: &AttributeSpecifierSequence) -> Vec<String> {
+    attributes
+        .iter()
+        .flat_map(|specifier| specifier.attributes.iter().flatten())
+        .map(|attribute| match attribute.token.prefix {
+            Some((prefix, _)) => format!("{prefix}::{}", attribute.token.token),
+            None => attribute.token.token.to_string(),
+        })
+        .collect()
+}
+
+fn attributed(src: &str) -> Vec<(&'static str, Vec<String>)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut attributed = Attributed(Vec::new());
+    walk_translation_unit(&mut attributed, &ast);
+    attributed.0
+}
+
+#[test]
+fn attributes_apply_to_the_whole_block() {
+    let src = "void f(int x) {
+        [[maybe_unused]] { int y = x; }
+        [[vendor::hint]] [[maybe_unused]] if (x) x--; else x++;
+        [[vendor::unroll(4)]] while (x) x--;
+    }";
+    assert_eq!(
+        attributed(src),
+        [
+            ("compound", vec!["maybe_unused".to_string()]),
+            (
+                "selection",
+                vec!["vendor::hint".to_string(), "maybe_unused".to_string()]
+            ),
+            ("iteration", vec!["vendor::unroll".to_string()]),
+        ]
+    );
+}
+
+fn placed(src: &str) -> Vec<(String, Vec<String>)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let mut placed = Placed(Vec::new());
+    walk_translation_unit(&mut placed, &ast);
+    placed.0
+}
+
+#[test]
+fn fallthrough_is_an_attribute_declaration_among_block_items() {
+    let src = "void f(int x) { switch (x) { case 1: foo(); [[fallthrough]]; case 2: break; } }";
+    assert_eq!(
+        placed(src),
+        [(
+            "attribute declaration".to_string(),
+            vec!["fallthrough".to_string()]
+        )]
+    );
+}
+
+#[test]
+fn fallthrough_is_an_attributed_null_statement_as_a_secondary_block() {
+    let src =
+        "void f(int x) { switch (x) { case 1: if (x) [[fallthrough]]; else break; case 2: ; } }";
+    assert_eq!(
+        placed(src),
+        [(
+            "null statement".to_string(),
+            vec!["fallthrough".to_string()]
+        )]
+    );
+}
+
+#[test]
+fn label_attributes_precede_the_label() {
+    let src = "void f(int x) {
+        [[maybe_unused]] label: ;
+        switch (x) { [[likely]] case 1: [[unlikely]] x++; }
+    }";
+    assert_eq!(
+        placed(src),
+        [
+            ("label label".to_string(), vec!["maybe_unused".to_string()]),
+            ("case".to_string(), vec!["likely".to_string()]),
+            ("expression".to_string(), vec!["unlikely".to_string()]),
+        ]
+    );
+}
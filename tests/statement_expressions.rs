@@ -0,0 +1,66 @@
+//! GNU statement expressions `({ ... })`, whose value is that of the last
+//! expression statement in their body.
+
+use ecc::{
+    ast::{BinaryOperator, BlockItemKind, ExpressionKind, UnlabeledStatementKind},
+    lexer::Lexer,
+    parse_expr,
+    parser::{Dialect, Parser, ParserOptions},
+};
+
+fn parses(src: &str, dialect: Dialect) -> bool {
+    let (tokens, _) = Lexer::new(src).lex();
+    let options = ParserOptions {
+        dialect,
+        ..Default::default()
+    };
+    Parser::with_options(&tokens, options).parse().is_ok()
+}
+
+#[test]
+fn last_expression_statement_is_the_value() {
+    let expression = parse_expr!("({ int y = 1; y += 2; y * 3; })");
+    let ExpressionKind::StatementExpression { body, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    let items: Vec<_> = body.items.iter().flat_map(|items| items.iter()).collect();
+    assert_eq!(items.len(), 3);
+    assert!(matches!(items[0].kind, BlockItemKind::Declaration(_)));
+    let BlockItemKind::Unlabeled(statement) = &items[2].kind else {
+        panic!("{:?}", items[2]);
+    };
+    let UnlabeledStatementKind::Expression(statement) = &statement.kind else {
+        panic!("{statement:?}");
+    };
+    let value = statement.expression.as_ref().unwrap();
+    let ExpressionKind::Binary {
+        left,
+        operator,
+        right,
+    } = &value.kind
+    else {
+        panic!("{value:?}");
+    };
+    assert_eq!(left.kind, ExpressionKind::Identifier("y"));
+    assert_eq!(operator.1, BinaryOperator::Multiply);
+    assert!(matches!(right.kind, ExpressionKind::Integer(_)));
+}
+
+#[test]
+fn statement_expressions_are_operands() {
+    let expression = parse_expr!("1 + ({ 2; })");
+    let ExpressionKind::Binary { right, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert!(matches!(
+        right.kind,
+        ExpressionKind::StatementExpression { .. }
+    ));
+}
+
+#[test]
+fn rejected_outside_the_gnu_dialect() {
+    let src = "int f(void) { int x = ({ int y = 1; y; }); return x; }";
+    assert!(parses(src, Dialect::Gnu));
+    assert!(!parses(src, Dialect::Standard));
+}
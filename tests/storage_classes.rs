@@ -0,0 +1,55 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn thread_local_combines_with_static_and_extern() {
+    for src in [
+        "static thread_local int x;",
+        "thread_local static int x;",
+        "extern thread_local int x;",
+        "thread_local int y;",
+        "void f(void) { static thread_local int x; extern thread_local int y; }",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn thread_local_conflicts_with_other_storage_classes() {
+    for src in [
+        "void f(void) { auto thread_local int z; }",
+        "void f(void) { register thread_local int z; }",
+        "typedef thread_local int T;",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::ConflictingStorageClasses],
+            "{src}"
+        );
+    }
+}
+
+#[test]
+fn thread_local_needs_static_or_extern_at_block_scope() {
+    assert_eq!(
+        errors("void f(void) { thread_local int z; }"),
+        [ValidationErrKind::ThreadLocalAtBlockScope]
+    );
+}
+
+#[test]
+fn at_most_one_other_storage_class() {
+    for src in [
+        "static extern int x;",
+        "void f(void) { auto register int x; }",
+        "typedef static int T;",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::ConflictingStorageClasses],
+            "{src}"
+        );
+    }
+}
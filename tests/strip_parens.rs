@@ -0,0 +1,59 @@
+//! Redundant parentheses are kept as `Parenthesized` nodes by the parser, and
+//! removed by `Expression::strip_parens` where precedence does not need them.
+
+use ecc::{
+    ast::{Expression, ExpressionKind},
+    parse_expr,
+};
+
+/// Prints the expression back out, with the parentheses the AST holds.
+fn unparse(expression: &Expression) -> String {
+    match &expression.kind {
+        ExpressionKind::Identifier(name) => name.to_string(),
+        ExpressionKind::Integer(int) => int.source.to_string(),
+        ExpressionKind::Binary {
+            left,
+            operator,
+            right,
+        } => format!("{} {} {}", unparse(left), operator.1, unparse(right)),
+        ExpressionKind::Parenthesized { inner, .. } => format!("({})", unparse(inner)),
+        kind => panic!("unexpected expression {kind:?}"),
+    }
+}
+
+/// Returns `src` with its redundant parentheses removed.
+fn stripped(src: &str) -> String {
+    let mut expression = parse_expr!(src);
+    expression.strip_parens();
+    unparse(&expression)
+}
+
+#[test]
+fn nested_parentheses_are_preserved() {
+    let expression = parse_expr!("((a + b))");
+    let ExpressionKind::Parenthesized { inner, .. } = &expression.kind else {
+        panic!("{expression:?}");
+    };
+    let ExpressionKind::Parenthesized { inner, .. } = &inner.kind else {
+        panic!("{inner:?}");
+    };
+    assert!(matches!(inner.kind, ExpressionKind::Binary { .. }));
+    assert_eq!(unparse(&expression), "((a + b))");
+    assert_eq!(expression.without_parens(), &**inner);
+}
+
+#[test]
+fn redundant_parentheses_are_stripped() {
+    assert_eq!(stripped("(a) + (b * c)"), "a + b * c");
+    assert_eq!(stripped("((a + b))"), "a + b");
+    assert_eq!(stripped("a - (b + c)"), "a - (b + c)");
+    assert_eq!(stripped("(a - b) + c"), "a - b + c");
+    assert_eq!(stripped("((1))"), "1");
+}
+
+#[test]
+fn needed_parentheses_are_kept() {
+    assert_eq!(stripped("(a + b) * c"), "(a + b) * c");
+    assert_eq!(stripped("((a + b)) * c"), "(a + b) * c");
+    assert_eq!(stripped("a * (b / c)"), "a * (b / c)");
+}
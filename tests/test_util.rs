@@ -0,0 +1,63 @@
+//! The snippet helpers return the single node they parse, and panic with the
+//! parse errors or the leftover tokens otherwise.
+
+use ecc::{
+    ast::{BinaryOperator, ExpressionKind, ExternalDeclarationKind},
+    parse_decl, parse_expr,
+};
+
+#[test]
+fn parse_decl_returns_the_declaration() {
+    let declaration = parse_decl!("int x = 1;");
+    assert!(matches!(
+        declaration.kind,
+        ExternalDeclarationKind::Declaration(_)
+    ));
+    let definition = parse_decl!("int f(void) { return 0; }");
+    assert!(matches!(
+        definition.kind,
+        ExternalDeclarationKind::Function(_)
+    ));
+}
+
+#[test]
+fn parse_expr_returns_the_expression() {
+    let expression = parse_expr!("a + b");
+    let ExpressionKind::Binary { operator, .. } = expression.kind else {
+        panic!("{expression:?}");
+    };
+    assert_eq!(operator.1, BinaryOperator::Add);
+}
+
+#[test]
+fn snippets_are_parsed_as_gnu_c() {
+    let expression = parse_expr!("({ int y = 1; y; })");
+    assert!(matches!(
+        expression.kind,
+        ExpressionKind::StatementExpression { .. }
+    ));
+}
+
+#[test]
+#[should_panic(expected = "failed to parse expression `a +`:\n    1:4: ")]
+fn parse_errors_are_reported_with_their_positions() {
+    parse_expr!("a +");
+}
+
+#[test]
+#[should_panic(expected = "failed to parse declaration `int x = ;`:\n    1:")]
+fn declaration_errors_are_reported() {
+    parse_decl!("int x = ;");
+}
+
+#[test]
+#[should_panic(expected = "expression `a b` is followed by `b` at 1:3")]
+fn trailing_tokens_are_reported() {
+    parse_expr!("a b");
+}
+
+#[test]
+#[should_panic(expected = "declaration `int x; int y;` is followed by `int` at 1:8")]
+fn only_one_declaration_is_parsed() {
+    parse_decl!("int x; int y;");
+}
@@ -0,0 +1,55 @@
+use ecc::{
+    lexer::{Lexer, LexerOptions},
+    parser::Dialect,
+    token::{TokenCategory, TokenKind},
+};
+
+fn categories(src: &str) -> Vec<TokenCategory> {
+    let options = LexerOptions {
+        keep_comments: true,
+        ..Default::default()
+    };
+    let (tokens, _) = Lexer::with_options(src, options).lex();
+    tokens.iter().map(|t| t.kind.category()).collect()
+}
+
+#[test]
+fn one_token_of_each_category() {
+    let table = [
+        ("while", TokenCategory::Keyword),
+        ("_Bool", TokenCategory::Keyword),
+        ("{", TokenCategory::Punctuator),
+        ("<<=", TokenCategory::Punctuator),
+        ("...", TokenCategory::Punctuator),
+        ("x", TokenCategory::Identifier),
+        ("asm", TokenCategory::Identifier),
+        ("42u", TokenCategory::Literal),
+        ("1.5f", TokenCategory::Literal),
+        ("u8\"s\"", TokenCategory::Literal),
+        ("'c'", TokenCategory::Literal),
+        ("// line", TokenCategory::Comment),
+        ("/* block */", TokenCategory::Comment),
+        ("0x1p", TokenCategory::Error),
+    ];
+    for (src, category) in table {
+        assert_eq!(categories(src), [category, TokenCategory::Eof], "{src}");
+    }
+}
+
+#[test]
+fn int128_follows_the_dialect() {
+    assert_eq!(TokenKind::Int128.category(), TokenCategory::Keyword);
+    assert_eq!(
+        TokenKind::Int128.in_dialect(Dialect::Gnu).category(),
+        TokenCategory::Keyword
+    );
+    assert_eq!(
+        TokenKind::Int128.in_dialect(Dialect::Standard),
+        TokenKind::Identifier("__int128")
+    );
+    assert_eq!(
+        TokenKind::Int128.in_dialect(Dialect::Standard).category(),
+        TokenCategory::Identifier
+    );
+    assert_eq!(TokenKind::Int.in_dialect(Dialect::Standard), TokenKind::Int);
+}
@@ -0,0 +1,49 @@
+//! Parsing token slices supplied by the caller, which may lack the final `Eof`.
+
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, ParseErr, Parser},
+    token::TokenKind,
+};
+
+#[test]
+fn missing_eof_parses_like_the_lexer_output() {
+    let src = "int x; void f(void) { x++; }";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+
+    let with_eof = Parser::new(&tokens).parse();
+    let without_eof = Parser::new(&tokens[..tokens.len() - 1]).parse();
+    assert!(with_eof.is_ok());
+    assert_eq!(without_eof.ast, with_eof.ast);
+    assert_eq!(without_eof.errors, []);
+}
+
+#[test]
+fn truncated_slice_reports_the_same_errors() {
+    for src in ["int x = 1", "void f(void) { g(", "struct S {"] {
+        let (tokens, _) = Lexer::new(src).lex();
+        let with_eof = Parser::new(&tokens).parse();
+        let without_eof = Parser::new(&tokens[..tokens.len() - 1]).parse();
+        assert!(!without_eof.errors.is_empty(), "{src}");
+        assert_eq!(
+            expected(&without_eof.errors),
+            expected(&with_eof.errors),
+            "{src}"
+        );
+    }
+}
+
+fn expected<'a>(errors: &[ParseErr<'a>]) -> Vec<(Expected<'a>, TokenKind<'a>)> {
+    errors
+        .iter()
+        .map(|err| (err.expected, err.at.kind))
+        .collect()
+}
+
+#[test]
+fn empty_slice_is_an_empty_translation_unit() {
+    let result = Parser::new(&[]).parse();
+    let expected: Vec<_> = result.errors.iter().map(|err| err.expected).collect();
+    assert_eq!(expected, [Expected::NonEmptyTranslationUnit]);
+}
@@ -0,0 +1,33 @@
+use ecc::{lexer::Lexer, token::TokenKind};
+
+fn texts(src: &str) -> Vec<&str> {
+    let (tokens, _) = Lexer::new(src).lex();
+    tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Eof)
+        .map(|t| t.text(src))
+        .collect()
+}
+
+#[test]
+fn digit_separators_round_trip() {
+    let src = "x = 1'000'000ull;";
+    let (tokens, _) = Lexer::new(src).lex();
+    assert!(matches!(tokens[2].kind, TokenKind::Integer(_)));
+    assert_eq!(tokens[2].text(src), "1'000'000ull");
+    assert_eq!(texts(src), ["x", "=", "1'000'000ull", ";"]);
+
+    for literal in ["0x1'F'FFu", "0b1010'0101", "01'7", "1'2.3'4e1'0f"] {
+        assert_eq!(texts(literal), [literal]);
+    }
+}
+
+#[test]
+fn texts_rebuild_the_source_between_whitespace() {
+    let src = "unsigned long masks[] = { 0xFFu, 1'000'000ull, u8\"a\\n\", L'b' };";
+    let rebuilt = texts(src).join(" ");
+    assert_eq!(
+        rebuilt,
+        "unsigned long masks [ ] = { 0xFFu , 1'000'000ull , u8\"a\\n\" , L'b' } ;"
+    );
+}
@@ -0,0 +1,197 @@
+//! Checks that every punctuator and keyword spelling lexes to the right token,
+//! whichever other spellings share its first character.
+
+use std::{fs, path::Path};
+
+use ecc::{lexer::Lexer, token::TokenKind};
+
+/// Lexes `src` and returns the kinds of its tokens, without the final `Eof`.
+fn kinds(src: &str) -> Vec<TokenKind<'_>> {
+    let (tokens, _) = Lexer::new(src).lex();
+    tokens
+        .iter()
+        .map(|t| t.kind)
+        .filter(|&kind| kind != TokenKind::Eof)
+        .collect()
+}
+
+const SPELLINGS: &[(&str, TokenKind)] = &[
+    ("...", TokenKind::Ellipses),
+    ("<<=", TokenKind::DoubleLessEqual),
+    (">>=", TokenKind::DoubleGreaterEqual),
+    ("->", TokenKind::ArrowLeft),
+    ("++", TokenKind::DoublePlus),
+    ("--", TokenKind::DoubleMinus),
+    ("<<", TokenKind::DoubleLess),
+    (">>", TokenKind::DoubleGreater),
+    ("<=", TokenKind::LessEqual),
+    (">=", TokenKind::GreaterEqual),
+    ("==", TokenKind::DoubleEqual),
+    ("!=", TokenKind::NotEqual),
+    ("&&", TokenKind::DoubleAmpersand),
+    ("||", TokenKind::DoubleBar),
+    ("::", TokenKind::DoubleColon),
+    ("*=", TokenKind::AsteriskEqual),
+    ("/=", TokenKind::SlashEqual),
+    ("%=", TokenKind::PercentEqual),
+    ("+=", TokenKind::PlusEqual),
+    ("-=", TokenKind::MinusEqual),
+    ("&=", TokenKind::AmpersandEqual),
+    ("^=", TokenKind::CaretEqual),
+    ("|=", TokenKind::BarEqual),
+    ("[", TokenKind::OpenBracket),
+    ("]", TokenKind::CloseBracket),
+    ("(", TokenKind::OpenParenthesis),
+    (")", TokenKind::CloseParenthesis),
+    ("{", TokenKind::OpenBrace),
+    ("}", TokenKind::CloseBrace),
+    (".", TokenKind::Period),
+    ("&", TokenKind::Ampersand),
+    ("*", TokenKind::Asterisk),
+    ("+", TokenKind::Plus),
+    ("-", TokenKind::Minus),
+    ("~", TokenKind::Tilde),
+    ("!", TokenKind::Exclamation),
+    ("/", TokenKind::Slash),
+    ("%", TokenKind::Percent),
+    ("<", TokenKind::Less),
+    (">", TokenKind::Greater),
+    ("^", TokenKind::Caret),
+    ("|", TokenKind::Bar),
+    ("?", TokenKind::Question),
+    (":", TokenKind::Colon),
+    (";", TokenKind::Semicolon),
+    ("=", TokenKind::Equal),
+    (",", TokenKind::Comma),
+    ("alignas", TokenKind::Alignas),
+    ("alignof", TokenKind::Alignof),
+    ("auto", TokenKind::Auto),
+    ("bool", TokenKind::Bool),
+    ("break", TokenKind::Break),
+    ("case", TokenKind::Case),
+    ("char", TokenKind::Char),
+    ("const", TokenKind::Const),
+    ("constexpr", TokenKind::Constexpr),
+    ("continue", TokenKind::Continue),
+    ("default", TokenKind::Default),
+    ("do", TokenKind::Do),
+    ("double", TokenKind::Double),
+    ("else", TokenKind::Else),
+    ("enum", TokenKind::Enum),
+    ("extern", TokenKind::Extern),
+    ("false", TokenKind::False),
+    ("float", TokenKind::Float),
+    ("for", TokenKind::For),
+    ("goto", TokenKind::Goto),
+    ("if", TokenKind::If),
+    ("inline", TokenKind::Inline),
+    ("int", TokenKind::Int),
+    ("long", TokenKind::Long),
+    ("nullptr", TokenKind::Nullptr),
+    ("register", TokenKind::Register),
+    ("restrict", TokenKind::Restrict),
+    ("return", TokenKind::Return),
+    ("short", TokenKind::Short),
+    ("signed", TokenKind::Signed),
+    ("sizeof", TokenKind::Sizeof),
+    ("static", TokenKind::Static),
+    ("static_assert", TokenKind::StaticAssert),
+    ("struct", TokenKind::Struct),
+    ("switch", TokenKind::Switch),
+    ("thread_local", TokenKind::ThreadLocal),
+    ("true", TokenKind::True),
+    ("typedef", TokenKind::Typedef),
+    ("typeof", TokenKind::Typeof),
+    ("typeof_unqual", TokenKind::TypeofUnqual),
+    ("union", TokenKind::Union),
+    ("unsigned", TokenKind::Unsigned),
+    ("void", TokenKind::Void),
+    ("volatile", TokenKind::Volatile),
+    ("while", TokenKind::While),
+    ("_Alignof", TokenKind::Alignof),
+    ("_Atomic", TokenKind::Atomic),
+    ("_Bool", TokenKind::Bool),
+    ("_BitInt", TokenKind::BitInt),
+    ("_Complex", TokenKind::Complex),
+    ("_Decimal128", TokenKind::Decimal128),
+    ("_Decimal32", TokenKind::Decimal32),
+    ("_Decimal64", TokenKind::Decimal64),
+    ("_Float16", TokenKind::Float16),
+    ("_Float32", TokenKind::Float32),
+    ("_Float64", TokenKind::Float64),
+    ("_Float128", TokenKind::Float128),
+    ("_Float32x", TokenKind::Float32x),
+    ("_Float64x", TokenKind::Float64x),
+    ("_Float128x", TokenKind::Float128x),
+    ("__int128", TokenKind::Int128),
+    ("_Generic", TokenKind::Generic),
+    ("_Imaginary", TokenKind::Imaginary),
+    ("_Noreturn", TokenKind::Noreturn),
+    ("_Pragma", TokenKind::Pragma),
+    ("_Static_assert", TokenKind::StaticAssert),
+];
+
+#[test]
+fn every_spelling_lexes_as_one_token() {
+    for &(spelling, kind) in SPELLINGS {
+        assert_eq!(kinds(spelling), [kind], "{spelling:?}");
+    }
+}
+
+#[test]
+fn keywords_are_not_matched_inside_identifiers() {
+    for &(spelling, _) in SPELLINGS {
+        if !spelling.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            continue;
+        }
+        for suffix in ["z", "_", "1"] {
+            let ident = format!("{spelling}{suffix}");
+            assert_eq!(kinds(&ident), [TokenKind::Identifier(&ident)], "{ident:?}");
+        }
+    }
+}
+
+#[test]
+fn corpus_tokens_lex_the_same_alone() {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&corpus)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "c"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let src = fs::read_to_string(&path).unwrap();
+        let (tokens, _) = Lexer::new(&src).lex();
+        for token in tokens.iter().filter(|t| t.kind != TokenKind::Eof) {
+            let text = token.text(&src);
+            assert_eq!(kinds(text), [token.kind], "{}: {text:?}", path.display());
+        }
+    }
+}
+
+#[test]
+fn identifiers_are_classified_as_a_whole() {
+    for (src, kind) in [
+        ("int", TokenKind::Int),
+        ("integer", TokenKind::Identifier("integer")),
+        ("restrict", TokenKind::Restrict),
+        ("restricted", TokenKind::Identifier("restricted")),
+        ("_Atomic", TokenKind::Atomic),
+        ("_AtomicX", TokenKind::Identifier("_AtomicX")),
+        ("Int", TokenKind::Identifier("Int")),
+        ("u8", TokenKind::Identifier("u8")),
+    ] {
+        assert_eq!(kinds(src), [kind], "{src:?}");
+    }
+    assert_eq!(
+        kinds("unsigned int integer;"),
+        [
+            TokenKind::Unsigned,
+            TokenKind::Int,
+            TokenKind::Identifier("integer"),
+            TokenKind::Semicolon,
+        ]
+    );
+}
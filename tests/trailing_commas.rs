@@ -0,0 +1,88 @@
+//! Token runs that variadic macros leave behind once expanded, such as a comma
+//! before a closing parenthesis when `__VA_ARGS__` is empty.
+
+use ecc::{
+    lexer::Lexer,
+    parser::{Expected, Parser, ParserOptions},
+    token::TokenKind,
+};
+
+/// Parses `src` and returns each error with the kind of the token it is at.
+fn errors(src: &str) -> Vec<(Expected<'_>, TokenKind<'_>)> {
+    let (tokens, _) = Lexer::new(src).lex();
+    let result = Parser::new(&tokens).parse();
+    result
+        .errors
+        .iter()
+        .map(|err| (err.expected, err.at.kind))
+        .collect()
+}
+
+#[test]
+fn resolved_expansions_parse() {
+    // `LOG(fmt, ...)` defined as `printf(fmt, ##__VA_ARGS__)`, with and without arguments.
+    assert_eq!(
+        errors("void f(void) { printf(\"a\"); printf(\"%d\", 1); }"),
+        []
+    );
+    // Nested and parenthesized arguments forwarded through `__VA_ARGS__`.
+    assert_eq!(errors("void f(void) { g(1, h(), (2, 3)); }"), []);
+    assert_eq!(errors("int a[] = { 1, 2, };"), []);
+    assert_eq!(errors("enum E { A, B, };"), []);
+    assert_eq!(errors("[[deprecated, ]] int x;"), []);
+    assert_eq!(errors("[[gnu::vendor(a, )]] int x;"), []);
+}
+
+#[test]
+fn comma_before_close_parenthesis_is_reported_at_the_parenthesis() {
+    let sources = [
+        "void f(void) { printf(\"a\", ); }",
+        "void f(void) { g(1, 2, ); }",
+        "void f(int a, );",
+        "int r = _Generic(0, int: 1, );",
+    ];
+    for src in sources {
+        let errors = errors(src);
+        assert_eq!(
+            errors.first(),
+            Some(&(Expected::ItemAfterComma, TokenKind::CloseParenthesis)),
+            "{src}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn trailing_argument_comma_is_accepted_in_tolerant_mode() {
+    let options = ParserOptions {
+        trailing_argument_comma: true,
+        ..Default::default()
+    };
+    for src in [
+        "void f(void) { g(a, b,); }",
+        "void f(void) { g(a,); }",
+        "void f(void) { g(h(1,), 2,); }",
+    ] {
+        let (tokens, _) = Lexer::new(src).lex();
+        let result = Parser::with_options(&tokens, options.clone()).parse();
+        assert_eq!(result.errors, [], "{src}");
+    }
+
+    // Only argument lists are affected, and only a comma after an argument.
+    for src in [
+        "void f(int a, );",
+        "void f(void) { g(,); }",
+        "void f(void) { g(a,,); }",
+    ] {
+        let (tokens, _) = Lexer::new(src).lex();
+        let result = Parser::with_options(&tokens, options.clone()).parse();
+        assert_ne!(result.errors, [], "{src}");
+    }
+}
+
+#[test]
+fn trailing_argument_comma_is_rejected_in_strict_mode() {
+    assert_eq!(
+        errors("void f(void) { g(a, b,); }").first(),
+        Some(&(Expected::ItemAfterComma, TokenKind::CloseParenthesis))
+    );
+}
@@ -0,0 +1,65 @@
+use ecc::{
+    ast::{
+        DeclarationKind, DeclarationSpecifierKind, ExternalDeclarationKind, TypeSpecifierKind,
+        TypeSpecifierQualifierKind,
+    },
+    lexer::Lexer,
+    parser::Parser,
+    symbols::{SymbolKind, SymbolTable},
+    types::{BaseType, Tag, TypeKind},
+};
+
+const SRC: &str = "typedef struct { int x; } Point; Point p; Point *q, r[2];";
+
+#[test]
+fn typedef_of_anonymous_struct_names_a_type() {
+    let (tokens, _) = Lexer::new(SRC).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+
+    let specifiers: Vec<_> = ast
+        .iter()
+        .map(|declaration| {
+            let ExternalDeclarationKind::Declaration(declaration) = &declaration.kind else {
+                panic!("unexpected function definition");
+            };
+            let DeclarationKind::Normal { specifiers, .. } = &declaration.kind else {
+                panic!("unexpected declaration {declaration:?}");
+            };
+            specifiers
+                .iter()
+                .filter_map(|specifier| match &specifier.kind {
+                    DeclarationSpecifierKind::Type(t) => match &t.kind {
+                        TypeSpecifierQualifierKind::TypeSpecifier(t) => Some(match t.kind {
+                            TypeSpecifierKind::StructOrUnion(_) => "struct",
+                            TypeSpecifierKind::TypedefName(name) => name,
+                            _ => "other",
+                        }),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(specifiers, [["struct"], ["Point"], ["Point"]]);
+}
+
+#[test]
+fn typedef_name_denotes_the_inline_struct() {
+    let (tokens, _) = Lexer::new(SRC).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    let symbols = SymbolTable::collect(&ast);
+
+    let point = &symbols.symbols()[0];
+    assert_eq!((point.name, point.kind), ("Point", SymbolKind::Typedef));
+    let TypeKind::Base(BaseType::Struct(Tag::Anonymous(_))) = point.ty.as_ref().unwrap().kind
+    else {
+        panic!("unexpected type {:?}", point.ty);
+    };
+
+    let p = symbols.symbols().iter().find(|s| s.name == "p").unwrap();
+    assert_eq!(p.kind, SymbolKind::Object);
+    assert_eq!(p.ty, point.ty);
+    let x = symbols.member(p.ty.as_ref().unwrap(), "x").unwrap();
+    assert_eq!(x.ty.as_ref().unwrap().kind, TypeKind::Base(BaseType::Int));
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn redefinition_to_the_same_type_is_allowed() {
+    for src in [
+        "typedef int T; typedef int T;",
+        "typedef int T; typedef signed int T; T x;",
+        "typedef int I; typedef I T; typedef int T;",
+        "typedef struct S { int x; } S; typedef struct S S;",
+        "typedef void F(int); typedef void F(int value);",
+        "typedef int T; void f(void) { typedef long T; }",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn redefinition_to_a_different_type_is_an_error() {
+    for src in [
+        "typedef int T; typedef long T;",
+        "typedef int T; typedef const int T;",
+        "typedef int *P; typedef char *P;",
+        "typedef int A[3]; typedef int A[];",
+        "void f(void) { typedef int T; typedef unsigned T; }",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::TypedefRedefinition],
+            "{src}"
+        );
+    }
+}
+
+#[test]
+fn block_scope_typedefs_shadow_without_redefining() {
+    for src in [
+        "typedef int T; void f(void) { typedef long T; } typedef int T;",
+        "void f(void) { typedef long T; } typedef int T;",
+        "void f(void) { typedef int T; { typedef long T; T x; } typedef int T; }",
+        "void f(void) { typedef int T; for (long T = 0; T < 3; T++) {} typedef int T; }",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+    // The file-scope definition is still the one a later redefinition must match.
+    assert_eq!(
+        errors("typedef int T; void f(void) { typedef long T; } typedef long T;"),
+        [ValidationErrKind::TypedefRedefinition]
+    );
+}
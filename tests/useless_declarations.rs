@@ -0,0 +1,35 @@
+mod common;
+
+use common::errors;
+use ecc::validate::ValidationErrKind;
+
+#[test]
+fn type_defining_declarations_are_useful() {
+    for src in [
+        "struct S { int x; };",
+        "struct S;",
+        "union U { int i; float f; };",
+        "enum E { A };",
+        "enum { B, C };",
+        "void f(void) { struct T { int y; }; }",
+    ] {
+        assert_eq!(errors(src), [], "{src}");
+    }
+}
+
+#[test]
+fn declarations_declaring_nothing_are_useless() {
+    for src in [
+        "int;",
+        "const unsigned long;",
+        "typedef double;",
+        "struct { int x; };",
+        "void f(void) { static int; }",
+    ] {
+        assert_eq!(
+            errors(src),
+            [ValidationErrKind::UselessDeclaration],
+            "{src}"
+        );
+    }
+}
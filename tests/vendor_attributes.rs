@@ -0,0 +1,67 @@
+use ecc::{
+    ast::{Attribute, BalancedTokenKind, BalancedTokenSequence},
+    lexer::Lexer,
+    parser::Parser,
+    validate::validate,
+    visit::{Node, Visitor, walk_translation_unit},
+};
+
+/// Prints every attribute back out from its parsed form.
+struct Attributes(Vec<String>);
+impl<'a> Visitor<'a> for Attributes {
+    fn enter(&mut self, node: Node<'_, 'a>) {
+        if let Node::Attribute(attribute) = node {
+            self.0.push(unparse(attribute));
+        }
+    }
+}
+
+fn unparse(attribute: &Attribute) -> String {
+    let mut out = String::new();
+    if let Some((prefix, _)) = attribute.token.prefix {
+        out += &format!("{prefix}::");
+    }
+    out += attribute.token.token;
+    if let Some(clause) = &attribute.argument_clause {
+        out += &format!("({})", unparse_tokens(clause.tokens.as_ref()));
+    }
+    out
+}
+
+fn unparse_tokens(tokens: Option<&BalancedTokenSequence>) -> String {
+    let tokens = tokens.iter().flat_map(|tokens| tokens.iter());
+    let spelled: Vec<_> = tokens
+        .map(|token| match &token.kind {
+            BalancedTokenKind::Parenthesized { inner, .. } => {
+                format!("({})", unparse_tokens(inner.as_ref()))
+            }
+            BalancedTokenKind::Bracketed { inner, .. } => {
+                format!("[{}]", unparse_tokens(inner.as_ref()))
+            }
+            BalancedTokenKind::Braced { inner, .. } => {
+                format!("{{{}}}", unparse_tokens(inner.as_ref()))
+            }
+            BalancedTokenKind::Token(kind) => kind.to_string(),
+        })
+        .collect();
+    spelled.join(" ")
+}
+
+#[test]
+fn unknown_vendor_attributes_keep_their_arguments() {
+    let attributes = [
+        "vendor::thing(1 , 2)",
+        "vendor::empty()",
+        "vendor::plain",
+        "acme::nested(f (x , [y]) {z ;} \"text\")",
+        "unknown(a + b)",
+    ];
+    let src = format!("[[{}]] int x;", attributes.join(", "));
+    let (tokens, _) = Lexer::new(&src).lex();
+    let ast = Parser::new(&tokens).parse().unwrap();
+    assert_eq!(validate(&ast), []);
+
+    let mut printed = Attributes(Vec::new());
+    walk_translation_unit(&mut printed, &ast);
+    assert_eq!(printed.0, attributes);
+}